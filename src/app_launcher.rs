@@ -0,0 +1,14 @@
+use std::path::Path;
+use std::process::Command;
+
+
+// Starts another instance of the application, so that it shows the given repository.
+pub(crate) fn launchApp(repositoryDir: &Path) -> Result<(), String>
+{
+    let executable = std::env::current_exe().map_err(|e| format!("Failed to find the application executable: {}", e))?;
+    Command::new(executable)
+        .arg(repositoryDir)
+        .spawn()
+        .map(|_child| ())
+        .map_err(|e| format!("Failed to start the application for {}: {}", repositoryDir.display(), e))
+}
@@ -3,9 +3,13 @@ use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::event_constants::FORWARD_EVENT;
 use crate::gui_element_provider::GuiElementProvider;
 
+use gtk::gdk;
+use gtk::prelude::AccelGroupExtManual as _;
 use gtk::prelude::GtkWindowExt as _;
 use gtk::prelude::WidgetExt as _;
 
+const ACCELERATOR_HANDLED: bool = true;
+
 
 pub(crate) struct ApplicationWindow
 {
@@ -30,7 +34,8 @@ impl ApplicationWindow
         let newSelf = Self{window: guiElementProvider.get::<gtk::ApplicationWindow>("Main window")};
         newSelf.loadConfig(config);
         newSelf.connectToMaximizationChanged(sender.clone());
-        newSelf.connectToWindowDeletion(sender);
+        newSelf.connectToWindowDeletion(sender.clone());
+        newSelf.setupSearchAccelerator(sender);
         newSelf
     }
 
@@ -69,6 +74,18 @@ impl ApplicationWindow
         });
     }
 
+    // Ctrl+F works in the whole window, not only when the searched view has focus.
+    fn setupSearchAccelerator(&self, sender: Sender)
+    {
+        let accelGroup = gtk::AccelGroup::new();
+        accelGroup.connect_accel_group(
+            *gdk::keys::constants::f, gdk::ModifierType::CONTROL_MASK, gtk::AccelFlags::VISIBLE, move |_, _, _, _| {
+                sender.send((Source::ApplicationWindow, Event::SearchRequested)).unwrap();
+                ACCELERATOR_HANDLED
+            });
+        self.window.add_accel_group(&accelGroup);
+    }
+
     fn onQuitRequested(&self)
     {
         if gtk::main_level() > 0 {
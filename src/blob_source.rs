@@ -0,0 +1,19 @@
+pub(crate) const NO_SIZE_LIMIT: usize = usize::MAX;
+
+
+// Where a version of a file is read from when comparing it as a whole, e.g. for image diffs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum BlobSource
+{
+    Head,
+    ParentOfHead,
+    Index,
+    Workdir
+}
+
+// A version of a file, whose data is only read when it is not larger than requested.
+pub(crate) struct FileContent
+{
+    pub size: usize,
+    pub data: Option<Vec<u8>>
+}
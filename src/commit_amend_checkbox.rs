@@ -17,9 +17,11 @@ impl IEventHandler for CommitAmendCheckbox
     fn handle(&mut self, source: Source, event: &Event)
     {
         match event {
-            Event::AmendedCommit       => self.onAmendedCommit(),
-            Event::Committed           => self.onCommitted(),
-            Event::Toggled(isSelected) => self.onToggled(*isSelected),
+            Event::AmendedCommit        => self.onAmendedCommit(),
+            Event::CommitAmendRequested => self.onCommitAmendRequested(),
+            Event::Committed            => self.onCommitted(),
+            Event::HeadChanged          => self.onHeadChanged(),
+            Event::Toggled(isSelected)  => self.onToggled(*isSelected),
             _ => handleUnknown(source, event)
         }
     }
@@ -87,6 +89,22 @@ impl CommitAmendCheckbox
         }
     }
 
+    // Requested when an amended message is restored from a draft.
+    fn onCommitAmendRequested(&self)
+    {
+        if !self.isDisabled() {
+            self.widget.set_active(true);
+        }
+    }
+
+    fn onHeadChanged(&self)
+    {
+        self.unselect();
+        if self.isDisabled() {
+            self.enable();
+        }
+    }
+
     fn onToggled(&self, isSelected: bool)
     {
         if isSelected {
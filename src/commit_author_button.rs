@@ -0,0 +1,88 @@
+use crate::commit_author_dialog::askForCommitAuthorOptions;
+use crate::commit_author_options::CommitAuthorOptions;
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::prelude::ButtonExt as _;
+use gtk::prelude::WidgetExt as _;
+
+const DEFAULT_LABEL: &str = "Author...";
+const OVERRIDDEN_LABEL: &str = "Author (custom)...";
+const DEFAULT_TOOLTIP: &str = "Set a different author or author date for the next commit.";
+
+
+pub(crate) struct CommitAuthorButton
+{
+    widget: gtk::Button,
+    options: CommitAuthorOptions,
+    sender: Sender
+}
+
+impl IEventHandler for CommitAuthorButton
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::AmendedCommit => self.onCommitted(),
+            Event::Clicked       => self.onClicked(),
+            Event::Committed     => self.onCommitted(),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl CommitAuthorButton
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
+    {
+        let widget = guiElementProvider.get::<gtk::Button>("Commit author button");
+        let newSelf = Self{widget, options: CommitAuthorOptions::default(), sender};
+        newSelf.connectWidget();
+        newSelf.updateLabel();
+        newSelf
+    }
+
+
+    // private
+
+    fn connectWidget(&self)
+    {
+        let sender = self.sender.clone();
+        self.widget.connect_clicked(move |_button| {
+            sender.send((Source::CommitAuthorButton, Event::Clicked)).unwrap();
+        });
+    }
+
+    fn onClicked(&mut self)
+    {
+        let options = match askForCommitAuthorOptions(&self.options) {
+            Some(options) => options,
+            None => return
+        };
+        if options == self.options {
+            return;
+        }
+
+        self.options = options;
+        self.updateLabel();
+        self.sender.send((Source::CommitAuthorButton, Event::CommitAuthorOptionsChanged(self.options.clone()))).unwrap();
+    }
+
+    // The repository resets its options after committing too.
+    fn onCommitted(&mut self)
+    {
+        self.options = CommitAuthorOptions::default();
+        self.updateLabel();
+    }
+
+    fn updateLabel(&self)
+    {
+        if self.options.isDefault() {
+            self.widget.set_label(DEFAULT_LABEL);
+            self.widget.set_tooltip_text(Some(DEFAULT_TOOLTIP));
+        } else {
+            self.widget.set_label(OVERRIDDEN_LABEL);
+            self.widget.set_tooltip_text(Some(&self.options.to_string()));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::commit_author_options::{AuthorDate, CommitAuthorOptions, Identity};
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::EditableSignals as _;
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::LabelExt as _;
+use gtk::prelude::ToggleButtonExt as _;
+use gtk::prelude::WidgetExt as _;
+
+
+pub(crate) fn askForCommitAuthorOptions(currentOptions: &CommitAuthorOptions) -> Option<CommitAuthorOptions>
+{
+    let guiElementProvider = GuiElementProvider::new(include_str!("commit_author_dialog.glade"));
+    let dialog = guiElementProvider.get::<gtk::Dialog>("dialog");
+    let authorEntry = guiElementProvider.get::<gtk::Entry>("Author entry");
+    let authorDateEntry = guiElementProvider.get::<gtk::Entry>("Author date entry");
+    let resetAuthorCheckbox = guiElementProvider.get::<gtk::CheckButton>("Reset author checkbox");
+    let errorLabel = guiElementProvider.get::<gtk::Label>("Error label");
+    let applyButton = guiElementProvider.get::<gtk::Button>("Apply button");
+
+    if let Some(author) = &currentOptions.author {
+        authorEntry.set_text(&author.to_string());
+    }
+    if let Some(authorDate) = &currentOptions.authorDate {
+        authorDateEntry.set_text(&authorDate.to_string());
+    }
+    resetAuthorCheckbox.set_active(currentOptions.resetAuthorOnAmend);
+
+    let validate = {
+        let (authorEntry, authorDateEntry) = (authorEntry.clone(), authorDateEntry.clone());
+        move || {
+            let error = parseOptions(&authorEntry, &authorDateEntry, false).err();
+            errorLabel.set_text(error.as_deref().unwrap_or_default());
+            applyButton.set_sensitive(error.is_none());
+        }
+    };
+    let validateAfterAuthorChange = validate.clone();
+    authorEntry.connect_changed(move |_entry| validateAfterAuthorChange());
+    authorDateEntry.connect_changed(move |_entry| validate());
+
+    let response = dialog.run();
+    let options = parseOptions(&authorEntry, &authorDateEntry, resetAuthorCheckbox.is_active());
+    dialog.close();
+
+    match response {
+        gtk::ResponseType::Ok => options.ok(),
+        _ => None
+    }
+}
+
+fn parseOptions(authorEntry: &gtk::Entry, authorDateEntry: &gtk::Entry, resetAuthorOnAmend: bool)
+    -> Result<CommitAuthorOptions, String>
+{
+    let author = authorEntry.text();
+    let authorDate = authorDateEntry.text();
+    Ok(CommitAuthorOptions{
+        author: (!author.trim().is_empty()).then(|| Identity::parse(&author)).transpose()?,
+        authorDate: (!authorDate.trim().is_empty()).then(|| AuthorDate::parse(&authorDate)).transpose()?,
+        resetAuthorOnAmend
+    })
+}
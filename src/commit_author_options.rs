@@ -0,0 +1,122 @@
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+const IDENTITY_PATTERN: &str = r"^\s*([^<>]*[^<>\s])\s*<([^<>\s]+)>\s*$";
+const DATE_PATTERN: &str =
+    r"^\s*(\d{4})-(\d{2})-(\d{2})[ T](\d{2}):(\d{2})(?::(\d{2}))?\s*(?:([+-])(\d{2}):?(\d{2}))?\s*$";
+
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CommitAuthorOptions
+{
+    pub author: Option<Identity>,
+    pub authorDate: Option<AuthorDate>,
+    pub resetAuthorOnAmend: bool
+}
+
+impl CommitAuthorOptions
+{
+    pub fn isDefault(&self) -> bool
+    {
+        *self == Self::default()
+    }
+}
+
+impl Display for CommitAuthorOptions
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        let mut lines = vec![];
+        if let Some(author) = &self.author {
+            lines.push(format!("Author: {}", author));
+        }
+        if let Some(authorDate) = &self.authorDate {
+            lines.push(format!("Author date: {}", authorDate));
+        }
+        if self.resetAuthorOnAmend {
+            lines.push("Reset the author when amending".into());
+        }
+        write!(formatter, "{}", lines.join("\n"))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Identity
+{
+    pub name: String,
+    pub email: String
+}
+
+impl Identity
+{
+    pub fn parse(text: &str) -> Result<Self, String>
+    {
+        static IDENTITY_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = IDENTITY_REGEX.get_or_init(|| Regex::new(IDENTITY_PATTERN).unwrap());
+        let captures = regex.captures(text)
+            .ok_or_else(|| format!(r#"Author "{}" is not in the form "Name <email>"."#, text))?;
+        Ok(Self{name: captures[1].into(), email: captures[2].into()})
+    }
+}
+
+impl Display for Identity
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        write!(formatter, "{} <{}>", self.name, self.email)
+    }
+}
+
+// Without an explicit UTC offset the date is taken to be in the local time zone of the committer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct AuthorDate
+{
+    text: String,
+    secondsAsIfUtc: i64,
+    offsetMinutes: Option<i32>
+}
+
+impl AuthorDate
+{
+    pub fn parse(text: &str) -> Result<Self, String>
+    {
+        let error = || format!(r#"Author date "{}" is not in the form "YYYY-MM-DD HH:MM[:SS] [+HHMM]"."#, text);
+        static DATE_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = DATE_REGEX.get_or_init(|| Regex::new(DATE_PATTERN).unwrap());
+        let captures = regex.captures(text).ok_or_else(error)?;
+        // The pattern guarantees that every captured number fits.
+        let number = |index: usize| captures.get(index).map_or(0, |value| value.as_str().parse::<u8>().unwrap());
+        let month = time::Month::try_from(number(2)).map_err(|_| error())?;
+        let date = time::Date::from_calendar_date(captures[1].parse().unwrap(), month, number(3))
+            .map_err(|_| error())?;
+        let time = time::Time::from_hms(number(4), number(5), number(6)).map_err(|_| error())?;
+        let secondsAsIfUtc = time::PrimitiveDateTime::new(date, time).assume_utc().unix_timestamp();
+
+        let offsetMinutes = match (captures.get(7), captures.get(8), captures.get(9)) {
+            (Some(sign), Some(hours), Some(minutes)) => {
+                let magnitude = hours.as_str().parse::<i32>().unwrap() * 60 + minutes.as_str().parse::<i32>().unwrap();
+                match sign.as_str() {
+                    "-" => Some(-magnitude),
+                    _ => Some(magnitude)
+                }
+            },
+            _ => None
+        };
+        Ok(Self{text: text.trim().into(), secondsAsIfUtc, offsetMinutes})
+    }
+
+    pub fn toGitTime(&self, localOffsetMinutes: i32) -> git2::Time
+    {
+        let offsetMinutes = self.offsetMinutes.unwrap_or(localOffsetMinutes);
+        git2::Time::new(self.secondsAsIfUtc - i64::from(offsetMinutes) * 60, offsetMinutes)
+    }
+}
+
+impl Display for AuthorDate
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        write!(formatter, "{}", self.text)
+    }
+}
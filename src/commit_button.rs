@@ -1,4 +1,6 @@
+use crate::commit_message_linter::CommitMessageLinter;
 use crate::commit_message_reader::CommitMessageReader;
+use crate::config::{Config, LintLevel};
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::gui_element_provider::GuiElementProvider;
 use crate::repository::Repository;
@@ -14,6 +16,8 @@ pub(crate) struct CommitButton
     widget: gtk::Button,
     repository: Rc<RefCell<Repository>>,
     commitMessageReader: CommitMessageReader,
+    commitMessageLinter: CommitMessageLinter,
+    commitMessageTemplate: Option<String>,
     sender: Sender,
     areChangesStaged: bool,
     isCommitMessageWritten: bool,
@@ -26,11 +30,15 @@ impl IEventHandler for CommitButton
     {
         match event {
             Event::AddedToStaged(_)     => self.onAddedToStaged(),
+            Event::BufferChanged        => self.onCommitMessageChanged(),
             Event::Clicked              => self.onClicked(),
             Event::CommitAmendDisabled  => self.onCommitAmendDisabled(),
             Event::CommitAmendEnabled   => self.onCommitAmendEnabled(),
+            Event::CommitFailed         => self.onCommitFailed(),
             Event::Emptied              => self.onCommitMessageEmptied(),
             Event::Filled               => self.onCommitMessageFilled(),
+            Event::HeadChanged          => self.onHeadChanged(),
+            Event::Refreshed            => self.onRefreshed(),
             Event::RemovedFromStaged(_) => self.onRemovedFromStaged(),
             _ => handleUnknown(source, event)
         }
@@ -43,15 +51,19 @@ impl CommitButton
         guiElementProvider: &GuiElementProvider,
         commitMessageReader: CommitMessageReader,
         repository: Rc<RefCell<Repository>>,
+        config: &Config,
         sender: Sender)
         -> Self
     {
         let isCommitMessageWritten = commitMessageReader.hasText();
         let areChangesStaged = repository.borrow().hasStagedChanges();
+        let commitMessageTemplate = repository.borrow().loadCommitMessageTemplate();
         let newSelf = Self {
             widget: guiElementProvider.get::<gtk::Button>("Commit button"),
             repository,
             commitMessageReader,
+            commitMessageLinter: CommitMessageLinter::new(&config.commitMessageLinter),
+            commitMessageTemplate,
             sender,
             areChangesStaged,
             isCommitMessageWritten,
@@ -91,6 +103,27 @@ impl CommitButton
         self.update();
     }
 
+    fn onHeadChanged(&mut self)
+    {
+        self.updateStagedState();
+    }
+
+    fn onRefreshed(&mut self)
+    {
+        self.updateStagedState();
+    }
+
+    fn onCommitFailed(&mut self)
+    {
+        self.updateStagedState();
+    }
+
+    fn updateStagedState(&mut self)
+    {
+        self.areChangesStaged = self.repository.borrow().hasStagedChanges();
+        self.update();
+    }
+
     fn onCommitMessageFilled(&mut self)
     {
         self.isCommitMessageWritten = true;
@@ -103,6 +136,11 @@ impl CommitButton
         self.update();
     }
 
+    fn onCommitMessageChanged(&self)
+    {
+        self.update();
+    }
+
     fn onCommitAmendEnabled(&mut self)
     {
         self.isCommitAmendEnabled = true;
@@ -134,8 +172,24 @@ impl CommitButton
             return;
         }
 
-        self.enable();
-        self.clearTooltip();
+        if self.commitMessageIsUnchangedTemplate() {
+            self.disable();
+            self.setTooltip("The commit message is the unchanged template.");
+            return;
+        }
+
+        let violations = self.commitMessageLinter.lint(&self.commitMessageReader.getText());
+        let hasErrors = violations.iter().any(|violation| violation.level == LintLevel::Error);
+        if hasErrors && self.commitMessageLinter.shouldBlockCommitOnErrors() {
+            self.disable();
+        } else {
+            self.enable();
+        }
+
+        match violations.is_empty() {
+            true => self.clearTooltip(),
+            false => self.setTooltip(&violations.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))
+        }
     }
 
     const fn noChangesAreStaged(&self) -> bool
@@ -148,6 +202,13 @@ impl CommitButton
         !self.isCommitMessageWritten
     }
 
+    // Like git, which aborts a commit whose message was not edited after being prefilled from the template.
+    fn commitMessageIsUnchangedTemplate(&self) -> bool
+    {
+        self.commitMessageTemplate.as_deref()
+            .is_some_and(|template| template.trim_end() == self.commitMessageReader.getText().trim_end())
+    }
+
     const fn commitAmendIsEnabled(&self) -> bool
     {
         self.isCommitAmendEnabled
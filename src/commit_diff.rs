@@ -1,35 +1,70 @@
 use crate::date_time::makeDateTime;
 use crate::diff_formatter::{DiffFormatter, FormattedDiff, LineFormat};
+use crate::hex_diff::formatHexDiff;
+use crate::image_diff_view::isImagePath;
+use crate::repository::Repository;
 
 use time::format_description::well_known::Rfc2822;
 
 
-pub(crate) fn formatCommitDiff(commit: &git2::Commit, diff: &git2::Diff) -> FormattedDiff
+pub(crate) fn formatCommitDiff(
+    commit: &git2::Commit,
+    signatureDescription: Option<&str>,
+    diff: &git2::Diff,
+    repository: &Repository,
+    hexDiffMaxSize: usize)
+    -> FormattedDiff
 {
-    let commitSummary = makeCommitSummary(commit);
+    let mut commitDiff = makeCommitSummary(commit, signatureDescription);
     let mut diffFormatter = DiffFormatter::newForCommit();
-    diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| diffFormatter.format(&line)).unwrap();
-    let formattedDiff = diffFormatter.takeOutput();
-    let text = commitSummary.text + &formattedDiff.text;
-    let mut lineFormats = commitSummary.lineFormats;
-    lineFormats.extend(formattedDiff.lineFormats);
-    FormattedDiff{text, lineFormats}
+    diff.print(git2::DiffFormat::Patch, |delta, _hunk, line| {
+        let result = diffFormatter.format(&line);
+        if line.origin_value() == git2::DiffLineType::Binary {
+            if let Some(hexDiff) = formatBinaryDelta(&delta, repository, hexDiffMaxSize) {
+                diffFormatter.append(hexDiff);
+            }
+        }
+        result
+    }).unwrap();
+    commitDiff.append(diffFormatter.takeOutput());
+    commitDiff
 }
 
 
 // private
 
-fn makeCommitSummary(commit: &git2::Commit) -> FormattedDiff
+fn makeCommitSummary(commit: &git2::Commit, signatureDescription: Option<&str>) -> FormattedDiff
 {
+    let signatureLine = signatureDescription
+        .map(|description| format!("Signature: {}\n", description))
+        .unwrap_or_default();
     let text = format!(
-        "Commit: {}\nAuthor: {} <{}>\nDate:   {}\n\n{}\n",
+        "Commit: {}\nAuthor: {} <{}>\nDate:   {}\n{}\n{}\n",
         commit.id(),
         commit.author().name().unwrap(),
         commit.author().email().unwrap(),
         formatDateTime(&commit.time()),
+        signatureLine,
         tabulateCommitMessage(&getMessage(commit)));
     let lineFormats = vec![LineFormat::TopHeader; text.lines().count()];
-    FormattedDiff{text, lineFormats}
+    FormattedDiff{text, lineFormats, characterFormats: vec![]}
+}
+
+// Images are not dumped, because they are previewed instead.
+fn formatBinaryDelta(delta: &git2::DiffDelta, repository: &Repository, hexDiffMaxSize: usize) -> Option<FormattedDiff>
+{
+    let path = delta.new_file().path().or_else(|| delta.old_file().path())?.to_str()?;
+    if isImagePath(path) {
+        return None;
+    }
+
+    let loadContent = |file: git2::DiffFile| match file.exists() {
+        true => repository.loadBlobContent(file.id(), hexDiffMaxSize),
+        false => None
+    };
+    let oldContent = loadContent(delta.old_file());
+    let newContent = loadContent(delta.new_file());
+    Some(formatHexDiff(oldContent.as_ref(), newContent.as_ref(), hexDiffMaxSize))
 }
 
 fn getMessage(commit: &git2::Commit) -> String
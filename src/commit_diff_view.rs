@@ -1,10 +1,19 @@
+use crate::blob_source::NO_SIZE_LIMIT;
 use crate::commit_diff::formatCommitDiff;
+use crate::commit_signing::SignatureVerification;
+use crate::config::Config;
 use crate::diff_colorizer::DiffColorizer;
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::gui_element_provider::GuiElementProvider;
+use crate::image_diff_view::{ImageDiffView, ImageDiffWidgetData, isImagePath};
 use crate::repository::Repository;
+use crate::text_filter_widgets::WidgetData;
+use crate::text_search::{TextSearch, TextSearchWidgetData};
 use crate::text_view::{Notifications, TextView};
 
+use gtk::prelude::ComboBoxExt as _;
+use gtk::prelude::ComboBoxExtManual as _;
+use gtk::prelude::ComboBoxTextExt as _;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -13,17 +22,39 @@ pub(crate) struct CommitDiffView
 {
     textView: TextView,
     diffColorizer: DiffColorizer,
-    repository: Rc<RefCell<Repository>>
+    search: TextSearch,
+    imageDiffView: Rc<ImageDiffView>,
+    imageFileComboBox: gtk::ComboBoxText,
+    imageChanges: Rc<RefCell<Vec<CommitImageChange>>>,
+    repository: Rc<RefCell<Repository>>,
+    selectedCommitId: Option<git2::Oid>,
+    hexDiffMaxSize: usize
+}
+
+// Either blob is None when the image does not exist on that side, e.g. it was added or deleted.
+struct CommitImageChange
+{
+    path: String,
+    oldId: Option<git2::Oid>,
+    newId: Option<git2::Oid>
 }
 
 impl IEventHandler for CommitDiffView
 {
     fn handle(&mut self, source: Source, event: &Event)
     {
-        match event {
-            Event::CommitSelected(id) => self.onCommitSelected(id),
-            Event::CommitUnselected   => self.onCommitUnselected(),
-            Event::ZoomRequested(_)   => self.onZoomRequested(source, event),
+        use crate::event::{Source as S, Event as E};
+        match (source, event) {
+            (S::ApplicationWindow,              E::SearchRequested)                     => self.search.open(),
+            (S::CommitDiffSearchCaseButton,     E::Toggled(isEnabled))                  => self.search.setCaseSensitivityEnabled(&self.textView, *isEnabled),
+            (S::CommitDiffSearchEntry,          E::TextEntered(text))                   => self.search.searchFor(&self.textView, text),
+            (S::CommitDiffSearchNextButton,     E::Clicked)                             => self.search.selectNextMatch(&self.textView),
+            (S::CommitDiffSearchPreviousButton, E::Clicked)                             => self.search.selectPreviousMatch(&self.textView),
+            (S::CommitDiffSearchRegexButton,    E::Toggled(isEnabled))                  => self.search.setRegexEnabled(&self.textView, *isEnabled),
+            (S::CommitDiffViewWidget,           E::ZoomRequested(_))                    => self.onZoomRequested(source, event),
+            (S::CommitLogView,                  E::CommitSelected(id))                  => self.onCommitSelected(id),
+            (S::CommitLogView,                  E::CommitUnselected)                    => self.onCommitUnselected(),
+            (S::Repository,                     E::SignatureVerified(id, verification)) => self.onSignatureVerified(*id, verification),
             _ => handleUnknown(source, event)
         }
     }
@@ -31,33 +62,112 @@ impl IEventHandler for CommitDiffView
 
 impl CommitDiffView
 {
-    pub fn new(repository: Rc<RefCell<Repository>>, guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
+    pub fn new(
+        repository: Rc<RefCell<Repository>>,
+        guiElementProvider: &GuiElementProvider,
+        config: &Config,
+        sender: Sender)
+        -> Self
     {
         let textView = TextView::new(
-            guiElementProvider, "Commit diff view", sender, Source::CommitDiffViewWidget, Notifications::Disabled);
+            guiElementProvider, "Commit diff view", sender.clone(), Source::CommitDiffViewWidget, Notifications::Disabled);
         let diffColorizer = DiffColorizer::new(&textView);
-        Self{
+        let search = TextSearch::new(guiElementProvider, &textView, &makeSearchWidgetData(), sender);
+        let newSelf = Self{
             textView,
             diffColorizer,
-            repository
-        }
+            search,
+            imageDiffView: Rc::new(ImageDiffView::new(guiElementProvider, &makeImageDiffWidgetData())),
+            imageFileComboBox: guiElementProvider.get::<gtk::ComboBoxText>("Commit image diff file combo box"),
+            imageChanges: Rc::new(RefCell::new(vec![])),
+            repository,
+            selectedCommitId: None,
+            hexDiffMaxSize: config.hexDiff.maxSize
+        };
+        newSelf.connectImageFileComboBox();
+        newSelf
     }
 
 
     // private
 
+    fn connectImageFileComboBox(&self)
+    {
+        let imageDiffView = Rc::clone(&self.imageDiffView);
+        let imageChanges = Rc::clone(&self.imageChanges);
+        let repository = Rc::clone(&self.repository);
+        self.imageFileComboBox.connect_changed(move |comboBox| {
+            let imageChanges = imageChanges.borrow();
+            if let Some(change) = comboBox.active().and_then(|index| imageChanges.get(index as usize)) {
+                let repository = repository.borrow();
+                let loadContent = |id: Option<git2::Oid>| id
+                    .and_then(|id| repository.loadBlobContent(id, NO_SIZE_LIMIT))
+                    .and_then(|content| content.data);
+                imageDiffView.show(loadContent(change.oldId).as_deref(), loadContent(change.newId).as_deref());
+            }
+        });
+    }
+
     fn onCommitSelected(&mut self, commitId: &git2::Oid)
     {
-        let repository = self.repository.borrow();
+        self.selectedCommitId = Some(*commitId);
+        let repository = Rc::clone(&self.repository);
+        let repository = repository.borrow();
         let commit = repository.findCommit(*commitId).unwrap();
         let commitDiff = repository.makeDiffOfCommitAndParent(&commit);
-        let formattedDiff = formatCommitDiff(&commit, &commitDiff);
-        self.diffColorizer.colorize(&self.textView, &formattedDiff);
+        let signatureDescription = repository.getSignatureFormat(*commitId)
+            .map(|format| format!("{} (verifying)", format));
+        self.showCommitDiff(&commit, &commitDiff, signatureDescription.as_deref(), &repository);
+        self.showImageChanges(collectImageChanges(&commitDiff));
+        repository.verifySignature(*commitId);
     }
 
-    fn onCommitUnselected(&self)
+    fn onCommitUnselected(&mut self)
     {
+        self.selectedCommitId = None;
         self.textView.clear();
+        self.search.refresh(&self.textView);
+        self.showImageChanges(vec![]);
+    }
+
+    // The result may come after another commit was selected, in which case it is only shown in the commit log.
+    fn onSignatureVerified(&mut self, commitId: git2::Oid, verification: &SignatureVerification)
+    {
+        if self.selectedCommitId != Some(commitId) {
+            return;
+        }
+
+        let repository = Rc::clone(&self.repository);
+        let repository = repository.borrow();
+        let commit = repository.findCommit(commitId).unwrap();
+        let commitDiff = repository.makeDiffOfCommitAndParent(&commit);
+        self.showCommitDiff(&commit, &commitDiff, Some(&verification.to_string()), &repository);
+    }
+
+    fn showCommitDiff(
+        &mut self,
+        commit: &git2::Commit,
+        commitDiff: &git2::Diff,
+        signatureDescription: Option<&str>,
+        repository: &Repository)
+    {
+        let formattedDiff = formatCommitDiff(commit, signatureDescription, commitDiff, repository, self.hexDiffMaxSize);
+        self.diffColorizer.colorize(&self.textView, &formattedDiff);
+        self.search.refresh(&self.textView);
+    }
+
+    fn showImageChanges(&self, imageChanges: Vec<CommitImageChange>)
+    {
+        *self.imageChanges.borrow_mut() = imageChanges;
+        self.imageFileComboBox.remove_all();
+        if self.imageChanges.borrow().is_empty() {
+            self.imageDiffView.hide();
+            return;
+        }
+        for change in self.imageChanges.borrow().iter() {
+            self.imageFileComboBox.append_text(&change.path);
+        }
+        self.imageFileComboBox.set_active(Some(0));
     }
 
     fn onZoomRequested(&mut self, source: Source, event: &Event)
@@ -65,3 +175,43 @@ impl CommitDiffView
         self.textView.handle(source, event);
     }
 }
+
+fn collectImageChanges(diff: &git2::Diff) -> Vec<CommitImageChange>
+{
+    diff.deltas()
+        .filter_map(|delta| {
+            let path = delta.new_file().path().or_else(|| delta.old_file().path())?.to_str()?;
+            isImagePath(path).then(|| CommitImageChange{
+                path: path.into(),
+                oldId: delta.old_file().exists().then(|| delta.old_file().id()),
+                newId: delta.new_file().exists().then(|| delta.new_file().id())
+            })
+        })
+        .collect()
+}
+
+fn makeImageDiffWidgetData() -> ImageDiffWidgetData
+{
+    ImageDiffWidgetData{
+        boxName: "Commit image diff box",
+        infoLabelName: "Commit image diff info label",
+        drawingAreaName: "Commit image diff drawing area",
+        modeComboBoxName: "Commit image diff mode combo box",
+        opacityScaleName: "Commit image diff opacity scale",
+        replacedWidgetName: None
+    }
+}
+
+fn makeSearchWidgetData() -> TextSearchWidgetData
+{
+    TextSearchWidgetData{
+        searchBarName: "Commit diff search bar",
+        entry: WidgetData{name: "Commit diff search entry", source: Source::CommitDiffSearchEntry},
+        caseButton: WidgetData{name: "Commit diff search case button", source: Source::CommitDiffSearchCaseButton},
+        regexButton: WidgetData{name: "Commit diff search regex button", source: Source::CommitDiffSearchRegexButton},
+        previousButton: WidgetData{
+            name: "Commit diff search previous button", source: Source::CommitDiffSearchPreviousButton},
+        nextButton: WidgetData{name: "Commit diff search next button", source: Source::CommitDiffSearchNextButton},
+        matchCountLabelName: "Commit diff search match count label"
+    }
+}
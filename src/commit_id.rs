@@ -0,0 +1,9 @@
+const SHORT_COMMIT_ID_LENGTH: usize = 7;
+
+
+pub(crate) fn shortenCommitId(id: git2::Oid) -> String
+{
+    let mut text = id.to_string();
+    text.truncate(SHORT_COMMIT_ID_LENGTH);
+    text
+}
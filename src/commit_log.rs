@@ -1,4 +1,6 @@
+use crate::commit_signing::SignatureFormat;
 use crate::date_time::makeDateTime;
+use crate::ref_label::RefLabel;
 use crate::repository::Repository;
 
 use time::OffsetDateTime;
@@ -30,6 +32,11 @@ impl CommitLog
         &self.commits
     }
 
+    pub fn findRow(&self, commitId: git2::Oid) -> Option<usize>
+    {
+        self.commits.iter().position(|commit| commit.id == commitId)
+    }
+
 
     // private
 
@@ -39,15 +46,21 @@ impl CommitLog
             return;
         }
 
-        repo.iterateCommits(|commit| {
+        let mut refLabels = repo.collectRefLabels();
+        let result = repo.iterateCommits(|commit| {
             let summary = getSummary(commit);
             let signature = commit.author();
             let date = makeDateTime(&commit.time());
             let author = signature.name().unwrap_or(INVALID_UTF8).into();
             let email = signature.email().unwrap_or(INVALID_UTF8).into();
             let id = commit.id();
-            self.commits.push(CommitInfo{id, summary, date, author, email});
+            let refs = refLabels.remove(&id).unwrap_or_default();
+            let signatureFormat = repo.getSignatureFormat(id);
+            self.commits.push(CommitInfo{id, summary, date, author, email, refs, signatureFormat});
         });
+        if let Err(e) = result {
+            eprintln!("Failed to load commits: {}", e);
+        }
     }
 }
 
@@ -65,4 +78,6 @@ pub(crate) struct CommitInfo
     pub date: OffsetDateTime,
     pub author: String,
     pub email: String,
+    pub refs: Vec<RefLabel>,
+    pub signatureFormat: Option<SignatureFormat>,
 }
@@ -1,11 +1,11 @@
-use crate::commit_log_text_filter_widgets::{CommitLogTextFilterWidgets, WidgetData};
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::gui_element_provider::GuiElementProvider;
+use crate::text_filter_widgets::{TextFilterWidgets, WidgetData};
 
 
 pub(crate) struct CommitLogAuthorFilterWidgets
 {
-    inner: CommitLogTextFilterWidgets
+    inner: TextFilterWidgets
 }
 
 impl IEventHandler for CommitLogAuthorFilterWidgets
@@ -24,7 +24,7 @@ impl CommitLogAuthorFilterWidgets
 {
     pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
     {
-        Self{inner: CommitLogTextFilterWidgets::new(
+        Self{inner: TextFilterWidgets::new(
             &WidgetData{name: "Commit log author filter entry", source: Source::CommitLogAuthorFilterEntry},
             &WidgetData{name: "Commit log author filter case button", source: Source::CommitLogAuthorFilterCaseButton},
             &WidgetData{name: "Commit log author filter regex button", source: Source::CommitLogAuthorFilterRegexButton},
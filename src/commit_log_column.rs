@@ -4,14 +4,16 @@ pub(crate) enum CommitLogColumn
     Date,
     Author,
     Email,
+    Signature,
+    Refs,
     OriginalRow
 }
 
 impl CommitLogColumn
 {
-    pub const fn asArrayOfI32() -> [i32; 4]
+    pub const fn asArrayOfI32() -> [i32; 5]
     {
-        [0, 1, 2, 3]
+        [0, 1, 2, 3, 4]
     }
 }
 
@@ -1,15 +1,27 @@
 use crate::commit_log::CommitLog;
 use crate::commit_log_column::CommitLogColumn;
 use crate::gui_element_provider::GuiElementProvider;
+use crate::color::Color;
+use crate::commit_signing::{SignatureFormat, SignatureVerification};
+use crate::number_casts::ToI32 as _;
 use crate::original_row::OriginalRow;
+use crate::ref_label::{RefKind, RefLabel};
 
+use gtk::glib;
 use gtk::prelude::GtkListStoreExt as _;
 use gtk::prelude::GtkListStoreExtManual as _;
+use gtk::prelude::TreeModelExt as _;
 use time::{format_description::FormatItem, macros::format_description};
 use to_trait::To;
 
+const NO_PARENT: Option<&gtk::TreeIter> = None;
 const DATE_TIME_FORMAT: &[FormatItem] =
     format_description!("[day padding:space] [month repr:short] [year] [hour padding:space]:[minute]:[second]");
+const HEAD_BADGE_COLOR: Color = Color("#2e7d32");
+const LOCAL_BRANCH_BADGE_COLOR: Color = Color("#558b2f");
+const OTHER_WORKTREE_BRANCH_BADGE_COLOR: Color = Color("#827717");
+const REMOTE_BRANCH_BADGE_COLOR: Color = Color("#1565c0");
+const TAG_BADGE_COLOR: Color = Color("#ef6c00");
 
 
 pub(crate) struct CommitLogModel
@@ -26,6 +38,18 @@ impl CommitLogModel
         newSelf
     }
 
+    pub fn update(&self, commitLog: &CommitLog)
+    {
+        self.storeCommits(commitLog);
+    }
+
+    pub fn setSignatureVerification(&self, row: usize, verification: &SignatureVerification)
+    {
+        if let Some(iter) = self.store.iter_nth_child(NO_PARENT, row.toI32()) {
+            self.store.set_value(&iter, CommitLogColumn::Signature.into(), &glib::Value::from(&verification.to_string()));
+        }
+    }
+
 
     // private
 
@@ -39,11 +63,41 @@ impl CommitLogModel
                   (CommitLogColumn::Date.into(),        &formatDateTime(&commit.date)),
                   (CommitLogColumn::Author.into(),      &commit.author),
                   (CommitLogColumn::Email.into(),       &commit.email),
+                  (CommitLogColumn::Refs.into(),        &formatRefLabels(&commit.refs)),
+                  (CommitLogColumn::Signature.into(),   &formatSignatureFormat(commit.signatureFormat)),
                   (CommitLogColumn::OriginalRow.into(), &(row.try_to::<OriginalRow>().unwrap()))]);
         }
     }
 }
 
+// Shown until the signature is verified in the background.
+fn formatSignatureFormat(format: Option<SignatureFormat>) -> String
+{
+    format.map(|format| format.to_string()).unwrap_or_default()
+}
+
+fn formatRefLabels(refLabels: &[RefLabel]) -> String
+{
+    refLabels.iter().map(formatRefLabel).collect::<Vec<_>>().join(" ")
+}
+
+fn formatRefLabel(refLabel: &RefLabel) -> String
+{
+    let (color, weight) = match refLabel.kind {
+        RefKind::Head                => (HEAD_BADGE_COLOR, "bold"),
+        RefKind::LocalBranch         => (LOCAL_BRANCH_BADGE_COLOR, "normal"),
+        RefKind::OtherWorktreeBranch => (OTHER_WORKTREE_BRANCH_BADGE_COLOR, "normal"),
+        RefKind::RemoteBranch        => (REMOTE_BRANCH_BADGE_COLOR, "normal"),
+        RefKind::Tag                 => (TAG_BADGE_COLOR, "normal")
+    };
+    let name = match refLabel.kind {
+        RefKind::OtherWorktreeBranch => format!("{} (worktree)", refLabel.name),
+        _ => refLabel.name.clone()
+    };
+    format!(r#"<span background="{}" foreground="white" weight="{}"> {} </span>"#,
+            color, weight, glib::markup_escape_text(&name))
+}
+
 fn formatDateTime(date: &time::OffsetDateTime) -> String
 {
     date.format(DATE_TIME_FORMAT).unwrap()
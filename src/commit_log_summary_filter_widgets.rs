@@ -1,11 +1,11 @@
-use crate::commit_log_text_filter_widgets::{CommitLogTextFilterWidgets, WidgetData};
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::gui_element_provider::GuiElementProvider;
+use crate::text_filter_widgets::{TextFilterWidgets, WidgetData};
 
 
 pub(crate) struct CommitLogSummaryFilterWidgets
 {
-    inner: CommitLogTextFilterWidgets
+    inner: TextFilterWidgets
 }
 
 impl IEventHandler for CommitLogSummaryFilterWidgets
@@ -24,7 +24,7 @@ impl CommitLogSummaryFilterWidgets
 {
     pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
     {
-        Self{inner: CommitLogTextFilterWidgets::new(
+        Self{inner: TextFilterWidgets::new(
             &WidgetData{name: "Commit log summary filter entry", source: Source::CommitLogSummaryFilterEntry},
             &WidgetData{name: "Commit log summary filter case button", source: Source::CommitLogSummaryFilterCaseButton},
             &WidgetData{name: "Commit log summary filter regex button", source: Source::CommitLogSummaryFilterRegexButton},
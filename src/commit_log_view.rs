@@ -1,19 +1,46 @@
+use crate::commit_id::shortenCommitId;
 use crate::commit_log::CommitLog;
 use crate::commit_log_column::CommitLogColumn;
+use crate::commit_log_model::CommitLogModel;
 use crate::commit_log_selections_comparer::CommitLogSelectionsComparer;
+use crate::commit_message_dialog::askForNewCommitMessage;
+use crate::commit_signing::SignatureVerification;
+use crate::confirmation_dialog::askForConfirmation;
+use crate::error_handling::showErrorDialog;
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::fixup_commit::FixupKind;
 use crate::gui_element_provider::GuiElementProvider;
+use crate::history_rewriting::HistoryEdit;
+use crate::number_casts::ToI32 as _;
 use crate::original_row::OriginalRow;
+use crate::repository::Repository;
+use crate::ref_label::RefKind;
+use crate::reset_mode::ResetMode;
+use crate::split_commit_dialog::askForSplitCommit;
+use crate::tag_dialog::askForNewTag;
 use crate::tree_view::TreeView;
 
+use gtk::gdk;
+use gtk::prelude::GtkMenuExt as _;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MenuShellExt as _;
+use gtk::prelude::TreeViewExt as _;
+use gtk::prelude::WidgetExt as _;
 use gtk::traits::TreeModelExt;
 use gtk::traits::TreeSelectionExt;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const NO_PARENT: Option<&gtk::TreeIter> = None;
+const NO_TRIGGER_EVENT: Option<&gdk::Event> = None;
 
 
 pub(crate) struct CommitLogView
 {
     widget: TreeView,
     commitLog: CommitLog,
+    model: CommitLogModel,
+    repository: Rc<RefCell<Repository>>,
     sender: Sender
 }
 
@@ -22,9 +49,18 @@ impl IEventHandler for CommitLogView
     fn handle(&mut self, source: Source, event: &Event)
     {
         match event {
-            Event::RefilterRequested           => self.onRefilterRequested(),
-            Event::RefilterEnded               => self.onRefilterEnded(),
-            Event::SelectionChanged(selection) => self.onSelectionChanged(selection),
+            Event::AmendedCommit                                   => self.reload(),
+            Event::Committed                                       => self.reload(),
+            Event::HeadChanged                                     => self.reload(),
+            Event::MenuRequested                                   => self.onMenuRequested(),
+            Event::ReferencesChanged                               => self.reload(),
+            Event::RefilterRequested                               => self.onRefilterRequested(),
+            Event::RefilterEnded                                   => self.onRefilterEnded(),
+            Event::ResetConfirmationNeeded(commitId, mode, losses) => self.confirmReset(*commitId, *mode, losses),
+            Event::RightClicked(buttonEvent)                       => self.onRightClicked(buttonEvent),
+            Event::SelectionChanged(selection)                     => self.onSelectionChanged(selection),
+            Event::SignatureVerified(commitId, verification)       => self.onSignatureVerified(*commitId, verification),
+            Event::TagPushFailed(message)                          => showErrorDialog(message),
             _ => handleUnknown(source, event)
         }
     }
@@ -32,7 +68,13 @@ impl IEventHandler for CommitLogView
 
 impl CommitLogView
 {
-    pub fn new(commitLog: CommitLog, guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
+    pub fn new(
+        commitLog: CommitLog,
+        model: CommitLogModel,
+        repository: Rc<RefCell<Repository>>,
+        guiElementProvider: &GuiElementProvider,
+        sender: Sender)
+        -> Self
     {
         let widget = TreeView::new(
             guiElementProvider,
@@ -41,12 +83,20 @@ impl CommitLogView
             sender.clone(),
             Source::CommitLogViewWidget,
             &CommitLogColumn::asArrayOfI32());
-        Self{widget, commitLog, sender}
+        widget.prependMarkupCell(CommitLogColumn::Summary.into(), CommitLogColumn::Refs.into());
+        widget.enableMenuKey(sender.clone(), Source::CommitLogViewWidget);
+        Self{widget, commitLog, model, repository, sender}
     }
 
 
     // private
 
+    fn reload(&mut self)
+    {
+        self.commitLog = CommitLog::new(&self.repository.borrow());
+        self.model.update(&self.commitLog);
+    }
+
     fn onRefilterRequested(&mut self)
     {
         self.widget.getSelectionMut().blockSignals();
@@ -61,12 +111,167 @@ impl CommitLogView
     {
         match selection.selected() {
             Some((model, iter)) => {
-                let row = model.value(&iter, CommitLogColumn::OriginalRow.into()).get::<OriginalRow>().unwrap()
-                    .try_into().unwrap();
-                let commitId = self.commitLog.getCommit(row).unwrap().id;
+                let commitId = self.getCommitId(&model, &iter);
                 self.sender.send((Source::CommitLogView, Event::CommitSelected(commitId))).unwrap();
             },
             None => self.sender.send((Source::CommitLogView, Event::CommitUnselected)).unwrap()
         }
     }
+
+    fn onSignatureVerified(&self, commitId: git2::Oid, verification: &SignatureVerification)
+    {
+        if let Some(row) = self.commitLog.findRow(commitId) {
+            self.model.setSignatureVerification(row, verification);
+        }
+    }
+
+    fn onRightClicked(&self, event: &gdk::EventButton)
+    {
+        let (x, y) = event.position();
+        if let Some(row) = self.widget.getRowAtPosition(x, y) {
+            let model = self.widget.getModel();
+            let iter = model.iter_nth_child(NO_PARENT, row.toI32()).unwrap();
+            self.showCommitMenu(self.getCommitId(&model, &iter), Some(event));
+        }
+    }
+
+    fn onMenuRequested(&self)
+    {
+        if let Some((model, iter)) = self.widget.getWidget().selection().selected() {
+            self.showCommitMenu(self.getCommitId(&model, &iter), None);
+        }
+    }
+
+    fn confirmReset(&self, commitId: git2::Oid, mode: ResetMode, losses: &str)
+    {
+        let question = format!("Reset the current branch to commit {}?", shortenCommitId(commitId));
+        if askForConfirmation(&question, losses) {
+            self.sender.send((Source::CommitLogView, Event::ResetToCommitConfirmed(commitId, mode))).unwrap();
+        }
+    }
+
+    fn getCommitId(&self, model: &gtk::TreeModel, iter: &gtk::TreeIter) -> git2::Oid
+    {
+        let row = model.value(iter, CommitLogColumn::OriginalRow.into()).get::<OriginalRow>().unwrap()
+            .try_into().unwrap();
+        self.commitLog.getCommit(row).unwrap().id
+    }
+
+    // Without a button event the menu was requested with the keyboard, so it is shown over the view.
+    fn showCommitMenu(&self, commitId: git2::Oid, event: Option<&gdk::EventButton>)
+    {
+        let menu = gtk::Menu::new();
+        menu.append(&self.makeMenuItem("Checkout (detached HEAD)", move || Event::CheckoutCommitRequested(commitId)));
+
+        let resetMenu = gtk::Menu::new();
+        for (label, mode) in [("Soft", ResetMode::Soft), ("Mixed", ResetMode::Mixed), ("Hard", ResetMode::Hard)] {
+            resetMenu.append(&self.makeMenuItem(label, move || Event::ResetToCommitRequested(commitId, mode)));
+        }
+        let resetMenuItem = gtk::MenuItem::with_label("Reset current branch here");
+        resetMenuItem.set_submenu(Some(&resetMenu));
+        menu.append(&resetMenuItem);
+
+        menu.append(&gtk::SeparatorMenuItem::new());
+        menu.append(&self.makeRewordMenuItem(commitId));
+        menu.append(&self.makeMenuItem("Drop", move || Event::EditCommitRequested(commitId, HistoryEdit::Drop)));
+        menu.append(&self.makeSplitMenuItem(commitId));
+
+        menu.append(&gtk::SeparatorMenuItem::new());
+        menu.append(&self.makeMenuItem(
+            "Prepare fixup commit for this commit", move || Event::FixupCommitRequested(commitId, FixupKind::Fixup)));
+        menu.append(&self.makeMenuItem(
+            "Prepare squash commit into this commit", move || Event::FixupCommitRequested(commitId, FixupKind::Squash)));
+        menu.append(&self.makeMenuItem("Autosquash fixup and squash commits", || Event::AutosquashRequested));
+
+        menu.append(&gtk::SeparatorMenuItem::new());
+        menu.append(&self.makeCreateTagMenuItem(commitId));
+        for tagName in self.getTagNames(commitId) {
+            let name = tagName.clone();
+            menu.append(&self.makeMenuItem(
+                &format!("Delete tag {}", tagName), move || Event::DeleteTagRequested(name.clone())));
+            menu.append(&self.makeMenuItem(
+                &format!("Push tag {}", tagName), move || Event::PushTagRequested(tagName.clone())));
+        }
+
+        menu.show_all();
+        match event {
+            Some(event) => menu.popup_at_pointer(Some(event)),
+            None => menu.popup_at_widget(
+                self.widget.getWidget(), gdk::Gravity::Center, gdk::Gravity::NorthWest, NO_TRIGGER_EVENT)
+        }
+    }
+
+    fn makeCreateTagMenuItem(&self, commitId: git2::Oid) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label("Create tag...");
+        let sender = self.sender.clone();
+        menuItem.connect_activate(move |_item| {
+            if let Some(newTag) = askForNewTag(commitId) {
+                sender.send((Source::CommitLogView, Event::CreateTagRequested(commitId, newTag))).unwrap();
+            }
+        });
+        menuItem
+    }
+
+    fn makeRewordMenuItem(&self, commitId: git2::Oid) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label("Reword...");
+        let repository = Rc::clone(&self.repository);
+        let sender = self.sender.clone();
+        menuItem.connect_activate(move |_item| {
+            let message = repository.borrow().getCommitMessage(commitId).unwrap_or_default();
+            if let Some(newMessage) = askForNewCommitMessage(commitId, &message) {
+                let edit = HistoryEdit::Reword(newMessage);
+                sender.send((Source::CommitLogView, Event::EditCommitRequested(commitId, edit))).unwrap();
+            }
+        });
+        menuItem
+    }
+
+    fn makeSplitMenuItem(&self, commitId: git2::Oid) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label("Split...");
+        let repository = Rc::clone(&self.repository);
+        let sender = self.sender.clone();
+        menuItem.connect_activate(move |_item| {
+            let hunks = match repository.borrow().collectCommitHunks(commitId) {
+                Ok(hunks) => hunks,
+                Err(e) => {
+                    showErrorDialog(&e);
+                    return;
+                }
+            };
+            if hunks.len() < 2 {
+                showErrorDialog("The commit has only one change, so it cannot be split.");
+                return;
+            }
+            let message = repository.borrow().getCommitMessage(commitId).unwrap_or_default();
+            if let Some(splitCommit) = askForSplitCommit(commitId, &hunks, &message) {
+                let edit = HistoryEdit::Split(splitCommit);
+                sender.send((Source::CommitLogView, Event::EditCommitRequested(commitId, edit))).unwrap();
+            }
+        });
+        menuItem
+    }
+
+    fn getTagNames(&self, commitId: git2::Oid) -> Vec<String>
+    {
+        self.commitLog.getCommits().iter()
+            .find(|commit| commit.id == commitId)
+            .map(|commit| commit.refs.iter()
+                .filter(|refLabel| refLabel.kind == RefKind::Tag)
+                .map(|refLabel| refLabel.name.clone())
+                .collect())
+            .unwrap_or_default()
+    }
+
+    fn makeMenuItem(&self, label: &str, makeEvent: impl Fn() -> Event + 'static) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label(label);
+        let sender = self.sender.clone();
+        menuItem.connect_activate(move |_item| {
+            sender.send((Source::CommitLogView, makeEvent())).unwrap();
+        });
+        menuItem
+    }
 }
@@ -0,0 +1,37 @@
+use crate::commit_id::shortenCommitId;
+use crate::gui_element_provider::GuiElementProvider;
+use crate::text_view::EXCLUDE_HIDDEN_CHARACTERS;
+
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::WidgetExt as _;
+
+
+pub(crate) fn askForNewCommitMessage(commitId: git2::Oid, message: &str) -> Option<String>
+{
+    let guiElementProvider = GuiElementProvider::new(include_str!("commit_message_dialog.glade"));
+    let dialog = guiElementProvider.get::<gtk::Dialog>("dialog");
+    let messageView = guiElementProvider.get::<gtk::TextView>("Commit message view");
+    let applyButton = guiElementProvider.get::<gtk::Button>("Apply button");
+
+    dialog.set_title(&format!("Reword commit {}", shortenCommitId(commitId)));
+    let buffer = messageView.buffer().unwrap();
+    buffer.set_text(message);
+    buffer.connect_changed(move |buffer| applyButton.set_sensitive(!getText(buffer).trim().is_empty()));
+
+    let response = dialog.run();
+    let newMessage = getText(&buffer);
+    dialog.close();
+
+    match response {
+        gtk::ResponseType::Ok => Some(newMessage),
+        _ => None
+    }
+}
+
+fn getText(buffer: &gtk::TextBuffer) -> String
+{
+    buffer.text(&buffer.start_iter(), &buffer.end_iter(), EXCLUDE_HIDDEN_CHARACTERS).unwrap().into()
+}
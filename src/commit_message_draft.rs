@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DRAFT_FILE_NAME: &str = "rusty-git-gui-draft.toml";
+
+
+// Kept in the .git directory, so that every repository (and every worktree) has its own draft.
+// In amend mode the user's own message is stashed, so the amended one is kept next to it.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct CommitMessageDraft
+{
+    pub message: String,
+    pub amendedMessage: String
+}
+
+impl CommitMessageDraft
+{
+    pub fn load(gitDir: &Path) -> Self
+    {
+        let filePath = getDraftFilePath(gitDir);
+        let content = match fs::read_to_string(&filePath) {
+            Ok(content) => content,
+            Err(_) => return Self::default()
+        };
+        toml::from_str(&content)
+            .map_err(|e| eprintln!("Failed to parse commit message draft {}: {}", filePath.display(), e))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, gitDir: &Path)
+    {
+        let filePath = getDraftFilePath(gitDir);
+        if let Err(e) = fs::write(&filePath, toml::to_string(self).unwrap()) {
+            eprintln!("Failed to save commit message draft {}: {}", filePath.display(), e);
+        }
+    }
+}
+
+fn getDraftFilePath(gitDir: &Path) -> PathBuf
+{
+    gitDir.join(DRAFT_FILE_NAME)
+}
@@ -0,0 +1,124 @@
+use crate::commit_message_trailers::findTrailers;
+use crate::config::{self, LintLevel};
+
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+const CONVENTIONAL_COMMITS_PREFIX_PATTERN: &str = r"^[a-z]+(\([^()]+\))?!?: \S";
+const COMMENT_PREFIX: char = '#';
+
+
+pub(crate) struct CommitMessageLinter
+{
+    config: config::CommitMessageLinter,
+    conventionalCommitsPrefixRegex: Regex,
+    requiredRegex: Option<Result<Regex, regex::Error>>
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LintViolation
+{
+    pub level: LintLevel,
+    pub lineNumber: usize,
+    pub description: String
+}
+
+impl Display for LintViolation
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        let level = match self.level {
+            LintLevel::Error => "Error",
+            _ => "Warning"
+        };
+        write!(formatter, "{} in line {}: {}", level, self.lineNumber, self.description)
+    }
+}
+
+impl CommitMessageLinter
+{
+    pub fn new(config: &config::CommitMessageLinter) -> Self
+    {
+        let requiredRegex = match config.requiredPattern.is_empty() {
+            true => None,
+            false => Some(Regex::new(&config.requiredPattern))
+        };
+        Self{
+            config: config.clone(),
+            conventionalCommitsPrefixRegex: Regex::new(CONVENTIONAL_COMMITS_PREFIX_PATTERN).unwrap(),
+            requiredRegex
+        }
+    }
+
+    pub fn lint(&self, message: &str) -> Vec<LintViolation>
+    {
+        let mut violations = vec![];
+        let lines = message.lines().collect::<Vec<_>>();
+        let summary = match lines.first() {
+            Some(summary) => *summary,
+            None => return violations
+        };
+
+        let mut report = |level: LintLevel, lineIndex: usize, description: String| {
+            if level != LintLevel::Off {
+                violations.push(LintViolation{level, lineNumber: lineIndex + 1, description});
+            }
+        };
+
+        let summaryLength = summary.chars().count();
+        if summaryLength > self.config.summaryWidth {
+            report(self.config.summaryTooLong, 0, format!(
+                "the summary has {} characters, more than {}", summaryLength, self.config.summaryWidth));
+        }
+        if !self.conventionalCommitsPrefixRegex.is_match(summary) {
+            report(self.config.conventionalCommitsPrefix, 0,
+                   r#"the summary lacks a Conventional Commits prefix like "fix: " or "feat(scope): ""#.into());
+        }
+        if lines.get(1).is_some_and(|line| !line.is_empty()) {
+            report(self.config.missingBlankLineAfterSummary, 1, "the summary should be followed by a blank line".into());
+        }
+
+        // Comments are removed by git when committing and trailers like "Signed-off-by" cannot be wrapped,
+        // so neither is held to the line rules.
+        let trailerLineIndexes = findTrailerLineIndexes(message, &lines);
+        for (lineIndex, line) in lines.iter().enumerate() {
+            if line.starts_with(COMMENT_PREFIX) || trailerLineIndexes.contains(&lineIndex) {
+                continue;
+            }
+            if line.ends_with(char::is_whitespace) {
+                report(self.config.trailingWhitespace, lineIndex, "trailing whitespace".into());
+            }
+            let lineLength = line.chars().count();
+            if lineIndex > 1 && lineLength > self.config.bodyWidth {
+                report(self.config.bodyLineTooLong, lineIndex, format!(
+                    "the line has {} characters, more than {}", lineLength, self.config.bodyWidth));
+            }
+        }
+
+        match &self.requiredRegex {
+            Some(Ok(requiredRegex)) if !requiredRegex.is_match(message) => report(
+                self.config.requiredPatternMissing, 0, format!(
+                    "the message does not match the required pattern {}", requiredRegex.as_str())),
+            Some(Err(_)) => report(self.config.requiredPatternMissing, 0, format!(
+                "the required pattern {} is not a valid regular expression", self.config.requiredPattern)),
+            Some(Ok(_)) | None => ()
+        }
+        violations
+    }
+
+    pub const fn shouldBlockCommitOnErrors(&self) -> bool
+    {
+        self.config.blockCommitOnErrors
+    }
+}
+
+// Trailers make up the last paragraph of the message, which may be followed by blank lines.
+fn findTrailerLineIndexes(message: &str, lines: &[&str]) -> Range<usize>
+{
+    let trailerCount = findTrailers(message).len();
+    match lines.iter().rposition(|line| !line.trim().is_empty()) {
+        Some(lastLineIndex) if trailerCount > 0 => (lastLineIndex + 1 - trailerCount)..(lastLineIndex + 1),
+        _ => 0..0
+    }
+}
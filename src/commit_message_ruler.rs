@@ -0,0 +1,37 @@
+use gtk::cairo;
+use gtk::glib::prelude::ObjectExt as _;
+use gtk::glib::prelude::ToValue as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::WidgetExt as _;
+
+const AFTER_DEFAULT_HANDLER: bool = true;
+const PROPAGATE_DRAWING: bool = false;
+const SUMMARY_RULER_COLOR: (f64, f64, f64, f64) = (0.8, 0.3, 0.3, 0.5);
+const BODY_RULER_COLOR: (f64, f64, f64, f64) = (0.5, 0.5, 0.5, 0.4);
+
+
+pub(crate) fn setupCommitMessageRuler(widget: &gtk::TextView, summaryWidth: usize, bodyWidth: usize)
+{
+    // Drawing after the default handler keeps the rulers on top of the text view background.
+    widget.connect_local("draw", AFTER_DEFAULT_HANDLER, move |values| {
+        let view = values[0].get::<gtk::TextView>().unwrap();
+        let context = values[1].get::<cairo::Context>().unwrap();
+        drawRuler(&view, &context, bodyWidth, BODY_RULER_COLOR);
+        drawRuler(&view, &context, summaryWidth, SUMMARY_RULER_COLOR);
+        Some(PROPAGATE_DRAWING.to_value())
+    });
+}
+
+fn drawRuler(view: &gtk::TextView, context: &cairo::Context, column: usize, color: (f64, f64, f64, f64))
+{
+    let (textWidth, _textHeight) = view.create_pango_layout(Some(&"0".repeat(column))).pixel_size();
+    let (x, _y) = view.buffer_to_window_coords(gtk::TextWindowType::Widget, view.left_margin() + textWidth, 0);
+    let (red, green, blue, alpha) = color;
+    context.set_source_rgba(red, green, blue, alpha);
+    context.set_line_width(1.0);
+    context.move_to(f64::from(x) + 0.5, 0.0);
+    context.line_to(f64::from(x) + 0.5, f64::from(view.allocated_height()));
+    if let Err(e) = context.stroke() {
+        eprintln!("Failed to draw the commit message ruler: {}", e);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::config;
+use crate::event_constants::FORWARD_EVENT;
+use crate::number_casts::ToI32 as _;
+use crate::repository::Repository;
+use crate::spell_checker::SpellChecker;
+use crate::text_view::{EXCLUDE_HIDDEN_CHARACTERS, TextView};
+
+use gtk::glib::Cast as _;
+use gtk::pango;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MenuShellExt as _;
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextTagExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+const MOUSE_RIGHT_BUTTON: u32 = 3;
+
+
+pub(crate) struct CommitMessageSpelling
+{
+    spellChecker: Rc<RefCell<SpellChecker>>,
+    misspelledTag: gtk::TextTag,
+    areRepositoryWordsOutdated: Cell<bool>
+}
+
+impl CommitMessageSpelling
+{
+    pub fn new(widget: &gtk::TextView, textView: &TextView, config: &config::SpellChecking) -> Option<Self>
+    {
+        if !config.enabled {
+            return None;
+        }
+        let spellChecker = Rc::new(RefCell::new(SpellChecker::new(config)));
+
+        let misspelledTag = gtk::TextTag::new(Some("misspelled"));
+        misspelledTag.set_underline(pango::Underline::Error);
+        textView.registerTags(&[&misspelledTag]);
+        connectButtonPress(widget);
+        connectPopulatePopup(widget, Rc::clone(&spellChecker));
+        Some(Self{spellChecker, misspelledTag, areRepositoryWordsOutdated: Cell::new(true)})
+    }
+
+    // Words from the staged diff are collected only when there is a message to check.
+    pub fn check(&self, textView: &TextView, repository: &Repository)
+    {
+        textView.removeTag(&self.misspelledTag);
+        let text = textView.getText();
+        if text.trim().is_empty() {
+            return;
+        }
+        if self.areRepositoryWordsOutdated.replace(false) {
+            self.spellChecker.borrow_mut().setRepositoryText(&repository.collectStagedDiffText());
+        }
+        for range in self.spellChecker.borrow().findMisspelledWords(&text) {
+            textView.applyTagToCharacters(&self.misspelledTag, range.start.toI32(), range.end.toI32());
+        }
+    }
+
+    pub fn invalidateRepositoryWords(&self)
+    {
+        self.areRepositoryWordsOutdated.set(true);
+    }
+}
+
+// Right clicking moves the cursor, so that suggestions are offered for the clicked word rather than the one
+// under the cursor. A selection is kept, because the menu can operate on it.
+fn connectButtonPress(widget: &gtk::TextView)
+{
+    widget.connect_button_press_event(|view, event| {
+        if event.button() != MOUSE_RIGHT_BUTTON {
+            return FORWARD_EVENT;
+        }
+        let buffer = view.buffer().unwrap();
+        if buffer.has_selection() {
+            return FORWARD_EVENT;
+        }
+        let (x, y) = event.position();
+        let (bufferX, bufferY) = view.window_to_buffer_coords(gtk::TextWindowType::Widget, x.toI32(), y.toI32());
+        if let Some(iter) = view.iter_at_location(bufferX, bufferY) {
+            buffer.place_cursor(&iter);
+        }
+        FORWARD_EVENT
+    });
+}
+
+fn connectPopulatePopup(widget: &gtk::TextView, spellChecker: Rc<RefCell<SpellChecker>>)
+{
+    widget.connect_populate_popup(move |view, popup| {
+        let menu = match popup.downcast_ref::<gtk::Menu>() {
+            Some(menu) => menu,
+            None => return
+        };
+        let buffer = view.buffer().unwrap();
+        let (start, end) = match findWordAtCursor(&buffer) {
+            Some(bounds) => bounds,
+            None => return
+        };
+        let word = buffer.text(&start, &end, EXCLUDE_HIDDEN_CHARACTERS).unwrap();
+        let spellChecker = spellChecker.borrow();
+        if !spellChecker.isMisspelled(&word) {
+            return;
+        }
+
+        menu.prepend(&gtk::SeparatorMenuItem::new());
+        let suggestions = spellChecker.suggest(&word);
+        if suggestions.is_empty() {
+            let menuItem = gtk::MenuItem::with_label("(no spelling suggestions)");
+            menuItem.set_sensitive(false);
+            menu.prepend(&menuItem);
+        }
+        for suggestion in suggestions.into_iter().rev() {
+            let menuItem = gtk::MenuItem::with_label(&suggestion);
+            let buffer = buffer.clone();
+            let (startOffset, endOffset) = (start.offset(), end.offset());
+            menuItem.connect_activate(move |_item| {
+                buffer.delete(&mut buffer.iter_at_offset(startOffset), &mut buffer.iter_at_offset(endOffset));
+                buffer.insert(&mut buffer.iter_at_offset(startOffset), &suggestion);
+            });
+            menu.prepend(&menuItem);
+        }
+        menu.show_all();
+    });
+}
+
+fn findWordAtCursor(buffer: &gtk::TextBuffer) -> Option<(gtk::TextIter, gtk::TextIter)>
+{
+    let cursor = buffer.iter_at_mark(&buffer.get_insert()?);
+    if !cursor.inside_word() && !cursor.ends_word() {
+        return None;
+    }
+    let mut start = cursor;
+    if !start.starts_word() {
+        start.backward_word_start();
+    }
+    let mut end = cursor;
+    if !end.ends_word() {
+        end.forward_word_end();
+    }
+    Some((start, end))
+}
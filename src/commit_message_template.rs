@@ -0,0 +1,39 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const PER_REPOSITORY_TEMPLATE_FILE_NAME: &str = ".gitmessage";
+
+
+// Comment lines are dropped, because they would otherwise end up in the commit.
+pub(crate) fn loadCommitMessageTemplate(gitRepo: &git2::Repository) -> Option<String>
+{
+    let workingDir = gitRepo.workdir()?;
+    let templatePath = findTemplatePath(gitRepo, workingDir)?;
+    let template = fs::read_to_string(&templatePath)
+        .map_err(|e| eprintln!("Failed to read commit message template {}: {}", templatePath.display(), e))
+        .ok()?;
+
+    let template = template.lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let template = template.trim_end();
+    match template.is_empty() {
+        true => None,
+        false => Some(format!("{}\n", template))
+    }
+}
+
+fn findTemplatePath(gitRepo: &git2::Repository, workingDir: &Path) -> Option<PathBuf>
+{
+    let configuredPath = gitRepo.config().ok()
+        .and_then(|config| config.get_path("commit.template").ok())
+        .map(|path| match path.is_relative() {
+            true => workingDir.join(path),
+            false => path
+        });
+    match configuredPath {
+        Some(path) => Some(path),
+        None => Some(workingDir.join(PER_REPOSITORY_TEMPLATE_FILE_NAME)).filter(|path| path.is_file())
+    }
+}
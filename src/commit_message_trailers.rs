@@ -0,0 +1,86 @@
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+pub(crate) const SIGNED_OFF_BY: &str = "Signed-off-by";
+pub(crate) const CO_AUTHORED_BY: &str = "Co-authored-by";
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TrailerKind
+{
+    SignedOffBy,
+    CoAuthoredBy,
+    Custom
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Trailer
+{
+    pub key: String,
+    pub value: String
+}
+
+impl Display for Trailer
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        write!(formatter, "{}: {}", self.key, self.value)
+    }
+}
+
+pub(crate) fn isValidTrailerKey(key: &str) -> bool
+{
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+// Trailers are the lines of the last paragraph of a message, given that all of them look like "Key: value".
+pub(crate) fn findTrailers(message: &str) -> Vec<Trailer>
+{
+    match findTrailerParagraph(message) {
+        Some(paragraph) => paragraph.lines().filter_map(parseTrailer).collect(),
+        None => vec![]
+    }
+}
+
+pub(crate) fn addTrailer(message: &str, trailer: &Trailer) -> String
+{
+    if findTrailers(message).contains(trailer) {
+        return message.into();
+    }
+
+    let trimmedMessage = message.trim_end();
+    let separator = match (trimmedMessage.is_empty(), findTrailerParagraph(trimmedMessage).is_some()) {
+        (true, _) => "\n\n",
+        (false, true) => "\n",
+        (false, false) => "\n\n"
+    };
+    format!("{}{}{}\n", trimmedMessage, separator, trailer)
+}
+
+pub(crate) fn addTrailers(message: &str, trailers: &[Trailer]) -> String
+{
+    trailers.iter().fold(message.into(), |message, trailer| addTrailer(&message, trailer))
+}
+
+
+// private
+
+fn findTrailerParagraph(message: &str) -> Option<&str>
+{
+    let trimmedMessage = message.trim_end();
+    let (_, lastParagraph) = trimmedMessage.rsplit_once("\n\n")?;
+    let lastParagraph = lastParagraph.trim_start_matches('\n');
+    match !lastParagraph.is_empty() && lastParagraph.lines().all(|line| parseTrailer(line).is_some()) {
+        true => Some(lastParagraph),
+        false => None
+    }
+}
+
+fn parseTrailer(line: &str) -> Option<Trailer>
+{
+    static TRAILER_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = TRAILER_REGEX.get_or_init(|| Regex::new(r"^([A-Za-z0-9-]+): (.+)$").unwrap());
+    let captures = regex.captures(line)?;
+    Some(Trailer{key: captures[1].into(), value: captures[2].into()})
+}
@@ -1,17 +1,55 @@
+use crate::commit_message_draft::CommitMessageDraft;
+use crate::commit_message_ruler::setupCommitMessageRuler;
+use crate::commit_message_spelling::CommitMessageSpelling;
+use crate::commit_message_trailers::{
+    addTrailer,
+    addTrailers,
+    CO_AUTHORED_BY,
+    findTrailers,
+    SIGNED_OFF_BY,
+    Trailer,
+    TrailerKind};
+use crate::config::Config;
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::event_constants::FORWARD_EVENT;
+use crate::fixup_commit::{FixupKind, makeFixupMessage};
 use crate::gui_element_provider::GuiElementProvider;
 use crate::repository::Repository;
 use crate::text_view::{Notifications, TextView};
+use crate::trailer_dialog::askForTrailer;
 
+use gtk::glib;
+use gtk::glib::Cast as _;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MenuButtonExt as _;
+use gtk::prelude::MenuShellExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::WidgetExt as _;
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
+
+const NO_KEY: Option<&str> = None;
+const NO_SUGGESTIONS: &[String] = &[];
+const MAX_RECENT_MESSAGE_LABEL_LENGTH: usize = 60;
+const MAX_RECENT_MESSAGES: usize = 10;
+const DRAFT_SAVE_DELAY: Duration = Duration::from_secs(1);
 
 
 pub(crate) struct CommitMessageView
 {
     widget: TextView,
+    recentMessagesButton: gtk::MenuButton,
     repository: Rc<RefCell<Repository>>,
-    stashedMessage: String
+    sender: Sender,
+    stashedMessage: String,
+    isAmendEnabled: bool,
+    template: String,
+    draft: CommitMessageDraft,
+    spelling: Option<CommitMessageSpelling>,
+    isSpellCheckScheduled: bool,
+    isDraftSaveScheduled: bool,
+    restoredAmendedMessage: Option<String>
 }
 
 impl IEventHandler for CommitMessageView
@@ -20,11 +58,23 @@ impl IEventHandler for CommitMessageView
     {
         use crate::event::Event as E;
         match event {
-            E::BufferChanged       => self.onBufferChanged(source, event),
-            E::CommitAmendDisabled => self.onCommitAmendDisabled(),
-            E::CommitAmendEnabled  => self.onCommitAmendEnabled(),
-            E::Committed           => self.onCommitted(),
-            E::ZoomRequested(_)    => self.onZoomRequested(source, event),
+            E::AddedToStaged(_)                   => self.onStagedChangesChanged(),
+            E::AmendedCommit                      => self.onAmendedCommit(),
+            E::BufferChanged                      => self.onBufferChanged(source, event),
+            E::CommitAmendDisabled                => self.onCommitAmendDisabled(),
+            E::CommitAmendEnabled                 => self.onCommitAmendEnabled(),
+            E::Committed                          => self.onCommitted(),
+            E::DraftSaveRequested                 => self.onDraftSaveRequested(),
+            E::FixupCommitRequested(target, kind) => self.onFixupCommitRequested(*target, *kind),
+            E::FocusLost                          => self.saveDraft(),
+            E::QuitRequested                      => self.saveDraft(),
+            E::RecentCommitMessageChosen(message) => self.onRecentCommitMessageChosen(message),
+            E::Refreshed                          => self.onStagedChangesChanged(),
+            E::RemovedFromStaged(_)               => self.onStagedChangesChanged(),
+            E::SpellCheckRequested                => self.onSpellCheckRequested(),
+            E::TrailerRequested(kind)             => self.onTrailerRequested(*kind),
+            E::UpdatedInStaged(_)                 => self.onStagedChangesChanged(),
+            E::ZoomRequested(_)                   => self.onZoomRequested(source, event),
             _ => handleUnknown(source, event)
         }
     }
@@ -35,19 +85,51 @@ impl CommitMessageView
     pub fn new(
         guiElementProvider: &GuiElementProvider,
         repository: Rc<RefCell<Repository>>,
+        config: &Config,
         sender: Sender)
         -> Self
     {
-        Self{
-            widget: TextView::new(
-                guiElementProvider,
-                "Commit message view",
-                sender,
-                Source::CommitMessageView,
-                Notifications::Enabled),
+        let widget = guiElementProvider.get::<gtk::TextView>("Commit message view");
+        connectPopulatePopup(&widget, sender.clone());
+        connectFocusOut(&widget, sender.clone());
+        let linterConfig = &config.commitMessageLinter;
+        setupCommitMessageRuler(&widget, linterConfig.summaryWidth, linterConfig.bodyWidth);
+        let template = repository.borrow().loadCommitMessageTemplate().unwrap_or_default();
+        let draft = CommitMessageDraft::load(repository.borrow().getGitDir());
+        let textView = TextView::new(
+            guiElementProvider,
+            "Commit message view",
+            sender.clone(),
+            Source::CommitMessageView,
+            Notifications::Enabled);
+        let spelling = CommitMessageSpelling::new(&widget, &textView, &config.spellChecking);
+        let restoredAmendedMessage = match draft.amendedMessage.is_empty() {
+            true => None,
+            false => Some(draft.amendedMessage.clone())
+        };
+        let newSelf = Self{
+            widget: textView,
+            recentMessagesButton: guiElementProvider.get::<gtk::MenuButton>("Recent commit messages button"),
             repository,
-            stashedMessage: "".into()
+            sender,
+            stashedMessage: "".into(),
+            isAmendEnabled: false,
+            template,
+            draft,
+            spelling,
+            isSpellCheckScheduled: false,
+            isDraftSaveScheduled: false,
+            restoredAmendedMessage
+        };
+        match newSelf.draft.message.is_empty() {
+            true => newSelf.setText(&newSelf.template),
+            false => newSelf.setText(&newSelf.draft.message)
+        }
+        if newSelf.restoredAmendedMessage.is_some() {
+            newSelf.sender.send((Source::CommitMessageView, Event::CommitAmendRequested)).unwrap();
         }
+        newSelf.updateRecentMessagesMenu();
+        newSelf
     }
 
     pub fn getText(&self) -> String
@@ -66,26 +148,211 @@ impl CommitMessageView
     fn onBufferChanged(&mut self, source: Source, event: &Event)
     {
         self.widget.handle(source, event);
+        self.checkSpelling();
+        self.scheduleDraftSave();
+    }
+
+    // The draft is saved shortly after typing stops, so that it survives a crash without a write on every keystroke.
+    fn scheduleDraftSave(&mut self)
+    {
+        if self.isDraftSaveScheduled {
+            return;
+        }
+        self.isDraftSaveScheduled = true;
+        let sender = self.sender.clone();
+        glib::timeout_add_local_once(DRAFT_SAVE_DELAY, move || {
+            sender.send((Source::CommitMessageView, Event::DraftSaveRequested)).unwrap();
+        });
     }
 
-    fn onCommitted(&self)
+    fn onDraftSaveRequested(&mut self)
     {
-        self.widget.clear();
+        self.isDraftSaveScheduled = false;
+        self.saveDraft();
     }
 
+    fn onCommitted(&mut self)
+    {
+        self.updateRecentMessagesMenu();
+        self.invalidateRepositoryWords();
+        self.setText(&self.template);
+        self.saveDraft();
+    }
+
+    fn onAmendedCommit(&mut self)
+    {
+        self.updateRecentMessagesMenu();
+    }
+
+    // Trailers are carried over between the stashed message and the amended one, so they are read from the text
+    // being left, which drops the ones removed by the user in the meantime.
+    // An amended message restored from the draft already has them.
     fn onCommitAmendEnabled(&mut self)
     {
+        self.isAmendEnabled = true;
         self.stashedMessage = self.getText();
-        self.setText(&self.repository.borrow().getLastCommitMessage().unwrap());
+        match self.restoredAmendedMessage.take() {
+            Some(amendedMessage) => self.setText(&amendedMessage),
+            None => {
+                let lastCommitMessage = self.repository.borrow().getLastCommitMessage().unwrap();
+                self.setText(&addTrailers(&lastCommitMessage, &findTrailers(&self.stashedMessage)));
+            }
+        }
+        self.saveDraft();
     }
 
     fn onCommitAmendDisabled(&mut self)
     {
-        self.setText(&self.stashedMessage);
+        self.isAmendEnabled = false;
+        let lastCommitTrailers = findTrailers(&self.repository.borrow().getLastCommitMessage().unwrap_or_default());
+        let addedTrailers = findTrailers(&self.getText()).into_iter()
+            .filter(|trailer| !lastCommitTrailers.contains(trailer))
+            .collect::<Vec<_>>();
+        self.setText(&addTrailers(&self.stashedMessage, &addedTrailers));
+        self.saveDraft();
+    }
+
+    fn onTrailerRequested(&mut self, kind: TrailerKind)
+    {
+        let trailer = match kind {
+            TrailerKind::SignedOffBy => self.repository.borrow().getUserIdentity()
+                .map(|identity| Trailer{key: SIGNED_OFF_BY.into(), value: identity}),
+            TrailerKind::CoAuthoredBy =>
+                askForTrailer(Some(CO_AUTHORED_BY), &self.repository.borrow().collectAuthorIdentities()),
+            TrailerKind::Custom => askForTrailer(NO_KEY, NO_SUGGESTIONS)
+        };
+
+        if let Some(trailer) = trailer {
+            self.setText(&addTrailer(&self.getText(), &trailer));
+        }
+    }
+
+    fn onFixupCommitRequested(&self, target: git2::Oid, kind: FixupKind)
+    {
+        let targetSummary = match self.repository.borrow().getCommitSummary(target) {
+            Some(summary) => summary,
+            None => return
+        };
+        let currentMessage = match self.getText() == self.template {
+            true => "".into(),
+            false => self.getText()
+        };
+        self.setText(&makeFixupMessage(kind, &targetSummary, &currentMessage));
+    }
+
+    // Staging many files sends an event for each of them, so a single check is scheduled after all are handled.
+    fn onStagedChangesChanged(&mut self)
+    {
+        self.invalidateRepositoryWords();
+        if self.spelling.is_none() || self.isSpellCheckScheduled {
+            return;
+        }
+        self.isSpellCheckScheduled = true;
+        let sender = self.sender.clone();
+        glib::idle_add_local_once(move || {
+            sender.send((Source::CommitMessageView, Event::SpellCheckRequested)).unwrap();
+        });
+    }
+
+    fn onSpellCheckRequested(&mut self)
+    {
+        self.isSpellCheckScheduled = false;
+        self.checkSpelling();
+    }
+
+    fn onRecentCommitMessageChosen(&self, message: &str)
+    {
+        self.setText(message);
     }
 
     fn onZoomRequested(&mut self, source: Source, event: &Event)
     {
         self.widget.handle(source, event);
     }
+
+    // In amend mode the text view shows the amended commit, so the user's own message is the stashed one.
+    fn saveDraft(&mut self)
+    {
+        let (message, amendedMessage) = match self.isAmendEnabled {
+            true => (self.stashedMessage.clone(), self.getText()),
+            false => (self.getText(), String::new())
+        };
+        self.draft.message = match message == self.template {
+            true => "".into(),
+            false => message
+        };
+        self.draft.amendedMessage = amendedMessage;
+        self.draft.save(self.repository.borrow().getGitDir());
+    }
+
+    fn invalidateRepositoryWords(&self)
+    {
+        if let Some(spelling) = &self.spelling {
+            spelling.invalidateRepositoryWords();
+        }
+    }
+
+    fn checkSpelling(&self)
+    {
+        if let Some(spelling) = &self.spelling {
+            spelling.check(&self.widget, &self.repository.borrow());
+        }
+    }
+
+    fn updateRecentMessagesMenu(&self)
+    {
+        let recentMessages = self.repository.borrow().collectRecentUserCommitMessages(MAX_RECENT_MESSAGES);
+        let menu = gtk::Menu::new();
+        for message in &recentMessages {
+            let menuItem = gtk::MenuItem::with_label(&makeRecentMessageLabel(message));
+            let sender = self.sender.clone();
+            let message = message.clone();
+            menuItem.connect_activate(move |_item| {
+                sender.send((Source::CommitMessageView, Event::RecentCommitMessageChosen(message.clone()))).unwrap();
+            });
+            menu.append(&menuItem);
+        }
+        menu.show_all();
+        self.recentMessagesButton.set_popup(Some(&menu));
+        self.recentMessagesButton.set_sensitive(!recentMessages.is_empty());
+    }
+}
+
+fn makeRecentMessageLabel(message: &str) -> String
+{
+    let summary = message.lines().next().unwrap_or_default();
+    match summary.chars().count() > MAX_RECENT_MESSAGE_LABEL_LENGTH {
+        true => format!("{}...", summary.chars().take(MAX_RECENT_MESSAGE_LABEL_LENGTH).collect::<String>()),
+        false => summary.into()
+    }
+}
+
+fn connectFocusOut(widget: &gtk::TextView, sender: Sender)
+{
+    widget.connect_focus_out_event(move |_view, _event| {
+        sender.send((Source::CommitMessageView, Event::FocusLost)).unwrap();
+        FORWARD_EVENT
+    });
+}
+
+fn connectPopulatePopup(widget: &gtk::TextView, sender: Sender)
+{
+    widget.connect_populate_popup(move |_view, popup| {
+        let menu = match popup.downcast_ref::<gtk::Menu>() {
+            Some(menu) => menu,
+            None => return
+        };
+        menu.append(&gtk::SeparatorMenuItem::new());
+        for (label, kind) in [("Add Signed-off-by", TrailerKind::SignedOffBy),
+                              ("Add Co-authored-by...", TrailerKind::CoAuthoredBy),
+                              ("Add trailer...", TrailerKind::Custom)] {
+            let menuItem = gtk::MenuItem::with_label(label);
+            let sender = sender.clone();
+            menuItem.connect_activate(move |_item| {
+                sender.send((Source::CommitMessageView, Event::TrailerRequested(kind))).unwrap();
+            });
+            menu.append(&menuItem);
+        }
+        menu.show_all();
+    });
 }
@@ -0,0 +1,188 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SignatureFormat
+{
+    OpenPgp,
+    Ssh,
+    X509
+}
+
+impl SignatureFormat
+{
+    pub fn fromSignature(signature: &[u8]) -> Option<Self>
+    {
+        if signature.starts_with(b"-----BEGIN PGP SIGNATURE-----") {
+            Some(Self::OpenPgp)
+        } else if signature.starts_with(b"-----BEGIN SSH SIGNATURE-----") {
+            Some(Self::Ssh)
+        } else if signature.starts_with(b"-----BEGIN SIGNED MESSAGE-----") {
+            Some(Self::X509)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for SignatureFormat
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        match self {
+            Self::OpenPgp => write!(formatter, "GPG"),
+            Self::Ssh     => write!(formatter, "SSH"),
+            Self::X509    => write!(formatter, "X.509")
+        }
+    }
+}
+
+pub(crate) struct CommitSigner
+{
+    format: SignatureFormat,
+    program: String,
+    signingKey: Option<String>,
+    committerIdentity: String
+}
+
+impl CommitSigner
+{
+    // Returns None when commit.gpgsign is not enabled.
+    pub fn fromRepository(gitRepo: &git2::Repository) -> Result<Option<Self>, String>
+    {
+        let config = gitRepo.config().map_err(|e| format!("Failed to read git configuration: {}", e))?;
+        if !config.get_bool("commit.gpgsign").unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let format = match config.get_string("gpg.format").as_deref() {
+            Ok("openpgp") | Err(_) => SignatureFormat::OpenPgp,
+            Ok("ssh") => SignatureFormat::Ssh,
+            Ok("x509") => SignatureFormat::X509,
+            Ok(other) => return Err(format!("Unsupported signing format in gpg.format: {}", other))
+        };
+        let program = match format {
+            SignatureFormat::OpenPgp => config.get_string("gpg.openpgp.program")
+                .or_else(|_| config.get_string("gpg.program"))
+                .unwrap_or_else(|_| "gpg".into()),
+            SignatureFormat::Ssh => config.get_string("gpg.ssh.program").unwrap_or_else(|_| "ssh-keygen".into()),
+            SignatureFormat::X509 => config.get_string("gpg.x509.program").unwrap_or_else(|_| "gpgsm".into())
+        };
+        let signingKey = config.get_string("user.signingkey").ok().filter(|key| !key.is_empty());
+        let committer = gitRepo.signature()
+            .map_err(|e| format!("Failed to get a name and/or email of the commit author: {}", e))?;
+        let committerIdentity = format!(
+            "{} <{}>", committer.name().unwrap_or_default(), committer.email().unwrap_or_default());
+
+        Ok(Some(Self{format, program, signingKey, committerIdentity}))
+    }
+
+    pub fn sign(&self, content: &str) -> Result<String, String>
+    {
+        match self.format {
+            SignatureFormat::OpenPgp | SignatureFormat::X509 => self.signWithGpg(content),
+            SignatureFormat::Ssh => self.signWithSsh(content)
+        }
+    }
+
+
+    // private
+
+    fn signWithGpg(&self, content: &str) -> Result<String, String>
+    {
+        let key = self.signingKey.as_deref().unwrap_or(&self.committerIdentity);
+        let mut child = Command::new(&self.program)
+            .args(["--status-fd=2", "-bsau", key])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to run {}: {}", self.program, e))?;
+        // The commit is written from another thread, as the program may fill its output pipes before it has read
+        // the whole commit, which would block both sides if the output was drained only after the writing.
+        let mut stdin = child.stdin.take().unwrap();
+        let content = content.to_owned();
+        let writer = std::thread::spawn(move || stdin.write_all(content.as_bytes()));
+        let output = child.wait_with_output()
+            .map_err(|e| format!("Failed to run {}: {}", self.program, e))?;
+        let writeResult = writer.join().unwrap();
+
+        let signature = String::from_utf8_lossy(&output.stdout).into_owned();
+        if !output.status.success() || signature.is_empty() {
+            return Err(format!("Failed to sign the commit with {}:\n{}",
+                               self.program, String::from_utf8_lossy(&output.stderr).trim_end()));
+        }
+        writeResult.map_err(|e| format!("Failed to pass the commit to {}: {}", self.program, e))?;
+        Ok(signature)
+    }
+
+    fn signWithSsh(&self, content: &str) -> Result<String, String>
+    {
+        let key = self.signingKey.as_deref()
+            .ok_or("Commit signing with SSH requires user.signingkey to be set.")?;
+        // ssh-keygen reads the commit and writes the signature next to it, so both live in a private directory
+        // with a random name, which is removed together with its content when dropped.
+        let temporaryDir = tempfile::Builder::new().prefix("rusty-git-gui-").tempdir()
+            .map_err(|e| format!("Failed to create a temporary directory: {}", e))?;
+        let contentPath = writeTemporaryFile(temporaryDir.path(), "commit", content)?;
+        // Like git, treat a literal public key as a request to sign with the matching key from ssh-agent.
+        let literalKey = key.strip_prefix("key::").or_else(|| key.starts_with("ssh-").then_some(key));
+        let (keyPath, useAgent) = match literalKey {
+            Some(literalKey) => (writeTemporaryFile(temporaryDir.path(), "key.pub", literalKey)?, true),
+            None => (expandHomeDir(key), false)
+        };
+
+        let mut command = Command::new(&self.program);
+        command.args(["-Y", "sign", "-n", "git", "-f"]).arg(&keyPath);
+        if useAgent {
+            command.arg("-U");
+        }
+        let output = command.arg(&contentPath).stdin(Stdio::null()).output()
+            .map_err(|e| format!("Failed to run {}: {}", self.program, e))?;
+        if !output.status.success() {
+            return Err(format!("Failed to sign the commit with {}:\n{}",
+                               self.program, String::from_utf8_lossy(&output.stderr).trim_end()));
+        }
+
+        let signaturePath = contentPath.with_extension("sig");
+        fs::read_to_string(&signaturePath)
+            .map_err(|e| format!("Failed to read the SSH signature {}: {}", signaturePath.display(), e))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct SignatureVerification
+{
+    pub format: SignatureFormat,
+    pub failure: Option<String>
+}
+
+impl Display for SignatureVerification
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result
+    {
+        match &self.failure {
+            None => write!(formatter, "{} verified", self.format),
+            Some(failure) => write!(formatter, "{} unverified: {}", self.format, failure)
+        }
+    }
+}
+
+fn writeTemporaryFile(dir: &Path, name: &str, content: &str) -> Result<PathBuf, String>
+{
+    let path = dir.join(name);
+    fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+fn expandHomeDir(path: &str) -> PathBuf
+{
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(relativePath), Some(homeDir)) => homeDir.join(relativePath),
+        _ => Path::new(path).to_owned()
+    }
+}
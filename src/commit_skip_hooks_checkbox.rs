@@ -0,0 +1,63 @@
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::prelude::ToggleButtonExt as _;
+
+
+pub(crate) struct CommitSkipHooksCheckbox
+{
+    widget: gtk::CheckButton,
+    sender: Sender
+}
+
+impl IEventHandler for CommitSkipHooksCheckbox
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::Toggled(isSelected) => self.onToggled(*isSelected),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl CommitSkipHooksCheckbox
+{
+    #[must_use]
+    pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
+    {
+        let widget = guiElementProvider.get::<gtk::CheckButton>("Commit skip hooks checkbox");
+        let newSelf = Self{widget, sender};
+        newSelf.connectWidget();
+        newSelf
+    }
+
+
+    // private
+
+    fn connectWidget(&self)
+    {
+        let eventSender = self.sender.clone();
+        self.widget.connect_toggled(move |checkbox|
+            eventSender.send((Source::CommitSkipHooksCheckbox, Event::Toggled(checkbox.is_active()))).unwrap());
+    }
+
+    fn onToggled(&self, isSelected: bool)
+    {
+        if isSelected {
+            self.notifyOnSelected();
+        } else {
+            self.notifyOnUnselected();
+        }
+    }
+
+    fn notifyOnSelected(&self)
+    {
+        self.sender.send((Source::CommitSkipHooksCheckbox, Event::CommitHooksDisabled)).unwrap();
+    }
+
+    fn notifyOnUnselected(&self)
+    {
+        self.sender.send((Source::CommitSkipHooksCheckbox, Event::CommitHooksEnabled)).unwrap();
+    }
+}
@@ -1,6 +1,8 @@
+use crate::file_changes_sort_order::FileChangesSortOrder;
 use crate::pane::PanePosition;
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -19,7 +21,13 @@ pub(crate) struct Config
     #[serde(default)]
     pub diffAndCommitPane: DiffAndCommitPane,
     #[serde(default)]
-    pub commitLogFilters: CommitLogFilters
+    pub commitLogFilters: CommitLogFilters,
+    #[serde(default)]
+    pub commitMessageLinter: CommitMessageLinter,
+    #[serde(default)]
+    pub spellChecking: SpellChecking,
+    #[serde(default)]
+    pub hexDiff: HexDiff
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -81,14 +89,20 @@ impl Default for MainPane
 #[derive(Debug, Deserialize, Serialize)]
 pub(crate) struct FileChangesPane
 {
-    pub position: PanePosition
+    pub position: PanePosition,
+    #[serde(default)]
+    pub showsDirectoryTree: bool,
+    #[serde(default)]
+    pub showsIgnoredFiles: bool,
+    #[serde(default)]
+    pub sortOrder: FileChangesSortOrder
 }
 
 impl Default for FileChangesPane
 {
     fn default() -> Self
     {
-        Self{position: 200}
+        Self{position: 200, showsDirectoryTree: false, showsIgnoredFiles: false, sortOrder: FileChangesSortOrder::Path}
     }
 }
 
@@ -147,3 +161,87 @@ pub(crate) struct TextFilter
     pub caseSensitive: bool,
     pub usesRegex: bool
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct CommitMessageLinter
+{
+    pub summaryWidth: usize,
+    pub bodyWidth: usize,
+    pub blockCommitOnErrors: bool,
+    pub summaryTooLong: LintLevel,
+    pub bodyLineTooLong: LintLevel,
+    pub missingBlankLineAfterSummary: LintLevel,
+    pub trailingWhitespace: LintLevel,
+    pub conventionalCommitsPrefix: LintLevel,
+    pub requiredPattern: String,
+    pub requiredPatternMissing: LintLevel
+}
+
+impl Default for CommitMessageLinter
+{
+    fn default() -> Self
+    {
+        Self{
+            summaryWidth: 50,
+            bodyWidth: 72,
+            blockCommitOnErrors: false,
+            summaryTooLong: LintLevel::Warning,
+            bodyLineTooLong: LintLevel::Warning,
+            missingBlankLineAfterSummary: LintLevel::Warning,
+            trailingWhitespace: LintLevel::Warning,
+            conventionalCommitsPrefix: LintLevel::Off,
+            requiredPattern: "".into(),
+            requiredPatternMissing: LintLevel::Error
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum LintLevel
+{
+    Off,
+    Warning,
+    Error
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct SpellChecking
+{
+    pub enabled: bool,
+    pub language: String,
+    pub dictionaryDirs: Vec<PathBuf>
+}
+
+impl Default for SpellChecking
+{
+    fn default() -> Self
+    {
+        Self{
+            enabled: true,
+            language: "en_US".into(),
+            dictionaryDirs: ["/usr/share/hunspell", "/usr/share/myspell", "/usr/share/myspell/dicts"]
+                .into_iter()
+                .map(PathBuf::from)
+                .collect()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct HexDiff
+{
+    // In bytes, larger binary files are only summarized.
+    pub maxSize: usize
+}
+
+impl Default for HexDiff
+{
+    fn default() -> Self
+    {
+        Self{maxSize: 256 * 1024}
+    }
+}
@@ -1,6 +1,7 @@
 use crate::config::{CommitLogFilters, Config};
 use crate::config_path::ConfigPath;
 use crate::event::{Event, handleUnknown, IEventHandler, Source};
+use crate::file_changes_sort_order::FileChangesSortOrder;
 use crate::pane::PanePosition;
 
 use std::path::PathBuf;
@@ -24,9 +25,14 @@ impl IEventHandler for ConfigStore
             (S::FileChangesPane,   E::PositionChanged(position))        => self.onFileChangesnPanePositionChanged(*position),
             (S::MainPane,          E::PositionChanged(position))        => self.onMainPanePositionChanged(*position),
             (_,                    E::ActivePageChanged(name))          => self.onMainStackActivePageChanged(name),
+            (_,                    E::DirectoryTreeDisabled)            => self.onDirectoryTreeToggled(false),
+            (_,                    E::DirectoryTreeEnabled)             => self.onDirectoryTreeToggled(true),
             (_,                    E::FiltersUpdated(filters))          => self.onFiltersUpdated(filters),
+            (_,                    E::IgnoredFilesDisabled)             => self.onIgnoredFilesToggled(false),
+            (_,                    E::IgnoredFilesEnabled)              => self.onIgnoredFilesToggled(true),
             (_,                    E::MaximizationChanged(isMaximized)) => self.onMaximizationChanged(*isMaximized),
             (_,                    E::QuitRequested)                    => self.onQuitRequested(),
+            (_,                    E::SortOrderChosen(sortOrder))       => self.onSortOrderChosen(*sortOrder),
             _ => handleUnknown(source, event)
         }
     }
@@ -74,6 +80,21 @@ impl ConfigStore
         self.config.mainPane.position = position;
     }
 
+    fn onDirectoryTreeToggled(&mut self, showsDirectoryTree: bool)
+    {
+        self.config.fileChangesPane.showsDirectoryTree = showsDirectoryTree;
+    }
+
+    fn onIgnoredFilesToggled(&mut self, showsIgnoredFiles: bool)
+    {
+        self.config.fileChangesPane.showsIgnoredFiles = showsIgnoredFiles;
+    }
+
+    fn onSortOrderChosen(&mut self, sortOrder: FileChangesSortOrder)
+    {
+        self.config.fileChangesPane.sortOrder = sortOrder;
+    }
+
     fn onFiltersUpdated(&mut self, filters: &CommitLogFilters)
     {
         self.config.commitLogFilters = filters.clone();
@@ -0,0 +1,20 @@
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::MessageDialogExt as _;
+
+const NO_WINDOW_PARENT: Option<&gtk::Window> = None;
+
+
+pub(crate) fn askForConfirmation(question: &str, details: &str) -> bool
+{
+    let dialog = gtk::MessageDialog::new(
+        NO_WINDOW_PARENT,
+        gtk::DialogFlags::MODAL,
+        gtk::MessageType::Question,
+        gtk::ButtonsType::OkCancel,
+        question);
+    dialog.set_secondary_text(Some(details));
+    let response = dialog.run();
+    dialog.close();
+    response == gtk::ResponseType::Ok
+}
@@ -3,7 +3,7 @@ use crate::line_number::LineNumber;
 use crate::text_view::TextView;
 
 use gtk::prelude::TextTagExt as _;
-use crate::diff_formatter::{FormattedDiff, LineFormat};
+use crate::diff_formatter::{CharacterFormat, FormattedCharacters, FormattedDiff, LineFormat};
 
 
 pub(crate) struct DiffColorizer
@@ -45,10 +45,10 @@ impl DiffColorizer
     pub fn colorize(&mut self, textView: &TextView, diff: &FormattedDiff)
     {
         textView.setText(&diff.text);
-        self.applyTags(textView, &diff.lineFormats);
+        self.applyTags(textView, diff);
     }
 
-    pub fn update(&mut self, textView: &TextView, differences: Vec<LineDiff>, lineFormats: &[LineFormat])
+    pub fn update(&mut self, textView: &TextView, differences: Vec<LineDiff>, diff: &FormattedDiff)
     {
         if !diffRequiresUpdating(&differences) {
             return;
@@ -56,18 +56,30 @@ impl DiffColorizer
 
         textView.removeTags();
         updateDiff(textView, differences);
-        self.applyTags(textView, lineFormats);
+        self.applyTags(textView, diff);
     }
 
 
     // private
 
-    fn applyTags(&mut self, textView: &TextView, lineFormats: &[LineFormat])
+    fn applyTags(&mut self, textView: &TextView, diff: &FormattedDiff)
     {
         self.state = State::Normal;
         self.tagStartLine = 0.into();
-        self.applyTagsBasedOnLineTypes(textView, lineFormats);
+        self.applyTagsBasedOnLineTypes(textView, &diff.lineFormats);
         self.closeLastOpenTag(textView);
+        self.applyTagsToCharacters(textView, &diff.characterFormats);
+    }
+
+    fn applyTagsToCharacters(&self, textView: &TextView, characterFormats: &[FormattedCharacters])
+    {
+        for characters in characterFormats {
+            let tag = match characters.format {
+                CharacterFormat::Added => &self.addedLineTag,
+                CharacterFormat::Removed => &self.removedLineTag
+            };
+            textView.applyTagToLineCharacters(tag, characters.line.into(), characters.columns.clone());
+        }
     }
 
     fn applyTagsBasedOnLineTypes(&mut self, textView: &TextView, lineFormats: &[LineFormat])
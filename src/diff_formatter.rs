@@ -1,4 +1,7 @@
 use crate::file_change::FileChange;
+use crate::file_status::FileStatusKind;
+
+use std::ops::Range;
 
 const FORMATTING_SUCCEEDED: bool = true;
 
@@ -44,6 +47,11 @@ impl DiffFormatter
         self.output
     }
 
+    pub fn append(&mut self, diff: FormattedDiff)
+    {
+        self.output.append(diff);
+    }
+
 
     // private
 
@@ -117,7 +125,36 @@ enum FormatterMode
 pub(crate) struct FormattedDiff
 {
     pub text: String,
-    pub lineFormats: Vec<LineFormat>
+    pub lineFormats: Vec<LineFormat>,
+    pub characterFormats: Vec<FormattedCharacters>
+}
+
+impl FormattedDiff
+{
+    pub fn append(&mut self, diff: FormattedDiff)
+    {
+        let lineOffset = self.lineFormats.len();
+        self.text.push_str(&diff.text);
+        self.lineFormats.extend(diff.lineFormats);
+        self.characterFormats.extend(diff.characterFormats.into_iter().map(
+            |characters| FormattedCharacters{line: characters.line + lineOffset, ..characters}));
+    }
+}
+
+// Characters within a line, which are formatted apart from the rest of it, e.g. changed bytes of a hex diff.
+#[derive(Clone, Debug)]
+pub(crate) struct FormattedCharacters
+{
+    pub line: usize,
+    pub columns: Range<usize>,
+    pub format: CharacterFormat
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum CharacterFormat
+{
+    Added,
+    Removed
 }
 
 #[derive(Clone, Debug)]
@@ -137,12 +174,21 @@ pub(crate) enum LineFormat
 
 fn formatDiffHeader(fileChange: &FileChange) -> FormattedDiff
 {
-    match &fileChange.oldPath {
-        Some(oldPath) => {
-            let text = format!("renamed file\nold path: {}\nnew path: {}\n", oldPath, fileChange.path);
-            let lineFormats = vec![LineFormat::TopHeader; 3];
-            FormattedDiff{text, lineFormats}
+    let text = match fileChange.status.kind {
+        FileStatusKind::Renamed => {
+            let mut text = format!(
+                "renamed file\nold path: {}\nnew path: {}\n",
+                fileChange.oldPath.as_deref().unwrap_or_default(),
+                fileChange.path);
+            if let Some(similarity) = fileChange.status.similarity {
+                text += &format!("similarity: {}%\n", similarity);
+            }
+            text
         },
-        None => FormattedDiff::default()
-    }
+        FileStatusKind::Typechange => "file type changed\n".into(),
+        FileStatusKind::Conflicted => "conflicted file\n".into(),
+        _ => return FormattedDiff::default()
+    };
+    let lineFormats = vec![LineFormat::TopHeader; text.lines().count()];
+    FormattedDiff{text, lineFormats, characterFormats: vec![]}
 }
@@ -1,11 +1,19 @@
+use crate::blob_source::{BlobSource, FileContent, NO_SIZE_LIMIT};
+use crate::config::Config;
 use crate::diff_colorizer::DiffColorizer;
-use crate::diff_formatter::{DiffFormatter, FormattedDiff};
+use crate::diff_formatter::{DiffFormatter, FormattedDiff, LineFormat};
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::error_handling::exit;
 use crate::file_change::FileChange;
+use crate::file_status::FileStatusKind;
 use crate::gui_element_provider::GuiElementProvider;
+use crate::hex_diff::formatHexDiff;
+use crate::image_diff_view::{ImageDiffView, ImageDiffWidgetData, isImagePath};
 use crate::line_diff::LineDiff;
 use crate::repository::Repository;
+use crate::submodule_change::{formatSubmoduleChange, SubmoduleChangeKind};
+use crate::text_filter_widgets::WidgetData;
+use crate::text_search::{TextSearch, TextSearchWidgetData};
 use crate::text_view::{Notifications, TextView};
 
 use std::cell::RefCell;
@@ -17,8 +25,12 @@ pub(crate) struct DiffView
     widget: TextView,
     repository: Rc<RefCell<Repository>>,
     diffColorizer: DiffColorizer,
+    search: TextSearch,
+    imageDiffView: ImageDiffView,
     displayState: DisplayedFileChange,
-    stagedChangeDiffMaker: DiffMaker
+    stagedChangeDiffMaker: DiffMaker,
+    stagedChangeOldBlobSource: BlobSource,
+    hexDiffMaxSize: usize
 }
 
 #[derive(Eq, PartialEq)]
@@ -37,15 +49,21 @@ impl IEventHandler for DiffView
     {
         use crate::event::{Source as S, Event as E};
         match (source, event) {
-            (S::CommitAmendCheckbox, E::CommitAmendDisabled)                => self.onCommitAmendDisabled(),
-            (S::CommitAmendCheckbox, E::CommitAmendEnabled)                 => self.onCommitAmendEnabled(),
-            (S::DiffView,            E::ZoomRequested(_))                   => self.onZoomRequested(source, event),
-            (S::StagedChangesView,   E::FileChangeRefreshed(fileChangeOpt)) => self.onStagedOptionalChangeRefreshed(fileChangeOpt),
-            (S::StagedChangesView,   E::FileChangeSelected(fileChange))     => self.onStagedChangeSelected(fileChange),
-            (S::StagedChangesView,   E::FileChangeUnselected)               => self.onStagedChangeUnselected(),
-            (S::UnstagedChangesView, E::FileChangeRefreshed(fileChangeOpt)) => self.onUnstagedOptionalChangeRefreshed(fileChangeOpt),
-            (S::UnstagedChangesView, E::FileChangeSelected(fileChange))     => self.onUnstagedChangeSelected(fileChange),
-            (S::UnstagedChangesView, E::FileChangeUnselected)               => self.onUnstagedChangeUnselected(),
+            (S::ApplicationWindow,        E::SearchRequested)                    => self.search.open(),
+            (S::CommitAmendCheckbox,      E::CommitAmendDisabled)                => self.onCommitAmendDisabled(),
+            (S::CommitAmendCheckbox,      E::CommitAmendEnabled)                 => self.onCommitAmendEnabled(),
+            (S::DiffSearchCaseButton,     E::Toggled(isEnabled))                 => self.search.setCaseSensitivityEnabled(&self.widget, *isEnabled),
+            (S::DiffSearchEntry,          E::TextEntered(text))                  => self.search.searchFor(&self.widget, text),
+            (S::DiffSearchNextButton,     E::Clicked)                            => self.search.selectNextMatch(&self.widget),
+            (S::DiffSearchPreviousButton, E::Clicked)                            => self.search.selectPreviousMatch(&self.widget),
+            (S::DiffSearchRegexButton,    E::Toggled(isEnabled))                 => self.search.setRegexEnabled(&self.widget, *isEnabled),
+            (S::DiffView,                 E::ZoomRequested(_))                   => self.onZoomRequested(source, event),
+            (S::StagedChangesView,        E::FileChangeRefreshed(fileChangeOpt)) => self.onStagedOptionalChangeRefreshed(fileChangeOpt),
+            (S::StagedChangesView,        E::FileChangeSelected(fileChange))     => self.onStagedChangeSelected(fileChange),
+            (S::StagedChangesView,        E::FileChangeUnselected)               => self.onStagedChangeUnselected(),
+            (S::UnstagedChangesView,      E::FileChangeRefreshed(fileChangeOpt)) => self.onUnstagedOptionalChangeRefreshed(fileChangeOpt),
+            (S::UnstagedChangesView,      E::FileChangeSelected(fileChange))     => self.onUnstagedChangeSelected(fileChange),
+            (S::UnstagedChangesView,      E::FileChangeUnselected)               => self.onUnstagedChangeUnselected(),
             _ => handleUnknown(source, event)
         }
     }
@@ -56,18 +74,25 @@ impl DiffView
     pub fn new(
         guiElementProvider: &GuiElementProvider,
         repository: Rc<RefCell<Repository>>,
+        config: &Config,
         sender: Sender)
         -> Self
     {
         let widget = TextView::new(
-            guiElementProvider, "Diff view", sender, Source::DiffView, Notifications::Disabled);
+            guiElementProvider, "Diff view", sender.clone(), Source::DiffView, Notifications::Disabled);
         let diffColorizer = DiffColorizer::new(&widget);
+        let search = TextSearch::new(guiElementProvider, &widget, &makeSearchWidgetData(), sender);
+        let imageDiffView = ImageDiffView::new(guiElementProvider, &makeImageDiffWidgetData());
         Self{
             widget,
             repository,
             diffColorizer,
+            search,
+            imageDiffView,
             displayState: DisplayedFileChange::None,
-            stagedChangeDiffMaker: makeDiffForStagedChange
+            stagedChangeDiffMaker: makeDiffForStagedChange,
+            stagedChangeOldBlobSource: BlobSource::Head,
+            hexDiffMaxSize: config.hexDiff.maxSize
         }
     }
 
@@ -76,8 +101,8 @@ impl DiffView
 
     fn onUnstagedChangeSelected(&mut self, fileChange: &FileChange)
     {
-        match fileChange.status.as_str() {
-            "Status(WT_RENAMED)" => self.onFileChangeSelected(
+        match fileChange.status.kind {
+            FileStatusKind::Renamed => self.onFileChangeSelected(
                 fileChange, makeDiffForUnstagedRenamedFile, DisplayedFileChange::Unstaged),
             _ => self.onFileChangeSelected(
                 fileChange, makeDiffForUnstagedChange, DisplayedFileChange::Unstaged)
@@ -86,8 +111,8 @@ impl DiffView
 
     fn onStagedChangeSelected(&mut self, fileChange: &FileChange)
     {
-        match fileChange.status.as_str() {
-            "Status(INDEX_RENAMED)" => {
+        match fileChange.status.kind {
+            FileStatusKind::Renamed => {
                 self.onFileChangeSelected(fileChange, makeDiffForStagedRenamedFile, DisplayedFileChange::Staged);
             },
             _ => self.onFileChangeSelected(fileChange, self.stagedChangeDiffMaker, DisplayedFileChange::Staged)
@@ -100,8 +125,15 @@ impl DiffView
         diffMaker: DiffMaker,
         newDisplayState: DisplayedFileChange)
     {
-        let diff = self.makeFormattedDiff(fileChange, diffMaker);
+        if isImagePath(&fileChange.path) {
+            self.showImageDiff(fileChange, newDisplayState);
+            return;
+        }
+
+        self.imageDiffView.hide();
+        let diff = self.makeFormattedDiff(fileChange, diffMaker, &newDisplayState);
         self.diffColorizer.colorize(&self.widget, &diff);
+        self.search.refresh(&self.widget);
         self.displayState = newDisplayState;
     }
 
@@ -111,8 +143,14 @@ impl DiffView
         diffMaker: DiffMaker,
         newDisplayState: DisplayedFileChange)
     {
+        if isImagePath(&fileChange.path) {
+            self.showImageDiff(fileChange, newDisplayState);
+            return;
+        }
+
+        self.imageDiffView.hide();
         let oldDiff = self.widget.getText();
-        let newDiff = self.makeFormattedDiff(fileChange, diffMaker);
+        let newDiff = self.makeFormattedDiff(fileChange, diffMaker, &newDisplayState);
         let changeset = similar::TextDiff::configure().diff_lines(&oldDiff, &newDiff.text);
         let changeset: Vec<_> = changeset.iter_all_changes().map(
             |change| match change.tag() {
@@ -120,7 +158,8 @@ impl DiffView
                 similar::ChangeTag::Delete => LineDiff::Delete(change.to_string_lossy()),
                 similar::ChangeTag::Insert => LineDiff::Insert(change.to_string_lossy())
             }).collect();
-        self.diffColorizer.update(&self.widget, changeset, &newDiff.lineFormats);
+        self.diffColorizer.update(&self.widget, changeset, &newDiff);
+        self.search.refresh(&self.widget);
         self.displayState = newDisplayState;
     }
 
@@ -164,8 +203,8 @@ impl DiffView
 
     fn onUnstagedChangeRefreshed(&mut self, fileChange: &FileChange)
     {
-        match fileChange.status.as_str() {
-            "Status(WT_RENAMED)" => self.onFileChangeRefreshed(
+        match fileChange.status.kind {
+            FileStatusKind::Renamed => self.onFileChangeRefreshed(
                 fileChange, makeDiffForUnstagedRenamedFile, DisplayedFileChange::Unstaged),
             _ => self.onFileChangeRefreshed(
                 fileChange, makeDiffForUnstagedChange, DisplayedFileChange::Unstaged)
@@ -185,27 +224,100 @@ impl DiffView
     fn onCommitAmendDisabled(&mut self)
     {
         self.stagedChangeDiffMaker = makeDiffForStagedChange;
+        self.stagedChangeOldBlobSource = BlobSource::Head;
     }
 
     fn onCommitAmendEnabled(&mut self)
     {
         self.stagedChangeDiffMaker = makeDiffForStagedChangeToAmend;
+        self.stagedChangeOldBlobSource = BlobSource::ParentOfHead;
+    }
+
+    fn showImageDiff(&mut self, fileChange: &FileChange, newDisplayState: DisplayedFileChange)
+    {
+        let (oldContent, newContent) = self.loadFileContents(fileChange, &newDisplayState, NO_SIZE_LIMIT);
+        let oldContent = oldContent.and_then(|content| content.data);
+        let newContent = newContent.and_then(|content| content.data);
+        // The text is cleared, so that a later textual diff is not compared against a stale one.
+        self.widget.clear();
+        self.search.refresh(&self.widget);
+        self.imageDiffView.show(oldContent.as_deref(), newContent.as_deref());
+        self.displayState = newDisplayState;
+    }
+
+    fn loadFileContents(&self, fileChange: &FileChange, displayState: &DisplayedFileChange, maxSize: usize)
+        -> (Option<FileContent>, Option<FileContent>)
+    {
+        let (oldSource, newSource) = match displayState {
+            DisplayedFileChange::Staged => (self.stagedChangeOldBlobSource, BlobSource::Index),
+            _ => (BlobSource::Index, BlobSource::Workdir)
+        };
+        let oldPath = fileChange.oldPath.as_ref().unwrap_or(&fileChange.path);
+        let repository = self.repository.borrow();
+        (repository.loadFileContent(oldPath, oldSource, maxSize),
+         repository.loadFileContent(&fileChange.path, newSource, maxSize))
     }
 
     fn clear(&mut self)
     {
+        self.imageDiffView.hide();
         self.widget.clear();
+        self.search.refresh(&self.widget);
         self.displayState = DisplayedFileChange::None;
     }
 
-    fn makeFormattedDiff(&self, fileChange: &FileChange, diffMaker: DiffMaker) -> FormattedDiff
+    fn makeFormattedDiff(
+        &self,
+        fileChange: &FileChange,
+        diffMaker: DiffMaker,
+        displayState: &DisplayedFileChange)
+        -> FormattedDiff
     {
-        let mut diffFormatter = DiffFormatter::newForFileChange(fileChange);
         let repository = self.repository.borrow();
+        let submoduleChangeKind = match displayState {
+            DisplayedFileChange::Staged => SubmoduleChangeKind::Staged,
+            _ => SubmoduleChangeKind::Unstaged
+        };
+        if let Some(submoduleChange) = repository.findSubmoduleChange(&fileChange.path, submoduleChangeKind) {
+            return formatSubmoduleChange(&submoduleChange);
+        }
+
+        let mut diffFormatter = DiffFormatter::newForFileChange(fileChange);
         let diff = (diffMaker)(fileChange, &repository);
         diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| diffFormatter.format(&line))
             .unwrap_or_else(|e| exit(&format!("Failed to format diff: {}", e)));
-        diffFormatter.takeOutput()
+        let formattedDiff = diffFormatter.takeOutput();
+        if !formattedDiff.lineFormats.iter().any(|lineFormat| matches!(lineFormat, LineFormat::BinaryLine)) {
+            return formattedDiff;
+        }
+
+        let (oldContent, newContent) = self.loadFileContents(fileChange, displayState, self.hexDiffMaxSize);
+        formatHexDiff(oldContent.as_ref(), newContent.as_ref(), self.hexDiffMaxSize)
+    }
+}
+
+fn makeSearchWidgetData() -> TextSearchWidgetData
+{
+    TextSearchWidgetData{
+        searchBarName: "Diff search bar",
+        entry: WidgetData{name: "Diff search entry", source: Source::DiffSearchEntry},
+        caseButton: WidgetData{name: "Diff search case button", source: Source::DiffSearchCaseButton},
+        regexButton: WidgetData{name: "Diff search regex button", source: Source::DiffSearchRegexButton},
+        previousButton: WidgetData{name: "Diff search previous button", source: Source::DiffSearchPreviousButton},
+        nextButton: WidgetData{name: "Diff search next button", source: Source::DiffSearchNextButton},
+        matchCountLabelName: "Diff search match count label"
+    }
+}
+
+fn makeImageDiffWidgetData() -> ImageDiffWidgetData
+{
+    ImageDiffWidgetData{
+        boxName: "Image diff box",
+        infoLabelName: "Image diff info label",
+        drawingAreaName: "Image diff drawing area",
+        modeComboBoxName: "Image diff mode combo box",
+        opacityScaleName: "Image diff opacity scale",
+        replacedWidgetName: Some("Diff view scrolled window")
     }
 }
 
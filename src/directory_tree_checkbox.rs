@@ -0,0 +1,65 @@
+use crate::config::Config;
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::prelude::ToggleButtonExt as _;
+
+
+pub(crate) struct DirectoryTreeCheckbox
+{
+    widget: gtk::CheckButton,
+    sender: Sender
+}
+
+impl IEventHandler for DirectoryTreeCheckbox
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::Toggled(isSelected) => self.onToggled(*isSelected),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl DirectoryTreeCheckbox
+{
+    #[must_use]
+    pub fn new(guiElementProvider: &GuiElementProvider, config: &Config, sender: Sender) -> Self
+    {
+        let widget = guiElementProvider.get::<gtk::CheckButton>("Directory tree checkbox");
+        widget.set_active(config.fileChangesPane.showsDirectoryTree);
+        let newSelf = Self{widget, sender};
+        newSelf.connectWidget();
+        newSelf
+    }
+
+
+    // private
+
+    fn connectWidget(&self)
+    {
+        let eventSender = self.sender.clone();
+        self.widget.connect_toggled(move |checkbox|
+            eventSender.send((Source::DirectoryTreeCheckbox, Event::Toggled(checkbox.is_active()))).unwrap());
+    }
+
+    fn onToggled(&self, isSelected: bool)
+    {
+        if isSelected {
+            self.notifyOnSelected();
+        } else {
+            self.notifyOnUnselected();
+        }
+    }
+
+    fn notifyOnSelected(&self)
+    {
+        self.sender.send((Source::DirectoryTreeCheckbox, Event::DirectoryTreeEnabled)).unwrap();
+    }
+
+    fn notifyOnUnselected(&self)
+    {
+        self.sender.send((Source::DirectoryTreeCheckbox, Event::DirectoryTreeDisabled)).unwrap();
+    }
+}
@@ -1,6 +1,7 @@
 use gtk::glib::Cast as _;
 use gtk::prelude::ContainerExt as _;
 use gtk::prelude::DialogExt as _;
+use gtk::prelude::GtkWindowExt as _;
 use gtk::prelude::LabelExt as _;
 use gtk::prelude::MessageDialogExt as _;
 
@@ -15,21 +16,29 @@ pub(crate) fn exit(errorMessage: &str) -> !
 
 pub(crate) fn showErrorDialog(message: &str)
 {
-    let dialog = makeDialog(message);
+    let dialog = makeDialog(message, gtk::MessageType::Error);
     dialog.run();
 }
 
+// For outcomes which are not failures, but still need the user's attention.
+pub(crate) fn showInfoDialog(message: &str)
+{
+    let dialog = makeDialog(message, gtk::MessageType::Info);
+    dialog.run();
+    dialog.close();
+}
+
 
 // private
 
-fn makeDialog(errorMessage: &str) -> gtk::MessageDialog
+fn makeDialog(message: &str, messageType: gtk::MessageType) -> gtk::MessageDialog
 {
     let dialog = gtk::MessageDialog::new(
         NO_WINDOW_PARENT,
         gtk::DialogFlags::MODAL,
-        gtk::MessageType::Error,
+        messageType,
         gtk::ButtonsType::Ok,
-        errorMessage);
+        message);
 
     let messageArea = dialog.message_area();
     let messageArea = messageArea.downcast_ref::<gtk::Box>()
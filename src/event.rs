@@ -1,7 +1,19 @@
+use crate::commit_author_options::CommitAuthorOptions;
 use crate::commit_message::CommitMessage;
+use crate::commit_message_trailers::TrailerKind;
+use crate::commit_signing::SignatureVerification;
 use crate::config::{AuthorFilter, CommitLogFilters};
 use crate::file_change::{FileChange, FileChangeUpdate};
+use crate::file_changes_sort_order::FileChangesSortOrder;
+use crate::file_path::FilePathString;
+use crate::fixup_commit::FixupKind;
+use crate::history_rewriting::HistoryEdit;
+use crate::ignore_pattern::IgnorePattern;
+use crate::index_flags::IndexFlag;
+use crate::new_tag::NewTag;
 use crate::pane::PanePosition;
+use crate::reset_mode::ResetMode;
+use crate::worktree::NewWorktree;
 
 use gtk::{gdk, glib};
 
@@ -12,24 +24,53 @@ pub(crate) enum Event
     // application window
     MaximizationChanged(IsMaximized),
     QuitRequested,
+    SearchRequested,
 
     // repository
     AddedToStaged(FileChange),
     AddedToUnstaged(FileChange),
+    AmendCommitHooksFinished(Result<CommitMessage, ErrorMessage>),
     AmendedCommit,
+    AutosquashFinished(Option<ErrorMessage>),
+    CommitFailed,
+    CommitHooksFinished(Result<CommitMessage, ErrorMessage>),
     Committed,
+    HeadChanged,
+    ReferencesChanged,
     Refreshed,
     RemovedFromStaged(FileChange),
     RemovedFromUnstaged(FileChange),
+    ResetConfirmationNeeded(git2::Oid, ResetMode, ResetLosses),
+    SignatureVerified(git2::Oid, SignatureVerification),
+    TagPushFailed(ErrorMessage),
     UpdatedInStaged(FileChangeUpdate),
     UpdatedInUnstaged(FileChangeUpdate),
 
     // requests to repository
     AmendCommitRequested(CommitMessage),
+    AutosquashRequested,
+    CheckoutCommitRequested(git2::Oid),
     CommitRequested(CommitMessage),
+    CreateTagRequested(git2::Oid, NewTag),
+    CreateWorktreeRequested(NewWorktree),
+    DeleteTagRequested(TagName),
+    EditCommitRequested(git2::Oid, HistoryEdit),
+    IgnoreRequested(IgnorePattern),
+    OpenSubmoduleRequested(SubmodulePath),
+    PruneWorktreesRequested,
+    PushTagRequested(TagName),
     RefreshRequested,
+    ResetToCommitConfirmed(git2::Oid, ResetMode),
+    ResetToCommitRequested(git2::Oid, ResetMode),
+    SetIndexFlagRequested(FilePathString, IndexFlag, IsEnabled),
+    StageManyRequested(Vec<FileChange>),
+    StagePathsRequested(Vec<FilePathString>),
     StageRequested(FileChange),
+    UnstageManyRequested(Vec<FileChange>),
+    UnstagePathsRequested(Vec<FilePathString>),
     UnstageRequested(FileChange),
+    UntrackRequested(FilePathString),
+    UpdateSubmoduleRequested(SubmodulePath),
 
     // button
     Clicked,
@@ -40,15 +81,42 @@ pub(crate) enum Event
     // commit amend mode
     CommitAmendEnabled,
     CommitAmendDisabled,
+    CommitAmendRequested,
+
+    // commit author
+    CommitAuthorOptionsChanged(CommitAuthorOptions),
+
+    // commit hooks
+    CommitHooksEnabled,
+    CommitHooksDisabled,
+
+    // directory tree
+    DirectoryTreeEnabled,
+    DirectoryTreeDisabled,
+
+    // ignored files
+    IgnoredFilesEnabled,
+    IgnoredFilesDisabled,
+
+    // file changes sort order
+    SortOrderChosen(FileChangesSortOrder),
 
     // commit log
     CommitSelected(git2::Oid),
     CommitUnselected,
+    FixupCommitRequested(git2::Oid, FixupKind),
+
+    // commit message view
+    DraftSaveRequested,
+    RecentCommitMessageChosen(String),
+    SpellCheckRequested,
+    TrailerRequested(TrailerKind),
 
     // text view
     BufferChanged,
     Filled,
     Emptied,
+    FocusLost,
     ZoomRequested(gdk::EventScroll),
 
     // file changes view
@@ -59,6 +127,7 @@ pub(crate) enum Event
     // tree view
     RowActivated(gtk::TreePath),
     RightClicked(gdk::EventButton),
+    MenuRequested,
 
     // tree selection
     SelectionChanged(gtk::TreeSelection),
@@ -95,8 +164,12 @@ pub(crate) enum Event
     ValidAuthorTextInputted,
 }
 
+type ErrorMessage = String;
 type IsEnabled = bool;
 type IsMaximized = bool;
+type ResetLosses = String;
+type SubmodulePath = String;
+type TagName = String;
 pub(crate) type FilterIndex = usize;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -104,7 +177,13 @@ pub(crate) enum Source
 {
     ApplicationWindow,
     CommitAmendCheckbox,
+    CommitAuthorButton,
     CommitButton,
+    CommitDiffSearchCaseButton,
+    CommitDiffSearchEntry,
+    CommitDiffSearchNextButton,
+    CommitDiffSearchPreviousButton,
+    CommitDiffSearchRegexButton,
     CommitDiffViewWidget,
     CommitLogAuthorFilterEntry,
     CommitLogAuthorFilterCaseButton,
@@ -122,9 +201,23 @@ pub(crate) enum Source
     CommitLogView,
     CommitLogViewWidget,
     CommitMessageView,
+    CommitSkipHooksCheckbox,
     DiffAndCommitPane,
+    DiffSearchCaseButton,
+    DiffSearchEntry,
+    DiffSearchNextButton,
+    DiffSearchPreviousButton,
+    DiffSearchRegexButton,
     DiffView,
+    DirectoryTreeCheckbox,
+    FileChangesFilterCaseButton,
+    FileChangesFilterEntry,
+    FileChangesFilterRegexButton,
+    FileChangesModelFilter,
     FileChangesPane,
+    FileChangesSortComboBox,
+    IgnoredFilesCheckbox,
+    IndexFlagsButton,
     MainPane,
     MainStack,
     RefreshButton,
@@ -132,7 +225,8 @@ pub(crate) enum Source
     StagedChangesStore,
     StagedChangesView,
     UnstagedChangesStore,
-    UnstagedChangesView
+    UnstagedChangesView,
+    WorktreesButton
 }
 
 pub(crate) trait IEventHandler
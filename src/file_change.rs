@@ -1,12 +1,20 @@
+use crate::color::Color;
 use crate::file_path::FilePathString;
+use crate::file_status::FileStatus;
+
+use std::ops::Add;
+
+const ADDED_LINES_COLOR: Color = Color("#2e7d32");
+const REMOVED_LINES_COLOR: Color = Color("#c62828");
 
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct FileChange
 {
-    pub status: String,
+    pub status: FileStatus,
     pub path: FilePathString,
-    pub oldPath: Option<FilePathString>
+    pub oldPath: Option<FilePathString>,
+    pub lineStats: LineStats
 }
 
 impl FileChange
@@ -14,9 +22,10 @@ impl FileChange
     pub fn from(delta: &git2::DiffDelta) -> Self
     {
         let mut newSelf = Self{
-            status: format!("{:?}", delta.status()),
+            status: FileStatus::fromDelta(delta.status()),
             path: delta.new_file().path().unwrap().to_str().unwrap().into(),
-            oldPath: None};
+            oldPath: None,
+            lineStats: LineStats::default()};
 
         if let Some(oldPath) = delta.old_file().path() {
             let oldPath = oldPath.to_str().unwrap();
@@ -34,3 +43,38 @@ pub(crate) struct FileChangeUpdate
     pub old: FileChange,
     pub new: FileChange
 }
+
+// Binary files and changes of the file mode only have no lines counted.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct LineStats
+{
+    pub added: usize,
+    pub removed: usize
+}
+
+impl LineStats
+{
+    pub const fn getTotal(&self) -> usize
+    {
+        self.added + self.removed
+    }
+
+    pub fn formatMarkup(&self) -> String
+    {
+        if self.getTotal() == 0 {
+            return String::new();
+        }
+        format!(r#"<span foreground="{}">+{}</span> <span foreground="{}">−{}</span>"#,
+                ADDED_LINES_COLOR, self.added, REMOVED_LINES_COLOR, self.removed)
+    }
+}
+
+impl Add for LineStats
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self
+    {
+        Self{added: self.added + other.added, removed: self.removed + other.removed}
+    }
+}
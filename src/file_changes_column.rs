@@ -1,7 +1,12 @@
 pub(crate) enum FileChangesColumn
 {
     Status,
-    Path
+    Path,
+    FullPath,
+    ShortStatus,
+    StatusIcon,
+    StatusColor,
+    LineStats
 }
 
 const FILE_CHANGES_COLUMN_COUNT: usize = 2;
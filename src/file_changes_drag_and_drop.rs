@@ -0,0 +1,182 @@
+use crate::event::{Event, Sender, Source};
+use crate::file_path::FilePathString;
+use crate::ifile_changes_store::IFileChangesStore;
+use crate::number_casts::ToI32 as _;
+use crate::tree_model_utils::toChildRowPaths;
+
+use gtk::{gdk, glib};
+use gtk::glib::object::Cast as _;
+use gtk::glib::object::ObjectExt as _;
+use gtk::glib::translate::{IntoGlib as _, Stash, ToGlibPtr};
+use gtk::prelude::DragContextExtManual as _;
+use gtk::prelude::TreeSelectionExt as _;
+use gtk::prelude::TreeViewExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+const FILE_PATHS_TARGET: &str = "application/x-rusty-git-gui-file-paths";
+const URI_LIST_TARGET: &str = "text/uri-list";
+const FILE_PATHS_TARGET_INFO: u32 = 0;
+const URI_LIST_TARGET_INFO: u32 = 1;
+const BYTE_FORMAT: i32 = 8;
+const FILE_PATHS_SEPARATOR: &str = "\n";
+const DO_NOT_DELETE_DRAGGED_DATA: bool = false;
+// Returned from drag motion handlers, which have set the drag status themselves.
+const DROP_IS_POSSIBLE_HERE: bool = true;
+
+
+pub(crate) type DropEventMaker = fn(Vec<FilePathString>) -> Event;
+
+#[derive(Clone, Copy)]
+pub(crate) enum DroppedItems
+{
+    FileChanges,
+    FileChangesAndFiles
+}
+
+// Rows are dragged as paths of their file changes, so a receiving view does not need the store of the dragging one.
+pub(crate) fn setupDragSource<StoreType>(
+    widget: &gtk::TreeView, modelFilter: &gtk::TreeModelFilter, store: Rc<RefCell<StoreType>>)
+    where StoreType: IFileChangesStore + 'static
+{
+    enableModelDragSource(widget, &[makeFilePathsTarget()], gdk::DragAction::MOVE);
+    let modelFilter = modelFilter.clone();
+    widget.connect_drag_data_get(move |widget, _context, selectionData, _info, _time| {
+        let (rowPaths, _model) = widget.selection().selected_rows();
+        let paths = store.borrow().collectFileChangesInRows(&toChildRowPaths(&modelFilter, &rowPaths))
+            .into_iter()
+            .map(|fileChange| fileChange.path)
+            .collect::<Vec<_>>();
+        selectionData.set(&selectionData.target(), BYTE_FORMAT, paths.join(FILE_PATHS_SEPARATOR).as_bytes());
+    });
+}
+
+// Files dropped from file managers come as URIs, which are made relative to the working directory.
+pub(crate) fn setupDropTarget(
+    widget: &gtk::TreeView,
+    droppedItems: DroppedItems,
+    workingDir: &Path,
+    makeDropEvent: DropEventMaker,
+    sender: Sender,
+    source: Source)
+{
+    let targets = match droppedItems {
+        DroppedItems::FileChanges => vec![makeFilePathsTarget()],
+        DroppedItems::FileChangesAndFiles => vec![makeFilePathsTarget(), makeUriListTarget()]
+    };
+    enableModelDragDest(widget, &targets, gdk::DragAction::MOVE | gdk::DragAction::COPY);
+
+    // The default handler of tree views would request the dragged data already during motion, to check whether rows
+    // can be dropped into the model, which would then be taken for a drop.
+    widget.connect_drag_motion(|widget, context, _x, _y, time| {
+        let action = match isDraggedFrom(widget, context) {
+            true => gdk::DragAction::empty(),
+            false => context.suggested_action()
+        };
+        context.drag_status(action, time);
+        DROP_IS_POSSIBLE_HERE
+    });
+
+    let workingDir = canonicalize(workingDir);
+    widget.connect_drag_data_received(move |widget, context, _x, _y, selectionData, info, time| {
+        // The default handler of tree views would try to insert rows into the model.
+        widget.stop_signal_emission_by_name("drag-data-received");
+        let paths = match info {
+            FILE_PATHS_TARGET_INFO if !isDraggedFrom(widget, context) => parseFilePaths(&selectionData.data()),
+            URI_LIST_TARGET_INFO => convertUrisToFilePaths(&selectionData.uris(), &workingDir),
+            _ => vec![]
+        };
+        context.drag_finish(!paths.is_empty(), DO_NOT_DELETE_DRAGGED_DATA, time);
+        if !paths.is_empty() {
+            sender.send((source, makeDropEvent(paths))).unwrap();
+        }
+    });
+}
+
+
+// private
+
+// Unlike the drag and drop of widgets, the one of tree views keeps rubber band selection working, but gtk-rs does not
+// wrap it.
+fn enableModelDragSource(widget: &gtk::TreeView, targets: &[gtk::TargetEntry], actions: gdk::DragAction)
+{
+    let targets = makeTargetArray(targets);
+    // SAFETY: the targets are copied by GTK and the tree view is a valid widget.
+    unsafe {
+        gtk::ffi::gtk_tree_view_enable_model_drag_source(
+            widget.to_glib_none().0,
+            gdk::ModifierType::BUTTON1_MASK.into_glib(),
+            targets.entries.as_ptr(),
+            targets.entries.len().toI32(),
+            actions.into_glib());
+    }
+}
+
+fn enableModelDragDest(widget: &gtk::TreeView, targets: &[gtk::TargetEntry], actions: gdk::DragAction)
+{
+    let targets = makeTargetArray(targets);
+    // SAFETY: the targets are copied by GTK and the tree view is a valid widget.
+    unsafe {
+        gtk::ffi::gtk_tree_view_enable_model_drag_dest(
+            widget.to_glib_none().0, targets.entries.as_ptr(), targets.entries.len().toI32(), actions.into_glib());
+    }
+}
+
+// The entries point into the stashes, so both are kept together.
+struct TargetArray<'a>
+{
+    _stashes: Vec<Stash<'a, *const gtk::ffi::GtkTargetEntry, gtk::TargetEntry>>,
+    entries: Vec<gtk::ffi::GtkTargetEntry>
+}
+
+fn makeTargetArray(targets: &[gtk::TargetEntry]) -> TargetArray<'_>
+{
+    let stashes = targets.iter()
+        .map(ToGlibPtr::<*const gtk::ffi::GtkTargetEntry>::to_glib_none)
+        .collect::<Vec<_>>();
+    // SAFETY: the stashes hold valid target entries, whose strings live as long as the stashes.
+    let entries = stashes.iter().map(|stash| unsafe { *stash.0 }).collect();
+    TargetArray{_stashes: stashes, entries}
+}
+
+fn makeFilePathsTarget() -> gtk::TargetEntry
+{
+    gtk::TargetEntry::new(FILE_PATHS_TARGET, gtk::TargetFlags::SAME_APP, FILE_PATHS_TARGET_INFO)
+}
+
+fn makeUriListTarget() -> gtk::TargetEntry
+{
+    gtk::TargetEntry::new(URI_LIST_TARGET, gtk::TargetFlags::OTHER_APP, URI_LIST_TARGET_INFO)
+}
+
+fn isDraggedFrom(widget: &gtk::TreeView, context: &gdk::DragContext) -> bool
+{
+    context.drag_get_source_widget().is_some_and(|sourceWidget| &sourceWidget == widget.upcast_ref::<gtk::Widget>())
+}
+
+fn parseFilePaths(data: &[u8]) -> Vec<FilePathString>
+{
+    String::from_utf8_lossy(data)
+        .split(FILE_PATHS_SEPARATOR)
+        .filter(|path| !path.is_empty())
+        .map(Into::into)
+        .collect()
+}
+
+fn convertUrisToFilePaths(uris: &[glib::GString], workingDir: &Path) -> Vec<FilePathString>
+{
+    uris.iter()
+        .filter_map(|uri| {
+            let (path, _hostname) = glib::filename_from_uri(uri).ok()?;
+            let path = canonicalize(&path);
+            Some(path.strip_prefix(workingDir).ok()?.to_str()?.into())
+        })
+        .collect()
+}
+
+fn canonicalize(path: &Path) -> PathBuf
+{
+    path.canonicalize().unwrap_or_else(|_| path.to_owned())
+}
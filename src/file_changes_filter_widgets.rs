@@ -0,0 +1,15 @@
+use crate::event::{Sender, Source};
+use crate::gui_element_provider::GuiElementProvider;
+use crate::text_filter_widgets::{TextFilterWidgets, WidgetData};
+
+
+pub(crate) fn makeFileChangesFilterWidgets(guiElementProvider: &GuiElementProvider, sender: Sender)
+    -> TextFilterWidgets
+{
+    TextFilterWidgets::new(
+        &WidgetData{name: "File changes filter entry", source: Source::FileChangesFilterEntry},
+        &WidgetData{name: "File changes filter case button", source: Source::FileChangesFilterCaseButton},
+        &WidgetData{name: "File changes filter regex button", source: Source::FileChangesFilterRegexButton},
+        guiElementProvider,
+        sender)
+}
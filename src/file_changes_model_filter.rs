@@ -0,0 +1,173 @@
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::file_changes_column::FileChangesColumn;
+use crate::gui_element_provider::GuiElementProvider;
+use crate::path_glob::{convertGlobToRegex, isGlob};
+use crate::text_filter::TextFilter;
+
+use anyhow::Result;
+use gtk::glib;
+use gtk::prelude::TreeModelExt as _;
+use gtk::prelude::TreeModelFilterExt as _;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+
+// Both file changes views are filtered by the same text, which is matched against full paths of files.
+// Without regular expressions enabled, text with glob characters is matched as a glob, otherwise as a substring.
+pub(crate) struct FileChangesModelFilter
+{
+    modelFilters: [gtk::TreeModelFilter; 2],
+    textFilter: Rc<RefCell<TextFilter>>,
+    text: String,
+    regexEnabled: bool,
+    error: Option<regex::Error>,
+    sender: Sender
+}
+
+impl IEventHandler for FileChangesModelFilter
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match (source, event) {
+            (_,                                    Event::RefilterRequested)     => self.onRefilterRequested(),
+            (Source::FileChangesFilterEntry,       Event::TextEntered(text))     => self.onTextChanged(text),
+            (Source::FileChangesFilterCaseButton,  Event::Toggled(shouldEnable)) => self.onCaseSensitivityToggled(*shouldEnable),
+            (Source::FileChangesFilterRegexButton, Event::Toggled(shouldEnable)) => self.onRegexToggled(*shouldEnable),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl FileChangesModelFilter
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender) -> Self
+    {
+        let modelFilters = [
+            guiElementProvider.get::<gtk::TreeModelFilter>("Unstaged changes store filter"),
+            guiElementProvider.get::<gtk::TreeModelFilter>("Staged changes store filter")];
+        let textFilter = Rc::new(RefCell::new(TextFilter::new()));
+        for modelFilter in &modelFilters {
+            setupFilterFunction(modelFilter, Rc::clone(&textFilter));
+            setupRefilterOnInsertedRows(modelFilter, Rc::clone(&textFilter));
+        }
+        Self{modelFilters, textFilter, text: String::new(), regexEnabled: false, error: None, sender}
+    }
+
+
+    // private
+
+    fn onTextChanged(&mut self, text: &str)
+    {
+        self.text = text.into();
+        let result = self.applyText();
+        self.handleChangeResult(result);
+    }
+
+    fn onCaseSensitivityToggled(&mut self, shouldEnable: bool)
+    {
+        let result = self.textFilter.borrow_mut().setCaseSensitivityEnabled(shouldEnable);
+        self.handleChangeResult(result);
+    }
+
+    fn onRegexToggled(&mut self, shouldEnable: bool)
+    {
+        self.regexEnabled = shouldEnable;
+        let result = self.applyText();
+        self.handleChangeResult(result);
+    }
+
+    // Globs are converted to regular expressions, so the regex mode of the filter is used for them too.
+    fn applyText(&self) -> Result<(), regex::Error>
+    {
+        let isGlobUsed = !self.regexEnabled && isGlob(&self.text);
+        let text = match isGlobUsed {
+            true => convertGlobToRegex(&self.text),
+            false => self.text.clone()
+        };
+        let mut textFilter = self.textFilter.borrow_mut();
+        textFilter.setRegexEnabled(false)?;
+        textFilter.setText(&text)?;
+        textFilter.setRegexEnabled(self.regexEnabled || isGlobUsed)
+    }
+
+    fn handleChangeResult(&mut self, result: Result<(), regex::Error>)
+    {
+        match result {
+            Ok(()) => self.onChangeSucceeded(),
+            Err(e) => self.onChangeFailed(e)
+        }
+    }
+
+    fn onChangeSucceeded(&mut self)
+    {
+        if self.error.take().is_some() {
+            self.sender.send((Source::FileChangesModelFilter, Event::ValidTextInputted)).unwrap();
+        }
+        self.sender.send((Source::FileChangesModelFilter, Event::RefilterRequested)).unwrap();
+    }
+
+    fn onChangeFailed(&mut self, error: regex::Error)
+    {
+        if self.error.as_ref() == Some(&error) {
+            return;
+        }
+        self.error = Some(error.clone());
+        self.sender.send((Source::FileChangesModelFilter, Event::InvalidTextInputted(error))).unwrap();
+    }
+
+    fn onRefilterRequested(&self)
+    {
+        for modelFilter in &self.modelFilters {
+            modelFilter.refilter();
+        }
+    }
+}
+
+fn setupFilterFunction(modelFilter: &gtk::TreeModelFilter, textFilter: Rc<RefCell<TextFilter>>)
+{
+    modelFilter.set_visible_func(move |model, iter| {
+        let textFilter = &*textFilter.borrow();
+        textFilter.isEmpty() || isMatch(textFilter, model, iter)
+    });
+}
+
+// Directories are inserted before the files underneath, so they would stay hidden while filtering.
+// Refiltering is deferred until the store is fully updated and done once for all inserted rows.
+fn setupRefilterOnInsertedRows(modelFilter: &gtk::TreeModelFilter, textFilter: Rc<RefCell<TextFilter>>)
+{
+    let isRefilterScheduled = Rc::new(Cell::new(false));
+    let store = modelFilter.model().unwrap();
+    let modelFilter = modelFilter.clone();
+    store.connect_row_inserted(move |_store, _rowPath, _iter| {
+        if textFilter.borrow().isEmpty() || isRefilterScheduled.replace(true) {
+            return;
+        }
+        let modelFilter = modelFilter.clone();
+        let isRefilterScheduled = Rc::clone(&isRefilterScheduled);
+        glib::idle_add_local_once(move || {
+            isRefilterScheduled.set(false);
+            modelFilter.refilter();
+        });
+    });
+}
+
+// Directories are shown when any file underneath matches.
+fn isMatch(textFilter: &TextFilter, model: &gtk::TreeModel, iter: &gtk::TreeIter) -> bool
+{
+    if let Some(child) = model.iter_children(Some(iter)) {
+        loop {
+            if isMatch(textFilter, model, &child) {
+                return true;
+            }
+            if !model.iter_next(&child) {
+                return false;
+            }
+        }
+    }
+
+    let value = model.value(iter, FileChangesColumn::FullPath.into());
+    match value.get::<&str>() {
+        Ok(path) => textFilter.isMatch(path),
+        Err(_) => false
+    }
+}
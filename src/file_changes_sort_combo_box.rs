@@ -0,0 +1,46 @@
+use crate::config::Config;
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::file_changes_sort_order::{FILE_CHANGES_SORT_ORDERS, FileChangesSortOrder};
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::prelude::ComboBoxExt as _;
+use gtk::prelude::ComboBoxTextExt as _;
+use gtk::prelude::WidgetExt as _;
+
+
+// Files in the directory tree are ordered by names within their directories, so the combo box is disabled then.
+pub(crate) struct FileChangesSortComboBox
+{
+    widget: gtk::ComboBoxText
+}
+
+impl IEventHandler for FileChangesSortComboBox
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::DirectoryTreeDisabled => self.widget.set_sensitive(true),
+            Event::DirectoryTreeEnabled  => self.widget.set_sensitive(false),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl FileChangesSortComboBox
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, config: &Config, sender: Sender) -> Self
+    {
+        let widget = guiElementProvider.get::<gtk::ComboBoxText>("File changes sort combo box");
+        for sortOrder in FILE_CHANGES_SORT_ORDERS {
+            widget.append(Some(sortOrder.getId()), sortOrder.getLabel());
+        }
+        widget.set_active_id(Some(config.fileChangesPane.sortOrder.getId()));
+        widget.set_sensitive(!config.fileChangesPane.showsDirectoryTree);
+        widget.connect_changed(move |comboBox| {
+            if let Some(sortOrder) = comboBox.active_id().and_then(|id| FileChangesSortOrder::fromId(&id)) {
+                sender.send((Source::FileChangesSortComboBox, Event::SortOrderChosen(sortOrder))).unwrap();
+            }
+        });
+        Self{widget}
+    }
+}
@@ -0,0 +1,70 @@
+use crate::file_change::FileChange;
+use crate::file_path::FilePathStr;
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::path::Path;
+
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum FileChangesSortOrder
+{
+    #[default]
+    Path,
+    Status,
+    Extension,
+    ChangeSize
+}
+
+pub(crate) const FILE_CHANGES_SORT_ORDERS: [FileChangesSortOrder; 4] = [
+    FileChangesSortOrder::Path,
+    FileChangesSortOrder::Status,
+    FileChangesSortOrder::Extension,
+    FileChangesSortOrder::ChangeSize];
+
+impl FileChangesSortOrder
+{
+    pub const fn getLabel(self) -> &'static str
+    {
+        match self {
+            Self::Path       => "Path",
+            Self::Status     => "Status",
+            Self::Extension  => "Extension",
+            Self::ChangeSize => "Size of change"
+        }
+    }
+
+    pub const fn getId(self) -> &'static str
+    {
+        match self {
+            Self::Path       => "path",
+            Self::Status     => "status",
+            Self::Extension  => "extension",
+            Self::ChangeSize => "changeSize"
+        }
+    }
+
+    pub fn fromId(id: &str) -> Option<Self>
+    {
+        FILE_CHANGES_SORT_ORDERS.into_iter().find(|sortOrder| sortOrder.getId() == id)
+    }
+
+    // Ties are broken by paths, so that file changes have one place in the order, which the store relies on
+    // when merging refreshed file changes. The biggest changes go first.
+    pub fn compare(self, fileChange: &FileChange, otherFileChange: &FileChange) -> Ordering
+    {
+        let ordering = match self {
+            Self::Path => Ordering::Equal,
+            Self::Status => fileChange.status.kind.cmp(&otherFileChange.status.kind),
+            Self::Extension => getExtension(&fileChange.path).cmp(getExtension(&otherFileChange.path)),
+            Self::ChangeSize => otherFileChange.lineStats.getTotal().cmp(&fileChange.lineStats.getTotal())
+        };
+        ordering.then_with(|| fileChange.path.cmp(&otherFileChange.path))
+    }
+}
+
+fn getExtension(path: &FilePathStr) -> &str
+{
+    Path::new(path).extension().and_then(|extension| extension.to_str()).unwrap_or_default()
+}
@@ -1,241 +1,392 @@
 use crate::event::{Event, Sender, Source};
-use crate::file_change::{FileChange, FileChangeUpdate};
+use crate::file_change::{FileChange, FileChangeUpdate, LineStats};
 use crate::file_changes_column::FileChangesColumn;
-use crate::file_path::FilePathStr;
+use crate::file_changes_sort_order::FileChangesSortOrder;
+use crate::file_changes_tree::{insertIntoTree, removeFromTree, setLineStatsInRow, setStatusInRow, updateInTree};
+use crate::file_path::{FilePathStr, FilePathString};
 use crate::gui_element_provider::GuiElementProvider;
 use crate::ifile_changes_store::IFileChangesStore;
 use crate::number_casts::ToI32 as _;
+use crate::text_filter_widgets::WidgetData;
 
 use gtk::glib;
-use gtk::prelude::GtkListStoreExt as _;
-use gtk::prelude::GtkListStoreExtManual as _;
+use gtk::prelude::LabelExt as _;
 use gtk::prelude::TreeModelExt as _;
-use std::cmp::{Ord, Ordering::Less, Ordering::Equal, Ordering::Greater};
+use gtk::prelude::TreeStoreExt as _;
+use gtk::prelude::TreeStoreExtManual as _;
+use std::cmp::{Ordering::Less, Ordering::Equal, Ordering::Greater};
+use std::collections::{HashMap, HashSet};
 
 const NO_PARENT: Option<&gtk::TreeIter> = None;
 
 
 pub(crate) struct FileChangesStore
 {
-    store: gtk::ListStore,
+    store: gtk::TreeStore,
+    summaryLabel: gtk::Label,
     fileChanges: Vec<FileChange>,
+    fileChangeIndexes: HashMap<FilePathString, usize>,
+    showsDirectoryTree: bool,
+    sortOrder: FileChangesSortOrder,
     source: Source,
     sender: Sender
 }
 
+pub(crate) struct FileChangesStoreWidgetData
+{
+    pub store: WidgetData,
+    pub summaryLabelName: &'static str
+}
+
 impl FileChangesStore
 {
     pub fn new(
         guiElementProvider: &GuiElementProvider,
-        name: &str,
-        source: Source,
+        widgetData: &FileChangesStoreWidgetData,
         sender: Sender,
-        fileChanges: &[FileChange]) -> Self
+        fileChanges: &[FileChange],
+        showsDirectoryTree: bool,
+        sortOrder: FileChangesSortOrder) -> Self
     {
-        let newSelf = Self {
-            store: guiElementProvider.get::<gtk::ListStore>(name),
+        let mut newSelf = Self {
+            store: guiElementProvider.get::<gtk::TreeStore>(widgetData.store.name),
+            summaryLabel: guiElementProvider.get::<gtk::Label>(widgetData.summaryLabelName),
             fileChanges: fileChanges.into(),
-            source,
+            fileChangeIndexes: HashMap::new(),
+            showsDirectoryTree,
+            sortOrder,
+            source: widgetData.store.source,
             sender
         };
+        newSelf.sortFileChanges();
+        newSelf.indexFileChanges();
         newSelf.fillFileChangesStore();
+        newSelf.updateSummary();
         newSelf
     }
 
     pub fn append(&mut self, fileChange: &FileChange)
     {
-        self.fileChanges.push(fileChange.clone());
-        self.store.set(
-            &self.store.append(),
-            &[(FileChangesColumn::Status.into(), &formatStatus(&fileChange.status)),
-              (FileChangesColumn::Path.into(),   &fileChange.path)]);
+        let index = self.findSortedIndex(fileChange);
+        self.fileChanges.insert(index, fileChange.clone());
+        self.indexFileChanges();
+        if self.showsDirectoryTree {
+            insertIntoTree(&self.store, fileChange);
+        } else {
+            setRow(&self.store, &self.store.insert(NO_PARENT, index.toI32()), fileChange);
+        }
+        self.updateSummary();
     }
 
     pub fn update(&mut self, fileChangeUpdate: &FileChangeUpdate)
     {
-        let indexAndFileChange = self.fileChanges.iter_mut().enumerate().find(
-            |(_index, fileChange)| fileChange.path == fileChangeUpdate.old.path);
-        let (index, currentFileChange) = indexAndFileChange.unwrap();
-        currentFileChange.status = fileChangeUpdate.new.status.clone();
+        let index = self.fileChangeIndexes[&fileChangeUpdate.old.path];
+        let mut fileChange = self.fileChanges.remove(index);
+        fileChange.status = fileChangeUpdate.new.status;
+        fileChange.lineStats = fileChangeUpdate.new.lineStats;
+        let newIndex = self.findSortedIndex(&fileChange);
+        self.fileChanges.insert(newIndex, fileChange.clone());
+        self.indexFileChanges();
+        self.updateSummary();
 
-        self.store.set_value(
-            &self.store.iter_nth_child(NO_PARENT, index.toI32()).unwrap(),
-            FileChangesColumn::Status.into(),
-            &glib::Value::from(&formatStatus(&fileChangeUpdate.new.status)));
+        if self.showsDirectoryTree {
+            updateInTree(&self.store, &fileChange);
+            return;
+        }
+        let row = self.store.iter_nth_child(NO_PARENT, index.toI32()).unwrap();
+        setStatusInRow(&self.store, &row, &fileChange.status);
+        setLineStatsInRow(&self.store, &row, &fileChange.lineStats);
+        // The row is moved rather than inserted anew, so that it stays selected.
+        match newIndex.cmp(&index) {
+            Less => self.store.move_before(&row, self.store.iter_nth_child(NO_PARENT, newIndex.toI32()).as_ref()),
+            Greater => self.store.move_after(&row, self.store.iter_nth_child(NO_PARENT, newIndex.toI32()).as_ref()),
+            Equal => ()
+        }
     }
 
     pub fn remove(&mut self, filePath: &FilePathStr)
     {
-        let index = self.fileChanges.iter().position(|fileChange| fileChange.path == filePath).unwrap();
+        let index = self.fileChangeIndexes[filePath];
         self.fileChanges.remove(index);
+        self.indexFileChanges();
+        self.updateSummary();
+        if self.showsDirectoryTree {
+            removeFromTree(&self.store, filePath);
+            return;
+        }
         self.store.remove(&self.store.iter_nth_child(NO_PARENT, index.toI32()).unwrap());
     }
 
-    pub fn refresh(&mut self, newFileChanges: &[FileChange])
+    pub fn refresh(&mut self, unsortedFileChanges: &[FileChange])
     {
-        let mut oldFileChangeIndex = 0;
-        for newFileChange in newFileChanges {
-            self.refreshOldFileChanges(&mut oldFileChangeIndex, newFileChange);
+        let mut newFileChanges = unsortedFileChanges.to_vec();
+        newFileChanges.sort_by(|fileChange, otherFileChange| self.sortOrder.compare(fileChange, otherFileChange));
+        if self.showsDirectoryTree {
+            self.refreshTree(&newFileChanges);
+            self.indexFileChanges();
+            self.updateSummary();
+            self.notifyOnRefreshed();
+            return;
         }
-        self.removeLeftoverFileChanges(newFileChanges.len());
+
+        self.refreshRows(&newFileChanges);
+        self.fileChanges = newFileChanges;
+        self.indexFileChanges();
+        self.updateSummary();
         self.notifyOnRefreshed();
     }
 
     pub fn clear(&mut self)
     {
         self.fileChanges.clear();
+        self.fileChangeIndexes.clear();
+        self.store.clear();
+        self.updateSummary();
+    }
+
+    pub fn setShowsDirectoryTree(&mut self, showsDirectoryTree: bool)
+    {
+        if self.showsDirectoryTree == showsDirectoryTree {
+            return;
+        }
+        self.showsDirectoryTree = showsDirectoryTree;
+        self.store.clear();
+        self.fillFileChangesStore();
+        self.notifyOnRefreshed();
+    }
+
+    // In the directory tree files stay ordered by names within their directories.
+    pub fn setSortOrder(&mut self, sortOrder: FileChangesSortOrder)
+    {
+        if self.sortOrder == sortOrder {
+            return;
+        }
+        self.sortOrder = sortOrder;
+        self.sortFileChanges();
+        self.indexFileChanges();
+        if self.showsDirectoryTree {
+            return;
+        }
         self.store.clear();
+        self.fillFileChangesStore();
+        self.notifyOnRefreshed();
     }
 
 
     // private
 
-    fn fillFileChangesStore(&self)
+    fn updateSummary(&self)
     {
-        self.fileChanges.iter().for_each(|fileChange| {
-            self.store.set(
-                &self.store.append(),
-                &[(FileChangesColumn::Status.into(), &formatStatus(&fileChange.status)),
-                  (FileChangesColumn::Path.into(),   &fileChange.path)]);
-        });
+        self.summaryLabel.set_markup(&formatSummary(&self.fileChanges));
     }
 
-    fn notifyOnRefreshed(&self)
+    fn sortFileChanges(&mut self)
     {
-        self.sender.send((self.source, Event::Refreshed)).unwrap();
+        let sortOrder = self.sortOrder;
+        self.fileChanges.sort_by(|fileChange, otherFileChange| sortOrder.compare(fileChange, otherFileChange));
     }
 
-    fn refreshOldFileChanges(&mut self, oldFileChangeIndex: &mut usize, newFileChange: &FileChange)
+    // Called whenever file changes are added, removed or reordered, so that they are found by paths without searching.
+    fn indexFileChanges(&mut self)
     {
-        loop {
-            if self.fileChanges.get(*oldFileChangeIndex).is_some() {
-                if self.refreshEntryWhenOldFileChangeIsPresent(oldFileChangeIndex, newFileChange) == LoopControl::Break {
-                    break;
-                }
-            } else {
-                self.refreshEntryWhenOldFileChangeIsAbsent(oldFileChangeIndex, newFileChange);
-                break;
-            }
+        self.fileChangeIndexes = self.fileChanges.iter().enumerate()
+            .map(|(index, fileChange)| (fileChange.path.clone(), index))
+            .collect();
+    }
+
+    fn findSortedIndex(&self, fileChange: &FileChange) -> usize
+    {
+        self.fileChanges.partition_point(|otherFileChange| self.sortOrder.compare(otherFileChange, fileChange).is_lt())
+    }
+
+    fn fillFileChangesStore(&self)
+    {
+        self.fileChanges.iter().for_each(|fileChange| self.appendRow(fileChange));
+    }
+
+    fn appendRow(&self, fileChange: &FileChange)
+    {
+        if self.showsDirectoryTree {
+            insertIntoTree(&self.store, fileChange);
+        } else {
+            setRow(&self.store, &self.store.append(NO_PARENT), fileChange);
         }
     }
 
-    fn refreshEntryWhenOldFileChangeIsPresent(&mut self, oldFileChangeIndex: &mut usize, newFileChange: &FileChange)
-        -> LoopControl
+    // The tree is updated in place rather than rebuilt, so that collapsed directories stay collapsed.
+    fn refreshTree(&mut self, newFileChanges: &[FileChange])
     {
-        let oldFileChange = &mut self.fileChanges[*oldFileChangeIndex];
-        match newFileChange.path.cmp(&oldFileChange.path) {
-            Less => {
-                self.refreshEntryWhenNewPathIsLessThanOld(oldFileChangeIndex, newFileChange);
-                LoopControl::Break
+        let newPaths = newFileChanges.iter().map(|fileChange| fileChange.path.as_str()).collect::<HashSet<_>>();
+        for oldFileChange in &self.fileChanges {
+            if !newPaths.contains(oldFileChange.path.as_str()) {
+                removeFromTree(&self.store, &oldFileChange.path);
             }
-            Equal => {
-                Self::refreshEntryWhenNewPathIsEqualToOld(&self.store, oldFileChangeIndex, oldFileChange, newFileChange);
-                LoopControl::Break
-            },
-            Greater => {
-                self.refreshEntryWhenNewPathIsGreaterThanOld(*oldFileChangeIndex);
-                LoopControl::DoNotBreak
+        }
+        for newFileChange in newFileChanges {
+            match self.fileChangeIndexes.get(&newFileChange.path).map(|index| &self.fileChanges[*index]) {
+                None => insertIntoTree(&self.store, newFileChange),
+                Some(oldFileChange) if oldFileChange.status != newFileChange.status
+                    || oldFileChange.lineStats != newFileChange.lineStats => updateInTree(&self.store, newFileChange),
+                Some(_) => ()
             }
         }
+        self.fileChanges = newFileChanges.into();
     }
 
-    fn refreshEntryWhenNewPathIsLessThanOld(&mut self, oldFileChangeIndex: &mut usize, newFileChange: &FileChange)
+    fn notifyOnRefreshed(&self)
     {
-        self.fileChanges.insert(*oldFileChangeIndex, newFileChange.clone());
-        self.store.set(
-            &self.store.insert((*oldFileChangeIndex).toI32()),
-            &[(FileChangesColumn::Status.into(), &formatStatus(&newFileChange.status)),
-              (FileChangesColumn::Path.into(),   &newFileChange.path)]);
-        *oldFileChangeIndex += 1;
+        self.sender.send((self.source, Event::Refreshed)).unwrap();
     }
 
-    fn refreshEntryWhenNewPathIsEqualToOld(
-        store: &gtk::ListStore,
-        oldFileChangeIndex: &mut usize,
-        oldFileChange: &mut FileChange,
-        newFileChange: &FileChange)
+    // Rows of files which are still changed are updated and moved rather than inserted anew, so that they stay selected.
+    fn refreshRows(&self, newFileChanges: &[FileChange])
     {
-        if newFileChange.status != oldFileChange.status {
-            oldFileChange.status = newFileChange.status.clone();
-            store.set_value(
-                &store.iter_nth_child(NO_PARENT, (*oldFileChangeIndex).toI32()).unwrap(),
-                FileChangesColumn::Status.into(),
-                &glib::Value::from(&formatStatus(&newFileChange.status))
-            );
+        let newPaths = newFileChanges.iter().map(|fileChange| fileChange.path.as_str()).collect::<HashSet<_>>();
+        let mut oldRows = HashMap::new();
+        for (row, oldFileChange) in collectRows(&self.store).into_iter().zip(&self.fileChanges) {
+            match newPaths.contains(oldFileChange.path.as_str()) {
+                true => { oldRows.insert(oldFileChange.path.as_str(), (row, oldFileChange)); },
+                false => { self.store.remove(&row); }
+            }
         }
 
-        if newFileChange.oldPath != oldFileChange.oldPath {
-            oldFileChange.oldPath = newFileChange.oldPath.clone();
-            store.set_value(
-                &store.iter_nth_child(NO_PARENT, (*oldFileChangeIndex).toI32()).unwrap(),
-                FileChangesColumn::Path.into(),
-                &glib::Value::from(&newFileChange.path)
-            );
+        let mut previousRow = None;
+        for newFileChange in newFileChanges {
+            let row = match oldRows.remove(newFileChange.path.as_str()) {
+                Some((row, oldFileChange)) => {
+                    updateRow(&self.store, &row, oldFileChange, newFileChange);
+                    row
+                },
+                None => {
+                    let row = self.store.append(NO_PARENT);
+                    setRow(&self.store, &row, newFileChange);
+                    row
+                }
+            };
+            if !isRowNext(&self.store, &row, previousRow.as_ref()) {
+                self.store.move_after(&row, previousRow.as_ref());
+            }
+            previousRow = Some(row);
         }
+    }
+}
 
-        *oldFileChangeIndex += 1;
+impl IFileChangesStore for FileChangesStore
+{
+    fn getFileChanges(&self) -> &[FileChange]
+    {
+        &self.fileChanges
     }
 
-    fn refreshEntryWhenNewPathIsGreaterThanOld(&mut self, oldFileChangeIndex: usize)
+    fn getFileChange(&self, rowPath: &gtk::TreePath) -> Option<&FileChange>
     {
-        self.fileChanges.remove(oldFileChangeIndex);
-        self.store.remove(&self.store.iter_nth_child(NO_PARENT, oldFileChangeIndex.toI32()).unwrap());
+        let iter = self.store.iter(rowPath)?;
+        if self.store.iter_has_child(&iter) {
+            return None;
+        }
+        let path = getFullPath(&self.store, &iter);
+        self.fileChangeIndexes.get(&path).map(|index| &self.fileChanges[*index])
     }
 
-    fn refreshEntryWhenOldFileChangeIsAbsent(&mut self, oldFileChangeIndex: &mut usize, newFileChange: &FileChange)
+    fn getFilePath(&self, rowPath: &gtk::TreePath) -> Option<FilePathString>
     {
-        self.fileChanges.push(newFileChange.clone());
-        self.store.set(
-            &self.store.append(),
-            &[(FileChangesColumn::Status.into(), &formatStatus(&newFileChange.status)),
-              (FileChangesColumn::Path.into(),   &newFileChange.path)]);
-        *oldFileChangeIndex += 1;
+        self.store.iter(rowPath).map(|iter| getFullPath(&self.store, &iter))
     }
 
-    fn removeLeftoverFileChanges(&mut self, newFileChangesSize: usize)
+    fn collectFileChanges(&self, rowPath: &gtk::TreePath) -> Vec<FileChange>
     {
-        if newFileChangesSize < self.fileChanges.len() {
-            for row in (newFileChangesSize..self.fileChanges.len()).rev() {
-                self.fileChanges.pop();
-                self.store.remove(&self.store.iter_nth_child(NO_PARENT, row.toI32()).unwrap());
-            }
+        if let Some(fileChange) = self.getFileChange(rowPath) {
+            return vec![fileChange.clone()];
+        }
+        match self.getFilePath(rowPath) {
+            Some(directoryPath) => self.fileChanges.iter()
+                .filter(|fileChange| fileChange.path.strip_prefix(&directoryPath)
+                    .is_some_and(|rest| rest.starts_with('/')))
+                .cloned()
+                .collect(),
+            None => vec![]
         }
     }
+
+    fn findFilePath(&self, path: &FilePathStr) -> Option<usize>
+    {
+        self.fileChangeIndexes.get(path).copied()
+    }
 }
 
-#[derive(Eq, PartialEq)]
-enum LoopControl
+fn setRow(store: &gtk::TreeStore, iter: &gtk::TreeIter, fileChange: &FileChange)
 {
-    Break,
-    DoNotBreak
+    store.set(
+        iter,
+        &[(FileChangesColumn::Path.into(),     &fileChange.path),
+          (FileChangesColumn::FullPath.into(), &fileChange.path)]);
+    setStatusInRow(store, iter, &fileChange.status);
+    setLineStatsInRow(store, iter, &fileChange.lineStats);
 }
 
-impl IFileChangesStore for FileChangesStore
+fn updateRow(store: &gtk::TreeStore, row: &gtk::TreeIter, oldFileChange: &FileChange, newFileChange: &FileChange)
 {
-    fn getFileChange(&self, row: usize) -> &FileChange
-    {
-        &self.fileChanges[row]
+    if newFileChange.status != oldFileChange.status {
+        setStatusInRow(store, row, &newFileChange.status);
     }
-
-    fn getFilePath(&self, row: usize) -> &FilePathStr
-    {
-        &self.getFileChange(row).path
+    if newFileChange.lineStats != oldFileChange.lineStats {
+        setLineStatsInRow(store, row, &newFileChange.lineStats);
     }
+    if newFileChange.oldPath != oldFileChange.oldPath {
+        store.set_value(row, FileChangesColumn::Path.into(), &glib::Value::from(&newFileChange.path));
+    }
+}
 
-    fn findFilePath(&self, path: &FilePathStr) -> Option<usize>
-    {
-        self.fileChanges.iter().enumerate().find_map(
-            |(index, fileChange)| (fileChange.path == path).then_some(index))
+fn collectRows(store: &gtk::TreeStore) -> Vec<gtk::TreeIter>
+{
+    let mut rows = vec![];
+    if let Some(row) = store.iter_children(NO_PARENT) {
+        loop {
+            rows.push(row);
+            if !store.iter_next(&row) {
+                break;
+            }
+        }
     }
+    rows
+}
+
+// Iterators of the same row are not equal, so rows are told apart by their paths.
+fn isRowNext(store: &gtk::TreeStore, row: &gtk::TreeIter, previousRow: Option<&gtk::TreeIter>) -> bool
+{
+    let nextRow = match previousRow {
+        Some(previousRow) => {
+            let nextRow = *previousRow;
+            if !store.iter_next(&nextRow) {
+                return false;
+            }
+            nextRow
+        },
+        None => match store.iter_children(NO_PARENT) {
+            Some(firstRow) => firstRow,
+            None => return false
+        }
+    };
+    getFullPath(store, &nextRow) == getFullPath(store, row)
 }
 
-fn formatStatus(status: &str) -> &str
+// The summary is a total of all changes, also of those hidden by the file changes filter,
+// so it is labelled as such. Ignored files are shown, but are not changes, so they are not counted.
+fn formatSummary(fileChanges: &[FileChange]) -> String
 {
-    match status {
-        "Status(WT_NEW)" | "Status(INDEX_NEW)" | "Added" => "New",
-        "Status(WT_MODIFIED)" | "Status(INDEX_MODIFIED)" | "Modified" => "Modified",
-        "Status(WT_DELETED)" | "Status(INDEX_DELETED)" | "Deleted" => "Deleted",
-        "Status(WT_RENAMED)" | "Status(INDEX_RENAMED)" => "Renamed",
-        _ => panic!("Cannot format unknown status: {}", status)
+    let changedFiles = fileChanges.iter().filter(|fileChange| !fileChange.status.isIgnored()).collect::<Vec<_>>();
+    if changedFiles.is_empty() {
+        return String::new();
     }
+    let lineStats = changedFiles.iter().fold(LineStats::default(), |total, fileChange| total + fileChange.lineStats);
+    let fileWord = match changedFiles.len() {
+        1 => "file",
+        _ => "files"
+    };
+    format!("Total: {} {}  {}", changedFiles.len(), fileWord, lineStats.formatMarkup())
+}
+
+fn getFullPath(store: &gtk::TreeStore, iter: &gtk::TreeIter) -> FilePathString
+{
+    store.value(iter, FileChangesColumn::FullPath.into()).get::<FilePathString>().unwrap()
 }
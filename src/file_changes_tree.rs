@@ -0,0 +1,177 @@
+use crate::file_change::{FileChange, LineStats};
+use crate::file_changes_column::FileChangesColumn;
+use crate::file_path::FilePathStr;
+use crate::file_status::FileStatus;
+
+use gtk::glib;
+use gtk::prelude::TreeModelExt as _;
+use gtk::prelude::TreeStoreExt as _;
+use gtk::prelude::TreeStoreExtManual as _;
+
+const NO_STATUS: &str = "";
+const PATH_SEPARATOR: char = '/';
+const DIRECTORY_ICON_NAME: &str = "folder-symbolic";
+
+
+// Directory rows exist only as long as they have files underneath, so a row with children is a directory.
+pub(crate) fn insertIntoTree(store: &gtk::TreeStore, fileChange: &FileChange)
+{
+    let path = &fileChange.path;
+    let mut parent = None;
+    for (separatorIndex, _separator) in path.match_indices(PATH_SEPARATOR) {
+        let directoryPath = &path[..separatorIndex];
+        parent = Some(match findChild(store, parent.as_ref(), directoryPath) {
+            Some(directory) => directory,
+            None => insertRow(store, parent.as_ref(), directoryPath, RowKind::Directory)
+        });
+    }
+    let row = insertRow(store, parent.as_ref(), path, RowKind::File);
+    setStatusInRow(store, &row, &fileChange.status);
+    setLineStatsInRow(store, &row, &fileChange.lineStats);
+    updateDirectoryLabels(store, parent);
+}
+
+pub(crate) fn removeFromTree(store: &gtk::TreeStore, path: &FilePathStr)
+{
+    let mut row = match findInTree(store, path) {
+        Some(row) => row,
+        None => return
+    };
+    loop {
+        let parent = store.iter_parent(&row);
+        store.remove(&row);
+        match parent {
+            Some(parent) if !store.iter_has_child(&parent) => row = parent,
+            parent => {
+                updateDirectoryLabels(store, parent);
+                return;
+            }
+        }
+    }
+}
+
+pub(crate) fn updateInTree(store: &gtk::TreeStore, fileChange: &FileChange)
+{
+    if let Some(row) = findInTree(store, &fileChange.path) {
+        setStatusInRow(store, &row, &fileChange.status);
+        setLineStatsInRow(store, &row, &fileChange.lineStats);
+    }
+}
+
+pub(crate) fn setStatusInRow(store: &gtk::TreeStore, row: &gtk::TreeIter, status: &FileStatus)
+{
+    store.set(
+        row,
+        &[(FileChangesColumn::Status.into(),      &status.formatLabel()),
+          (FileChangesColumn::ShortStatus.into(), &status.formatShortStatus()),
+          (FileChangesColumn::StatusIcon.into(),  &status.getIconName()),
+          (FileChangesColumn::StatusColor.into(), &status.getColor().to_string())]);
+}
+
+pub(crate) fn setLineStatsInRow(store: &gtk::TreeStore, row: &gtk::TreeIter, lineStats: &LineStats)
+{
+    store.set_value(row, FileChangesColumn::LineStats.into(), &glib::Value::from(&lineStats.formatMarkup()));
+}
+
+
+// private
+
+fn findInTree(store: &gtk::TreeStore, path: &FilePathStr) -> Option<gtk::TreeIter>
+{
+    let mut row = None;
+    for (separatorIndex, _separator) in path.match_indices(PATH_SEPARATOR) {
+        row = Some(findChild(store, row.as_ref(), &path[..separatorIndex])?);
+    }
+    findChild(store, row.as_ref(), path)
+}
+
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+enum RowKind
+{
+    Directory,
+    File
+}
+
+// Files get their status set afterwards, while directories keep an empty one.
+fn insertRow(store: &gtk::TreeStore, parent: Option<&gtk::TreeIter>, path: &FilePathStr, kind: RowKind)
+    -> gtk::TreeIter
+{
+    let position = findInsertPosition(store, parent, path, kind);
+    let iconName = match kind {
+        RowKind::Directory => Some(DIRECTORY_ICON_NAME),
+        RowKind::File => None
+    };
+    store.insert_with_values(
+        parent,
+        position,
+        &[(FileChangesColumn::Status.into(),      &NO_STATUS),
+          (FileChangesColumn::Path.into(),        &getName(path)),
+          (FileChangesColumn::FullPath.into(),    &path),
+          (FileChangesColumn::ShortStatus.into(), &NO_STATUS),
+          (FileChangesColumn::StatusIcon.into(),  &iconName)])
+}
+
+// Directories go before files, like in file managers, and both are sorted by name.
+fn findInsertPosition(store: &gtk::TreeStore, parent: Option<&gtk::TreeIter>, path: &FilePathStr, kind: RowKind)
+    -> Option<u32>
+{
+    let sibling = store.iter_children(parent)?;
+    let mut position = 0;
+    loop {
+        let siblingKind = if store.iter_has_child(&sibling) { RowKind::Directory } else { RowKind::File };
+        if (kind, path) < (siblingKind, getFullPath(store, &sibling).as_str()) {
+            return Some(position);
+        }
+        if !store.iter_next(&sibling) {
+            return None;
+        }
+        position += 1;
+    }
+}
+
+fn findChild(store: &gtk::TreeStore, parent: Option<&gtk::TreeIter>, path: &FilePathStr) -> Option<gtk::TreeIter>
+{
+    let child = store.iter_children(parent)?;
+    loop {
+        if getFullPath(store, &child) == path {
+            return Some(child);
+        }
+        if !store.iter_next(&child) {
+            return None;
+        }
+    }
+}
+
+fn updateDirectoryLabels(store: &gtk::TreeStore, mut directory: Option<gtk::TreeIter>)
+{
+    while let Some(row) = directory {
+        let label = format!("{} ({})", getName(&getFullPath(store, &row)), countFiles(store, &row));
+        store.set_value(&row, FileChangesColumn::Path.into(), &glib::Value::from(&label));
+        directory = store.iter_parent(&row);
+    }
+}
+
+fn countFiles(store: &gtk::TreeStore, row: &gtk::TreeIter) -> usize
+{
+    let child = match store.iter_children(Some(row)) {
+        Some(child) => child,
+        None => return 1
+    };
+    let mut count = 0;
+    loop {
+        count += countFiles(store, &child);
+        if !store.iter_next(&child) {
+            return count;
+        }
+    }
+}
+
+fn getFullPath(store: &gtk::TreeStore, row: &gtk::TreeIter) -> String
+{
+    store.value(row, FileChangesColumn::FullPath.into()).get::<String>().unwrap()
+}
+
+fn getName(path: &FilePathStr) -> &str
+{
+    path.rsplit(PATH_SEPARATOR).next().unwrap_or(path)
+}
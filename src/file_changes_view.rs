@@ -1,35 +1,61 @@
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::file_change::FileChange;
 use crate::file_changes_column::FileChangesColumn;
+use crate::file_changes_drag_and_drop::{DropEventMaker, DroppedItems, setupDragSource, setupDropTarget};
+use crate::file_path::FilePathStr;
 use crate::gui_element_provider::GuiElementProvider;
+use crate::ignore_pattern::makeIgnorePatterns;
+use crate::index_flag_menu_items::makeIndexFlagMenuItems;
+use crate::index_flags::IndexFlags;
 use crate::ifile_changes_store::IFileChangesStore;
+use crate::repository::Repository;
 use crate::selections_comparer::SelectionsComparer;
-use crate::tree_model_utils::toRow;
+use crate::tree_model_utils::toChildRowPaths;
 use crate::tree_view::TreeView;
 
 use gtk::gdk;
+use gtk::glib::object::Cast as _;
 use gtk::prelude::GtkMenuExt as _;
 use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MenuShellExt as _;
 use gtk::prelude::TreeModelExt as _;
+use gtk::prelude::TreeModelFilterExt as _;
 use gtk::prelude::TreeSelectionExt as _;
+use gtk::prelude::TreeViewExt as _;
 use gtk::prelude::WidgetExt as _;
 use std::cell::RefCell;
 use std::rc::Rc;
 
-pub(crate) type OnRowActivatedAction = Box<dyn Fn(&FileChange)>;
+pub(crate) type OnRowActivatedAction = Box<dyn Fn(&[FileChange])>;
+
+// A context menu item that applies the row activation action to a group of file changes.
+pub(crate) struct MenuAction
+{
+    pub label: &'static str,
+    pub scope: MenuActionScope
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum MenuActionScope
+{
+    Selected,
+    All,
+    AllTracked
+}
 
-const LEFT_MENU_ITEM_ATTACH: u32 = 0;
-const RIGHT_MENU_ITEM_ATTACH: u32 = 1;
-const TOP_MENU_ITEM_ATTACH: u32 = 0;
-const BOTTOM_MENU_ITEM_ATTACH: u32 = 1;
 const NO_SELECTIONS_COMPARER: Option<Box<dyn SelectionsComparer>> = None;
+const NO_TRIGGER_EVENT: Option<&gdk::Event> = None;
+const LINE_STATS_VIEW_COLUMN: i32 = 2;
 
 
 pub(crate) struct FileChangesView<StoreType>
 {
     view: TreeView,
+    modelFilter: gtk::TreeModelFilter,
     store: Rc<RefCell<StoreType>>,
-    onRowActivatedAction: OnRowActivatedAction,
+    repository: Rc<RefCell<Repository>>,
+    onRowActivatedAction: Rc<OnRowActivatedAction>,
+    menuActions: Vec<MenuAction>,
     source: Source,
     sender: Sender
 }
@@ -42,6 +68,7 @@ impl<StoreType> IEventHandler for FileChangesView<StoreType>
         use crate::event::Event as E;
         match event {
             E::FileChangeSelected(_)       => self.unselectAll(),
+            E::MenuRequested               => self.onMenuRequested(),
             E::Refreshed                   => self.onRefreshed(),
             E::RightClicked(buttonEvent)   => self.onRightClicked(buttonEvent),
             E::RowActivated(rowPath)       => self.onRowActivated(rowPath),
@@ -54,11 +81,14 @@ impl<StoreType> IEventHandler for FileChangesView<StoreType>
 impl<StoreType> FileChangesView<StoreType>
     where StoreType: IFileChangesStore + 'static
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         guiElementProvider: &GuiElementProvider,
         widgetName: &str,
         store: Rc<RefCell<StoreType>>,
+        repository: Rc<RefCell<Repository>>,
         onRowActivatedAction: OnRowActivatedAction,
+        menuActions: Vec<MenuAction>,
         sender: Sender,
         source: Source)
         -> Self
@@ -70,11 +100,21 @@ impl<StoreType> FileChangesView<StoreType>
             sender.clone(),
             source,
             &FileChangesColumn::asArrayOfI32());
+        view.prependMonospaceCell(FileChangesColumn::Status.into(), FileChangesColumn::ShortStatus.into());
+        view.prependIconCell(FileChangesColumn::Status.into(), FileChangesColumn::StatusIcon.into());
+        view.colorTextCells(FileChangesColumn::Status.into(), FileChangesColumn::StatusColor.into());
+        view.prependMarkupCell(LINE_STATS_VIEW_COLUMN, FileChangesColumn::LineStats.into());
+        view.expandRowsOnFirstChild();
+        view.enableMenuKey(sender.clone(), source);
+        let modelFilter = view.getModel().downcast::<gtk::TreeModelFilter>().unwrap();
 
         Self{
             view,
+            modelFilter,
             store,
-            onRowActivatedAction,
+            repository,
+            onRowActivatedAction: Rc::new(onRowActivatedAction),
+            menuActions,
             source,
             sender
         }
@@ -87,12 +127,17 @@ impl<StoreType> FileChangesView<StoreType>
 
     pub fn trySelectFirst(&self) -> bool
     {
-        if let Some(iter) = self.getModel().iter_first() {
-            self.view.getSelection().selectByIterator(&iter);
-            self.view.focusFirstRow();
-            return true;
+        let model = self.getModel();
+        let mut iter = match model.iter_first() {
+            Some(iter) => iter,
+            None => return false
+        };
+        while let Some(child) = model.iter_children(Some(&iter)) {
+            iter = child;
         }
-        false
+        self.view.getSelection().selectByIterator(&iter);
+        self.view.focusRow(&iter);
+        true
     }
 
     pub fn focus(&self)
@@ -100,6 +145,19 @@ impl<StoreType> FileChangesView<StoreType>
         self.view.focus();
     }
 
+    pub fn setupDragAndDrop(&self, droppedItems: DroppedItems, makeDropEvent: DropEventMaker)
+    {
+        let widget = self.view.getWidget();
+        setupDragSource(widget, &self.modelFilter, Rc::clone(&self.store));
+        setupDropTarget(
+            widget,
+            droppedItems,
+            self.repository.borrow().getWorkingDir(),
+            makeDropEvent,
+            self.sender.clone(),
+            self.source);
+    }
+
 
     // private
 
@@ -108,11 +166,24 @@ impl<StoreType> FileChangesView<StoreType>
         self.view.getModel()
     }
 
+    // Row paths of the view are paths in the model filter, so they are converted before asking the store.
+    fn toStoreRowPath(&self, rowPath: &gtk::TreePath) -> Option<gtk::TreePath>
+    {
+        self.modelFilter.convert_path_to_child_path(rowPath)
+    }
+
+    fn getFileChange(&self, rowPath: &gtk::TreePath) -> Option<FileChange>
+    {
+        let storeRowPath = self.toStoreRowPath(rowPath)?;
+        self.store.borrow().getFileChange(&storeRowPath).cloned()
+    }
+
     fn notifyBasedOnSelectionChanged(&self, selection: &gtk::TreeSelection)
     {
         let (rows, _model) = selection.selected_rows();
-        match rows.get(0) {
-            Some(rowPath) => self.notifyOnSelected(self.store.borrow().getFileChange(toRow(rowPath)).clone()),
+        let fileChangeOpt = rows.get(0).and_then(|rowPath| self.getFileChange(rowPath));
+        match fileChangeOpt {
+            Some(fileChange) => self.notifyOnSelected(fileChange),
             None => self.notifyOnUnselected()
         }
     }
@@ -132,44 +203,167 @@ impl<StoreType> FileChangesView<StoreType>
         self.sender.send((self.source, Event::FileChangeRefreshed(fileChangeOpt))).unwrap();
     }
 
+    // Activating a directory applies to every file underneath, activating a selected row to the whole selection.
     fn onRowActivated(&self, rowPath: &gtk::TreePath)
     {
-        let store = self.store.borrow();
-        let fileChange = store.getFileChange(toRow(rowPath));
-        (self.onRowActivatedAction)(fileChange);
+        let fileChanges = match self.view.getSelection().isRowSelected(rowPath) {
+            true => self.collectSelectedFileChanges(),
+            false => match self.toStoreRowPath(rowPath) {
+                Some(storeRowPath) => self.store.borrow().collectFileChanges(&storeRowPath),
+                None => vec![]
+            }
+        };
+        if !fileChanges.is_empty() {
+            (self.onRowActivatedAction)(&fileChanges);
+        }
+    }
+
+    fn collectSelectedFileChanges(&self) -> Vec<FileChange>
+    {
+        let rowPaths = toChildRowPaths(&self.modelFilter, &self.view.getSelection().getSelectedRowPaths());
+        self.store.borrow().collectFileChangesInRows(&rowPaths)
+    }
+
+    fn collectFileChangesInScope(&self, scope: MenuActionScope) -> Vec<FileChange>
+    {
+        match scope {
+            MenuActionScope::Selected => self.collectSelectedFileChanges(),
+            // Ignored files are staged only when they are chosen explicitly.
+            MenuActionScope::All => self.store.borrow().getFileChanges().iter()
+                .filter(|fileChange| !fileChange.status.isIgnored())
+                .cloned()
+                .collect(),
+            MenuActionScope::AllTracked => self.store.borrow().getFileChanges().iter()
+                .filter(|fileChange| fileChange.status.isTracked())
+                .cloned()
+                .collect()
+        }
     }
 
     fn onRightClicked(&self, event: &gdk::EventButton)
     {
         let (x, y) = event.position();
-        if let Some(row) = self.view.getRowAtPosition(x, y) {
-            self.onRightClickedRow(row, event);
-        }
+        self.showMenu(self.view.getRowPathAtPosition(x, y), Some(event));
     }
 
-    fn onRightClickedRow(&self, row: usize, event: &gdk::EventButton)
+    fn onMenuRequested(&self)
     {
-        let filePath = self.store.borrow().getFilePath(row).to_owned();
+        let (rowPath, _column) = self.view.getWidget().cursor();
+        self.showMenu(rowPath, None);
+    }
+
+    // Without a button event the menu was requested with the keyboard, so it is shown over the view.
+    fn showMenu(&self, rowPath: Option<gtk::TreePath>, event: Option<&gdk::EventButton>)
+    {
+        let rowPath = rowPath.and_then(|rowPath| self.toStoreRowPath(&rowPath));
         let menu = gtk::Menu::new();
+        for menuAction in &self.menuActions {
+            menu.append(&self.makeActionMenuItem(menuAction));
+        }
+        if let Some(rowPath) = rowPath {
+            self.appendRowMenuItems(&menu, &rowPath);
+        }
+        menu.show_all();
+        match event {
+            Some(event) => menu.popup_at_pointer(Some(event)),
+            None => menu.popup_at_widget(
+                self.view.getWidget(), gdk::Gravity::Center, gdk::Gravity::NorthWest, NO_TRIGGER_EVENT)
+        }
+    }
+
+    fn appendRowMenuItems(&self, menu: &gtk::Menu, rowPath: &gtk::TreePath)
+    {
+        let filePath = match self.store.borrow().getFilePath(rowPath) {
+            Some(filePath) => filePath,
+            None => return
+        };
+        menu.append(&gtk::SeparatorMenuItem::new());
+        menu.append(&self.makeCopyPathMenuItem(&filePath));
+        if self.canBeIgnored(rowPath) {
+            self.appendIgnoreMenuItems(menu, &filePath, rowPath);
+        }
+        if let Some(indexFlags) = self.getIndexFlagsOfFile(&filePath, rowPath) {
+            self.appendIndexFlagMenuItems(menu, &filePath, indexFlags);
+        }
+        if self.repository.borrow().isSubmodule(&filePath) {
+            menu.append(&gtk::SeparatorMenuItem::new());
+            let path = filePath.clone();
+            menu.append(&self.makeMenuItem(
+                "Initialize and update submodule", move || Event::UpdateSubmoduleRequested(path.clone())));
+            menu.append(&self.makeMenuItem(
+                "Open submodule as repository", move || Event::OpenSubmoduleRequested(filePath.clone())));
+        }
+    }
+
+    // Only rows of untracked files can be ignored, a directory when all files underneath are untracked.
+    fn canBeIgnored(&self, rowPath: &gtk::TreePath) -> bool
+    {
+        let fileChanges = self.store.borrow().collectFileChanges(rowPath);
+        !fileChanges.is_empty() && fileChanges.iter().all(|fileChange| fileChange.status.isUntracked())
+    }
+
+    fn appendIgnoreMenuItems(&self, menu: &gtk::Menu, filePath: &FilePathStr, rowPath: &gtk::TreePath)
+    {
+        let isDirectory = self.store.borrow().getFileChange(rowPath).is_none();
+        menu.append(&gtk::SeparatorMenuItem::new());
+        for (label, pattern) in makeIgnorePatterns(filePath, isDirectory) {
+            menu.append(&self.makeMenuItem(&label, move || Event::IgnoreRequested(pattern.clone())));
+        }
+    }
+
+    // Directories have no index entries of their own, so only rows of tracked files get the flags.
+    fn getIndexFlagsOfFile(&self, filePath: &FilePathStr, rowPath: &gtk::TreePath) -> Option<IndexFlags>
+    {
+        self.store.borrow().getFileChange(rowPath)?;
+        self.repository.borrow().getIndexFlags(filePath)
+    }
+
+    fn appendIndexFlagMenuItems(&self, menu: &gtk::Menu, filePath: &FilePathStr, indexFlags: IndexFlags)
+    {
+        menu.append(&gtk::SeparatorMenuItem::new());
+        let path = filePath.to_owned();
+        menu.append(&self.makeMenuItem("Stop tracking (keep file)", move || Event::UntrackRequested(path.clone())));
+        for menuItem in makeIndexFlagMenuItems(filePath, indexFlags, &self.sender, self.source) {
+            menu.append(&menuItem);
+        }
+    }
+
+    fn makeActionMenuItem(&self, menuAction: &MenuAction) -> gtk::MenuItem
+    {
+        let fileChanges = self.collectFileChangesInScope(menuAction.scope);
+        let menuItem = gtk::MenuItem::with_label(menuAction.label);
+        menuItem.set_sensitive(!fileChanges.is_empty());
+        let onRowActivatedAction = Rc::clone(&self.onRowActivatedAction);
+        menuItem.connect_activate(move |_item| onRowActivatedAction(&fileChanges));
+        menuItem
+    }
+
+    fn makeCopyPathMenuItem(&self, filePath: &str) -> gtk::MenuItem
+    {
+        let filePath = filePath.to_owned();
         let menuItem = gtk::MenuItem::with_label("Copy path");
         menuItem.connect_activate(move |_item| {
             let clipboard = gtk::Clipboard::get(&gdk::SELECTION_CLIPBOARD);
             clipboard.set_text(&filePath);
         });
-        menu.attach(
-            &menuItem,
-            LEFT_MENU_ITEM_ATTACH,
-            RIGHT_MENU_ITEM_ATTACH,
-            TOP_MENU_ITEM_ATTACH,
-            BOTTOM_MENU_ITEM_ATTACH);
-        menu.show_all();
-        menu.popup_at_pointer(Some(event));
+        menuItem
+    }
+
+    fn makeMenuItem(&self, label: &str, makeEvent: impl Fn() -> Event + 'static) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label(label);
+        let sender = self.sender.clone();
+        let source = self.source;
+        menuItem.connect_activate(move |_item| {
+            sender.send((source, makeEvent())).unwrap();
+        });
+        menuItem
     }
 
     fn onRefreshed(&self)
     {
-        let fileChangeOpt = self.view.getSelection().getSelectedRow()
-            .map(|row| self.store.borrow().getFileChange(row).clone());
+        let fileChangeOpt = self.view.getSelection().getSelectedRowPath()
+            .and_then(|rowPath| self.getFileChange(&rowPath));
         self.notifyOnRefreshed(fileChangeOpt);
     }
 }
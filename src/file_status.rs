@@ -0,0 +1,264 @@
+use crate::color::Color;
+
+const UNMODIFIED_CODE: char = ' ';
+const UNTRACKED_CODE: char = '?';
+const UNMERGED_CODE: char = 'U';
+const IGNORED_CODE: char = '!';
+const SUBMODULE_MODIFIED_CONTENT_CODE: char = 'm';
+
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum FileStatusKind
+{
+    New,
+    Modified,
+    Deleted,
+    Renamed,
+    Typechange,
+    Conflicted,
+    Ignored
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum FileStatusSide
+{
+    Index,
+    Worktree
+}
+
+// The kind describes the change on one side only, while the short status is the "XY" pair
+// of "git status --short", where X is the state of the index and Y of the working tree.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct FileStatus
+{
+    pub kind: FileStatusKind,
+    pub shortStatus: [char; 2],
+    pub similarity: Option<u8>,
+    pub submodule: Option<SubmoduleStatus>
+}
+
+// Like "git status", a modified submodule tells apart moving its HEAD from changing its working tree.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct SubmoduleStatus
+{
+    pub hasNewCommits: bool,
+    pub hasModifiedContent: bool,
+    pub hasUntrackedContent: bool
+}
+
+impl FileStatus
+{
+    pub fn fromFlags(flags: git2::Status, side: FileStatusSide) -> Option<Self>
+    {
+        let kind = match side {
+            FileStatusSide::Index => getIndexKind(flags)?,
+            FileStatusSide::Worktree => getWorktreeKind(flags)?
+        };
+        Some(Self{kind, shortStatus: makeShortStatus(flags), similarity: None, submodule: None})
+    }
+
+    // Deltas come from diffs of a tree to the index, so they describe the index side only.
+    pub fn fromDelta(delta: git2::Delta) -> Self
+    {
+        let kind = match delta {
+            git2::Delta::Added | git2::Delta::Copied | git2::Delta::Untracked => FileStatusKind::New,
+            git2::Delta::Deleted => FileStatusKind::Deleted,
+            git2::Delta::Renamed => FileStatusKind::Renamed,
+            git2::Delta::Typechange => FileStatusKind::Typechange,
+            git2::Delta::Conflicted => FileStatusKind::Conflicted,
+            _ => FileStatusKind::Modified
+        };
+        Self{kind, shortStatus: [getCode(kind), UNMODIFIED_CODE], similarity: None, submodule: None}
+    }
+
+    // Only modified submodules get a submodule status, added and deleted ones are shown like files.
+    pub fn setSubmoduleStatus(&mut self, flags: git2::SubmoduleStatus, side: FileStatusSide)
+    {
+        if self.kind != FileStatusKind::Modified {
+            return;
+        }
+        let submodule = SubmoduleStatus::fromFlags(flags, side);
+        if side == FileStatusSide::Worktree && !submodule.hasNewCommits {
+            self.shortStatus[1] = match submodule.hasModifiedContent {
+                true => SUBMODULE_MODIFIED_CONTENT_CODE,
+                false => UNTRACKED_CODE
+            };
+        }
+        self.submodule = Some(submodule);
+    }
+
+    pub fn isUntracked(&self) -> bool
+    {
+        self.shortStatus == [UNTRACKED_CODE, UNTRACKED_CODE]
+    }
+
+    pub fn isIgnored(&self) -> bool
+    {
+        self.kind == FileStatusKind::Ignored
+    }
+
+    pub fn isTracked(&self) -> bool
+    {
+        !self.isUntracked() && !self.isIgnored()
+    }
+
+    pub const fn getLabel(&self) -> &'static str
+    {
+        match self.kind {
+            FileStatusKind::New        => "New",
+            FileStatusKind::Modified   => "Modified",
+            FileStatusKind::Deleted    => "Deleted",
+            FileStatusKind::Renamed    => "Renamed",
+            FileStatusKind::Typechange => "Type changed",
+            FileStatusKind::Conflicted => "Conflicted",
+            FileStatusKind::Ignored    => "Ignored"
+        }
+    }
+
+    pub fn formatLabel(&self) -> String
+    {
+        match (self.similarity, self.submodule) {
+            (_, Some(submodule)) => format!("Submodule ({})", submodule.describe()),
+            (Some(similarity), None) => format!("{} ({}%)", self.getLabel(), similarity),
+            (None, None) => self.getLabel().into()
+        }
+    }
+
+    pub fn formatShortStatus(&self) -> String
+    {
+        self.shortStatus.iter().collect()
+    }
+
+    pub const fn getIconName(&self) -> &'static str
+    {
+        if self.submodule.is_some() {
+            return "folder-remote-symbolic";
+        }
+        match self.kind {
+            FileStatusKind::New        => "list-add-symbolic",
+            FileStatusKind::Modified   => "document-edit-symbolic",
+            FileStatusKind::Deleted    => "list-remove-symbolic",
+            FileStatusKind::Renamed    => "go-next-symbolic",
+            FileStatusKind::Typechange => "view-refresh-symbolic",
+            FileStatusKind::Conflicted => "dialog-warning-symbolic",
+            FileStatusKind::Ignored    => "action-unavailable-symbolic"
+        }
+    }
+
+    pub const fn getColor(&self) -> Color
+    {
+        match self.kind {
+            FileStatusKind::New        => Color("#2e7d32"),
+            FileStatusKind::Modified   => Color("#ef6c00"),
+            FileStatusKind::Deleted    => Color("#c62828"),
+            FileStatusKind::Renamed    => Color("#1565c0"),
+            FileStatusKind::Typechange => Color("#6a1b9a"),
+            FileStatusKind::Conflicted => Color("#d50000"),
+            FileStatusKind::Ignored    => Color("#757575")
+        }
+    }
+}
+
+impl SubmoduleStatus
+{
+    pub fn fromFlags(flags: git2::SubmoduleStatus, side: FileStatusSide) -> Self
+    {
+        use git2::SubmoduleStatus as S;
+        match side {
+            FileStatusSide::Index => Self{
+                hasNewCommits: flags.contains(S::INDEX_MODIFIED),
+                hasModifiedContent: false,
+                hasUntrackedContent: false
+            },
+            FileStatusSide::Worktree => Self{
+                hasNewCommits: flags.contains(S::WD_MODIFIED),
+                hasModifiedContent: flags.intersects(S::WD_INDEX_MODIFIED | S::WD_WD_MODIFIED),
+                hasUntrackedContent: flags.contains(S::WD_UNTRACKED)
+            }
+        }
+    }
+
+    pub fn describe(&self) -> String
+    {
+        [(self.hasNewCommits, "new commits"),
+         (self.hasModifiedContent, "modified content"),
+         (self.hasUntrackedContent, "untracked content")]
+            .iter()
+            .filter(|(isSet, _description)| *isSet)
+            .map(|(_isSet, description)| *description)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+
+// private
+
+fn getIndexKind(flags: git2::Status) -> Option<FileStatusKind>
+{
+    if flags.is_index_new() {
+        Some(FileStatusKind::New)
+    } else if flags.is_index_modified() {
+        Some(FileStatusKind::Modified)
+    } else if flags.is_index_deleted() {
+        Some(FileStatusKind::Deleted)
+    } else if flags.is_index_typechange() {
+        Some(FileStatusKind::Typechange)
+    } else if flags.is_index_renamed() {
+        Some(FileStatusKind::Renamed)
+    } else {
+        None
+    }
+}
+
+// Conflicts are resolved by staging and ignored files can be staged too, so both are on the working tree side.
+fn getWorktreeKind(flags: git2::Status) -> Option<FileStatusKind>
+{
+    if flags.is_conflicted() {
+        Some(FileStatusKind::Conflicted)
+    } else if flags.is_ignored() {
+        Some(FileStatusKind::Ignored)
+    } else if flags.is_wt_new() {
+        Some(FileStatusKind::New)
+    } else if flags.is_wt_modified() {
+        Some(FileStatusKind::Modified)
+    } else if flags.is_wt_deleted() {
+        Some(FileStatusKind::Deleted)
+    } else if flags.is_wt_typechange() {
+        Some(FileStatusKind::Typechange)
+    } else if flags.is_wt_renamed() {
+        Some(FileStatusKind::Renamed)
+    } else {
+        None
+    }
+}
+
+fn makeShortStatus(flags: git2::Status) -> [char; 2]
+{
+    if flags.is_conflicted() {
+        return [UNMERGED_CODE, UNMERGED_CODE];
+    }
+    if flags.is_ignored() {
+        return [IGNORED_CODE, IGNORED_CODE];
+    }
+    if flags.is_wt_new() {
+        return [UNTRACKED_CODE, UNTRACKED_CODE];
+    }
+    [
+        getIndexKind(flags).map_or(UNMODIFIED_CODE, getCode),
+        getWorktreeKind(flags).map_or(UNMODIFIED_CODE, getCode)
+    ]
+}
+
+const fn getCode(kind: FileStatusKind) -> char
+{
+    match kind {
+        FileStatusKind::New        => 'A',
+        FileStatusKind::Modified   => 'M',
+        FileStatusKind::Deleted    => 'D',
+        FileStatusKind::Renamed    => 'R',
+        FileStatusKind::Typechange => 'T',
+        FileStatusKind::Conflicted => UNMERGED_CODE,
+        FileStatusKind::Ignored    => IGNORED_CODE
+    }
+}
@@ -0,0 +1,63 @@
+const FIXUP_PREFIX: &str = "fixup! ";
+const SQUASH_PREFIX: &str = "squash! ";
+const MIN_ABBREVIATED_COMMIT_ID_LENGTH: usize = 4;
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum FixupKind
+{
+    Fixup,
+    Squash
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum AutosquashBase
+{
+    Commit(git2::Oid),
+    Root
+}
+
+// A squash message keeps the text written so far, because it ends up in the combined commit message.
+pub(crate) fn makeFixupMessage(kind: FixupKind, targetSummary: &str, currentMessage: &str) -> String
+{
+    match kind {
+        FixupKind::Fixup => format!("{}{}\n", FIXUP_PREFIX, targetSummary),
+        FixupKind::Squash => match currentMessage.trim().is_empty() {
+            true => format!("{}{}\n\n", SQUASH_PREFIX, targetSummary),
+            false => format!("{}{}\n\n{}", SQUASH_PREFIX, targetSummary, currentMessage)
+        }
+    }
+}
+
+// Like "git rebase --autosquash", nested prefixes such as "fixup! fixup! " point to the same target.
+pub(crate) fn findFixupTarget(summary: &str) -> Option<&str>
+{
+    let mut target = summary;
+    while let Some(stripped) = target.strip_prefix(FIXUP_PREFIX).or_else(|| target.strip_prefix(SQUASH_PREFIX)) {
+        target = stripped;
+    }
+    match target.len() == summary.len() {
+        true => None,
+        false => Some(target)
+    }
+}
+
+// Follows the rules of "git rebase --autosquash": the target is either a prefix of the summary, which includes
+// the whole summary, or an abbreviated or full id of the commit.
+pub(crate) fn isFixupTarget(target: &str, summary: &str, commitId: git2::Oid) -> bool
+{
+    if target.is_empty() {
+        return false;
+    }
+    summary.starts_with(target) || isAbbreviatedCommitId(target, commitId)
+}
+
+
+// private
+
+fn isAbbreviatedCommitId(text: &str, commitId: git2::Oid) -> bool
+{
+    text.len() >= MIN_ABBREVIATED_COMMIT_ID_LENGTH
+        && text.chars().all(|character| character.is_ascii_hexdigit())
+        && commitId.to_string().starts_with(&text.to_ascii_lowercase())
+}
@@ -0,0 +1,19 @@
+use std::path::Path;
+use std::process::Command;
+
+
+pub(crate) fn runGitCommand(workingDir: &Path, args: &[&str]) -> Result<String, String>
+{
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(workingDir)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    match output.status.success() {
+        true => Ok(stdout),
+        false => Err(format!("{}{}", stdout, String::from_utf8_lossy(&output.stderr)).trim().into())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::commit_amend_checkbox::CommitAmendCheckbox;
 use crate::app_quitter::AppQuitter;
 use crate::application_window::ApplicationWindow;
+use crate::commit_author_button::CommitAuthorButton;
 use crate::commit_button::CommitButton;
 use crate::commit_diff_view::CommitDiffView;
 use crate::commit_log::CommitLog;
@@ -17,14 +18,22 @@ use crate::commit_log_summary_filter_widgets::CommitLogSummaryFilterWidgets;
 use crate::commit_log_view::CommitLogView;
 use crate::commit_message_reader::CommitMessageReader;
 use crate::commit_message_view::CommitMessageView;
+use crate::commit_skip_hooks_checkbox::CommitSkipHooksCheckbox;
 use crate::config::Config;
 use crate::config_path::ConfigPath;
 use crate::config_store::ConfigStore;
 use crate::diff_and_commit_pane::setupDiffAndCommitPane;
 use crate::diff_view::DiffView;
+use crate::directory_tree_checkbox::DirectoryTreeCheckbox;
 use crate::event::{Event, handleUnknown, IEventHandler, Receiver, Sender, Source};
+use crate::file_changes_filter_widgets::makeFileChangesFilterWidgets;
+use crate::file_changes_model_filter::FileChangesModelFilter;
 use crate::file_changes_pane::setupFileChangesPane;
+use crate::file_changes_sort_combo_box::FileChangesSortComboBox;
 use crate::gui_element_provider::GuiElementProvider;
+use crate::head_label::HeadLabel;
+use crate::ignored_files_checkbox::IgnoredFilesCheckbox;
+use crate::index_flags_button::IndexFlagsButton;
 use crate::main_context::{attach, makeChannel};
 use crate::main_pane::setupMainPane;
 use crate::main_stack::setupMainStack;
@@ -32,9 +41,11 @@ use crate::refresh_button::RefreshButton;
 use crate::repository::Repository;
 use crate::staged_changes_store::StagedChangesStore;
 use crate::staged_changes_view::{makeStagedChangesView, StagedChangesView};
+use crate::text_filter_widgets::TextFilterWidgets;
 use crate::tool_bar_stack::ToolBarStack;
 use crate::unstaged_changes_store::UnstagedChangesStore;
 use crate::unstaged_changes_view::{makeUnstagedChangesView, UnstagedChangesView};
+use crate::worktrees_button::WorktreesButton;
 
 use gtk::glib;
 use std::cell::RefCell;
@@ -53,11 +64,21 @@ struct GuiObjects
     toolBarStack: ToolBarStack,
     unstagedChangesView: UnstagedChangesView,
     stagedChangesView: StagedChangesView,
+    directoryTreeCheckbox: DirectoryTreeCheckbox,
+    ignoredFilesCheckbox: IgnoredFilesCheckbox,
+    fileChangesModelFilter: FileChangesModelFilter,
+    fileChangesFilterWidgets: TextFilterWidgets,
+    fileChangesSortComboBox: FileChangesSortComboBox,
     diffView: DiffView,
     refreshButton: RefreshButton,
     commitMessageView: CommitMessageView,
     commitButton: CommitButton,
     commitAmendCheckbox: CommitAmendCheckbox,
+    commitSkipHooksCheckbox: CommitSkipHooksCheckbox,
+    commitAuthorButton: CommitAuthorButton,
+    headLabel: HeadLabel,
+    worktreesButton: WorktreesButton,
+    indexFlagsButton: IndexFlagsButton,
     unstagedChangesStore: Rc<RefCell<UnstagedChangesStore>>,
     stagedChangesStore: Rc<RefCell<StagedChangesStore>>,
     commitLogFilters: CommitLogFilters,
@@ -83,32 +104,44 @@ impl Gui
         let guiElementProvider = GuiElementProvider::new(include_str!("main_window.glade"));
 
         let unstagedChangesStore = Rc::new(RefCell::new(UnstagedChangesStore::new(
-            &guiElementProvider, sender.clone(), &repository)));
+            &guiElementProvider, sender.clone(), &repository, config)));
         let unstagedChangesView = makeUnstagedChangesView(
-            &guiElementProvider, sender.clone(), Rc::clone(&unstagedChangesStore));
+            &guiElementProvider, sender.clone(), Rc::clone(&unstagedChangesStore), Rc::clone(&repository));
         let stagedChangesStore = Rc::new(RefCell::new(StagedChangesStore::new(
-            &guiElementProvider, sender.clone(), &repository)));
+            &guiElementProvider, sender.clone(), &repository, config)));
         let stagedChangesView = makeStagedChangesView(
-            &guiElementProvider, sender.clone(), Rc::clone(&stagedChangesStore));
+            &guiElementProvider, sender.clone(), Rc::clone(&stagedChangesStore), Rc::clone(&repository));
+        let directoryTreeCheckbox = DirectoryTreeCheckbox::new(&guiElementProvider, config, sender.clone());
+        let ignoredFilesCheckbox = IgnoredFilesCheckbox::new(&guiElementProvider, config, sender.clone());
+        let fileChangesModelFilter = FileChangesModelFilter::new(&guiElementProvider, sender.clone());
+        let fileChangesFilterWidgets = makeFileChangesFilterWidgets(&guiElementProvider, sender.clone());
+        let fileChangesSortComboBox = FileChangesSortComboBox::new(&guiElementProvider, config, sender.clone());
         let diffView = DiffView::new(
-            &guiElementProvider, Rc::clone(&repository), sender.clone());
+            &guiElementProvider, Rc::clone(&repository), config, sender.clone());
         let refreshButton = RefreshButton::new(&guiElementProvider, sender.clone());
         let commitAmendCheckbox = CommitAmendCheckbox::new(
             &guiElementProvider, &mut repository.borrow_mut(), sender.clone());
+        let commitSkipHooksCheckbox = CommitSkipHooksCheckbox::new(&guiElementProvider, sender.clone());
+        let commitAuthorButton = CommitAuthorButton::new(&guiElementProvider, sender.clone());
         let commitMessageView = CommitMessageView::new(
-            &guiElementProvider, Rc::clone(&repository), sender.clone());
+            &guiElementProvider, Rc::clone(&repository), config, sender.clone());
         let commitMessageReader = CommitMessageReader::new(&guiElementProvider);
         let commitButton = CommitButton::new(
-            &guiElementProvider, commitMessageReader, Rc::clone(&repository), sender.clone());
+            &guiElementProvider, commitMessageReader, Rc::clone(&repository), config, sender.clone());
+        let headLabel = HeadLabel::new(&guiElementProvider, Rc::clone(&repository));
+        let worktreesButton = WorktreesButton::new(&guiElementProvider, Rc::clone(&repository), sender.clone());
+        let indexFlagsButton = IndexFlagsButton::new(&guiElementProvider, Rc::clone(&repository), sender.clone());
 
         let commitLogFilters = CommitLogFilters::new(config, sender.clone());
         let commitLogFiltersComboBox = CommitLogFiltersComboBox::new(&guiElementProvider, config, sender.clone());
         let commitLogFiltersView = CommitLogFiltersView::new(&guiElementProvider);
         let commitLogModelFilter = CommitLogModelFilter::new(&guiElementProvider, sender.clone());
         let commitLog = CommitLog::new(&repository.borrow());
-        let _commitLogModel = CommitLogModel::new(&commitLog, &guiElementProvider);
-        let commitLogView = CommitLogView::new(commitLog, &guiElementProvider, sender.clone());
-        let commitDiffView = CommitDiffView::new(Rc::clone(&repository), &guiElementProvider, sender.clone());
+        let commitLogModel = CommitLogModel::new(&commitLog, &guiElementProvider);
+        let commitLogView = CommitLogView::new(
+            commitLog, commitLogModel, Rc::clone(&repository), &guiElementProvider, sender.clone());
+        let commitDiffView = CommitDiffView::new(
+            Rc::clone(&repository), &guiElementProvider, config, sender.clone());
 
         setupMainStack(&guiElementProvider, config, sender.clone());
         let toolBarStack = ToolBarStack::new(&guiElementProvider);
@@ -129,11 +162,21 @@ impl Gui
             configStore,
             unstagedChangesView,
             stagedChangesView,
+            directoryTreeCheckbox,
+            ignoredFilesCheckbox,
+            fileChangesModelFilter,
+            fileChangesFilterWidgets,
+            fileChangesSortComboBox,
             diffView,
             refreshButton,
             commitMessageView,
             commitButton,
             commitAmendCheckbox,
+            commitSkipHooksCheckbox,
+            commitAuthorButton,
+            headLabel,
+            worktreesButton,
+            indexFlagsButton,
             unstagedChangesStore,
             stagedChangesStore,
             commitLogFilters,
@@ -171,13 +214,23 @@ fn setupDispatching(gui: GuiObjects, mut repository: Rc<RefCell<Repository>>, re
     let mut toolBarStack = gui.toolBarStack;
     let mut unstagedChangesView = gui.unstagedChangesView;
     let mut stagedChangesView = gui.stagedChangesView;
+    let mut directoryTreeCheckbox = gui.directoryTreeCheckbox;
+    let mut ignoredFilesCheckbox = gui.ignoredFilesCheckbox;
+    let mut fileChangesModelFilter = gui.fileChangesModelFilter;
+    let mut fileChangesFilterWidgets = gui.fileChangesFilterWidgets;
+    let mut fileChangesSortComboBox = gui.fileChangesSortComboBox;
     let mut diffView = gui.diffView;
     let mut refreshButton = gui.refreshButton;
     let mut commitMessageView = gui.commitMessageView;
     let mut commitButton = gui.commitButton;
     let mut commitAmendCheckbox = gui.commitAmendCheckbox;
+    let mut commitSkipHooksCheckbox = gui.commitSkipHooksCheckbox;
+    let mut commitAuthorButton = gui.commitAuthorButton;
+    let mut headLabel = gui.headLabel;
+    let mut worktreesButton = gui.worktreesButton;
+    let mut indexFlagsButton = gui.indexFlagsButton;
     let mut unstagedChangesStore = Rc::clone(&gui.unstagedChangesStore);
-    let mut stagedChangesStore = Rc::clone(&gui.stagedChangesStore);
+    let stagedChangesStore = Rc::clone(&gui.stagedChangesStore);
     let mut commitLogFilters = gui.commitLogFilters;
     let mut commitLogFiltersComboBox = gui.commitLogFiltersComboBox;
     let mut commitLogFiltersView = gui.commitLogFiltersView;
@@ -193,13 +246,21 @@ fn setupDispatching(gui: GuiObjects, mut repository: Rc<RefCell<Repository>>, re
     use Event as E;
     attach(receiver, move |(source, event)| { match (source, &event) {
         (S::ApplicationWindow,                 E::MaximizationChanged(_))        => configStore.handle(source, &event),
-        (S::ApplicationWindow,                 E::QuitRequested)                 => (&mut configStore, &mut appQuitter).handle(source, &event),
+        (S::ApplicationWindow,                 E::QuitRequested)                 => (&mut configStore, &mut commitMessageView, &mut appQuitter).handle(source, &event),
+        (S::ApplicationWindow,                 E::SearchRequested)               => (&mut diffView, &mut commitDiffView).handle(source, &event),
         (S::CommitAmendCheckbox,               E::CommitAmendDisabled)           => (&repository, &mut commitMessageView, &mut commitButton, &mut diffView).handle(source, &event),
         (S::CommitAmendCheckbox,               E::CommitAmendEnabled)            => (&repository, &mut commitMessageView, &mut commitButton, &mut diffView).handle(source, &event),
         (S::CommitAmendCheckbox,               E::Toggled(_))                    => commitAmendCheckbox.handle(source, &event),
+        (S::CommitAuthorButton,                E::Clicked)                       => commitAuthorButton.handle(source, &event),
+        (S::CommitAuthorButton,                E::CommitAuthorOptionsChanged(_)) => repository.handle(source, &event),
         (S::CommitButton,                      E::AmendCommitRequested(_))       => repository.handle(source, &event),
         (S::CommitButton,                      E::Clicked)                       => commitButton.handle(source, &event),
         (S::CommitButton,                      E::CommitRequested(_))            => repository.handle(source, &event),
+        (S::CommitDiffSearchCaseButton,        E::Toggled(_))                    => commitDiffView.handle(source, &event),
+        (S::CommitDiffSearchEntry,             E::TextEntered(_))                => commitDiffView.handle(source, &event),
+        (S::CommitDiffSearchNextButton,        E::Clicked)                       => commitDiffView.handle(source, &event),
+        (S::CommitDiffSearchPreviousButton,    E::Clicked)                       => commitDiffView.handle(source, &event),
+        (S::CommitDiffSearchRegexButton,       E::Toggled(_))                    => commitDiffView.handle(source, &event),
         (S::CommitDiffViewWidget,              E::ZoomRequested(_))              => commitDiffView.handle(source, &event),
         (S::CommitLogAuthorFilterCaseButton,   E::Toggled(_))                    => (&mut commitLogModelFilter, &mut commitLogFilters).handle(source, &event),
         (S::CommitLogAuthorFilterEntry,        E::TextEntered(_))                => (&mut commitLogModelFilter, &mut commitLogFilters).handle(source, &event),
@@ -223,47 +284,114 @@ fn setupDispatching(gui: GuiObjects, mut repository: Rc<RefCell<Repository>>, re
         (S::CommitLogSummaryFilterCaseButton,  E::Toggled(_))                    => (&mut commitLogModelFilter, &mut commitLogFilters).handle(source, &event),
         (S::CommitLogSummaryFilterEntry,       E::TextEntered(_))                => (&mut commitLogModelFilter, &mut commitLogFilters).handle(source, &event),
         (S::CommitLogSummaryFilterRegexButton, E::Toggled(_))                    => (&mut commitLogModelFilter, &mut commitLogFilters).handle(source, &event),
+        (S::CommitLogView,                     E::AutosquashRequested)           => repository.handle(source, &event),
+        (S::CommitLogView,                     E::CheckoutCommitRequested(_))    => repository.handle(source, &event),
         (S::CommitLogView,                     E::CommitSelected(_))             => commitDiffView.handle(source, &event),
         (S::CommitLogView,                     E::CommitUnselected)              => commitDiffView.handle(source, &event),
-        (S::CommitLogViewWidget,               E::RightClicked(_))               => (),
+        (S::CommitLogView,                     E::CreateTagRequested(..))        => repository.handle(source, &event),
+        (S::CommitLogView,                     E::DeleteTagRequested(_))         => repository.handle(source, &event),
+        (S::CommitLogView,                     E::EditCommitRequested(..))       => repository.handle(source, &event),
+        (S::CommitLogView,                     E::FixupCommitRequested(..))      => commitMessageView.handle(source, &event),
+        (S::CommitLogView,                     E::PushTagRequested(_))           => repository.handle(source, &event),
+        (S::CommitLogView,                     E::ResetToCommitConfirmed(..))    => repository.handle(source, &event),
+        (S::CommitLogView,                     E::ResetToCommitRequested(..))    => repository.handle(source, &event),
+        (S::CommitLogViewWidget,               E::MenuRequested)                 => commitLogView.handle(source, &event),
+        (S::CommitLogViewWidget,               E::RightClicked(_))               => commitLogView.handle(source, &event),
         (S::CommitLogViewWidget,               E::RowActivated(_))               => (),
         (S::CommitLogViewWidget,               E::SelectionChanged(_))           => commitLogView.handle(source, &event),
-        (S::CommitMessageView,                 E::BufferChanged)                 => commitMessageView.handle(source, &event),
+        (S::CommitMessageView,                 E::BufferChanged)                 => (&mut commitMessageView, &mut commitButton).handle(source, &event),
+        (S::CommitMessageView,                 E::CommitAmendRequested)          => commitAmendCheckbox.handle(source, &event),
+        (S::CommitMessageView,                 E::DraftSaveRequested)            => commitMessageView.handle(source, &event),
         (S::CommitMessageView,                 E::Emptied)                       => commitButton.handle(source, &event),
         (S::CommitMessageView,                 E::Filled)                        => commitButton.handle(source, &event),
+        (S::CommitMessageView,                 E::FocusLost)                     => commitMessageView.handle(source, &event),
+        (S::CommitMessageView,                 E::RecentCommitMessageChosen(_))  => commitMessageView.handle(source, &event),
+        (S::CommitMessageView,                 E::SpellCheckRequested)           => commitMessageView.handle(source, &event),
+        (S::CommitMessageView,                 E::TrailerRequested(_))           => commitMessageView.handle(source, &event),
         (S::CommitMessageView,                 E::ZoomRequested(_))              => commitMessageView.handle(source, &event),
+        (S::CommitSkipHooksCheckbox,           E::CommitHooksDisabled)           => repository.handle(source, &event),
+        (S::CommitSkipHooksCheckbox,           E::CommitHooksEnabled)            => repository.handle(source, &event),
+        (S::CommitSkipHooksCheckbox,           E::Toggled(_))                    => commitSkipHooksCheckbox.handle(source, &event),
         (S::DiffAndCommitPane,                 E::PositionChanged(_))            => configStore.handle(source, &event),
+        (S::DiffSearchCaseButton,              E::Toggled(_))                    => diffView.handle(source, &event),
+        (S::DiffSearchEntry,                   E::TextEntered(_))                => diffView.handle(source, &event),
+        (S::DiffSearchNextButton,              E::Clicked)                       => diffView.handle(source, &event),
+        (S::DiffSearchPreviousButton,          E::Clicked)                       => diffView.handle(source, &event),
+        (S::DiffSearchRegexButton,             E::Toggled(_))                    => diffView.handle(source, &event),
         (S::DiffView,                          E::ZoomRequested(_))              => diffView.handle(source, &event),
+        (S::DirectoryTreeCheckbox,             E::DirectoryTreeDisabled)         => (&unstagedChangesStore, &stagedChangesStore, &mut configStore, &mut fileChangesSortComboBox).handle(source, &event),
+        (S::DirectoryTreeCheckbox,             E::DirectoryTreeEnabled)          => (&unstagedChangesStore, &stagedChangesStore, &mut configStore, &mut fileChangesSortComboBox).handle(source, &event),
+        (S::DirectoryTreeCheckbox,             E::Toggled(_))                    => directoryTreeCheckbox.handle(source, &event),
+        (S::FileChangesFilterCaseButton,       E::Toggled(_))                    => fileChangesModelFilter.handle(source, &event),
+        (S::FileChangesFilterEntry,            E::TextEntered(_))                => fileChangesModelFilter.handle(source, &event),
+        (S::FileChangesFilterRegexButton,      E::Toggled(_))                    => fileChangesModelFilter.handle(source, &event),
+        (S::FileChangesModelFilter,            E::InvalidTextInputted(_))        => fileChangesFilterWidgets.handle(source, &event),
+        (S::FileChangesModelFilter,            E::RefilterRequested)             => fileChangesModelFilter.handle(source, &event),
+        (S::FileChangesModelFilter,            E::ValidTextInputted)             => fileChangesFilterWidgets.handle(source, &event),
         (S::FileChangesPane,                   E::PositionChanged(_))            => configStore.handle(source, &event),
+        (S::FileChangesSortComboBox,           E::SortOrderChosen(_))            => (&unstagedChangesStore, &stagedChangesStore, &mut configStore).handle(source, &event),
+        (S::IgnoredFilesCheckbox,              E::IgnoredFilesDisabled)          => (&repository, &mut configStore).handle(source, &event),
+        (S::IgnoredFilesCheckbox,              E::IgnoredFilesEnabled)           => (&repository, &mut configStore).handle(source, &event),
+        (S::IgnoredFilesCheckbox,              E::Toggled(_))                    => ignoredFilesCheckbox.handle(source, &event),
+        (S::IndexFlagsButton,                  E::Clicked)                       => indexFlagsButton.handle(source, &event),
+        (S::IndexFlagsButton,                  E::SetIndexFlagRequested(..))     => repository.handle(source, &event),
         (S::MainPane,                          E::PositionChanged(_))            => configStore.handle(source, &event),
         (S::MainStack,                         E::ActivePageChanged(_))          => (&mut toolBarStack, &mut configStore).handle(source, &event),
         (S::RefreshButton,                     E::Clicked)                       => refreshButton.handle(source, &event),
         (S::RefreshButton,                     E::RefreshRequested)              => repository.handle(source, &event),
-        (S::Repository,                        E::AddedToStaged(_))              => (&stagedChangesStore, &mut commitButton).handle(source, &event),
+        (S::Repository,                        E::AddedToStaged(_))              => (&stagedChangesStore, &mut commitButton, &mut commitMessageView).handle(source, &event),
         (S::Repository,                        E::AddedToUnstaged(_))            => unstagedChangesStore.handle(source, &event),
-        (S::Repository,                        E::AmendedCommit)                 => (&stagedChangesStore, &mut commitAmendCheckbox).handle(source, &event),
-        (S::Repository,                        E::Committed)                     => (&stagedChangesStore, &mut commitMessageView, &mut commitAmendCheckbox).handle(source, &event),
-        (S::Repository,                        E::RemovedFromStaged(_))          => (&stagedChangesStore, &mut commitButton).handle(source, &event),
+        (S::Repository,                        E::AmendCommitHooksFinished(_))   => repository.handle(source, &event),
+        (S::Repository,                        E::AmendedCommit)                 => ((&stagedChangesStore, &mut commitMessageView), (&mut commitAmendCheckbox, &mut commitAuthorButton, &mut commitLogView)).handle(source, &event),
+        (S::Repository,                        E::AutosquashFinished(_))         => repository.handle(source, &event),
+        (S::Repository,                        E::CommitFailed)                  => commitButton.handle(source, &event),
+        (S::Repository,                        E::CommitHooksFinished(_))        => repository.handle(source, &event),
+        (S::Repository,                        E::Committed)                     => ((&stagedChangesStore, &mut commitMessageView), (&mut commitAmendCheckbox, &mut headLabel, &mut commitAuthorButton, &mut commitLogView)).handle(source, &event),
+        (S::Repository,                        E::HeadChanged)                   => ((&unstagedChangesStore, &stagedChangesStore, &mut commitButton), (&mut commitAmendCheckbox, &mut commitLogView, &mut headLabel)).handle(source, &event),
+        (S::Repository,                        E::ReferencesChanged)             => commitLogView.handle(source, &event),
+        (S::Repository,                        E::RemovedFromStaged(_))          => (&stagedChangesStore, &mut commitButton, &mut commitMessageView).handle(source, &event),
         (S::Repository,                        E::RemovedFromUnstaged(_))        => unstagedChangesStore.handle(source, &event),
-        (S::Repository,                        E::Refreshed)                     => (&unstagedChangesStore, &stagedChangesStore).handle(source, &event),
-        (S::Repository,                        E::UpdatedInStaged(_))            => stagedChangesStore.handle(source, &event),
+        (S::Repository,                        E::Refreshed)                     => ((&unstagedChangesStore, &stagedChangesStore), (&mut commitMessageView, &mut commitButton, &mut indexFlagsButton)).handle(source, &event),
+        (S::Repository,                        E::ResetConfirmationNeeded(..))   => commitLogView.handle(source, &event),
+        (S::Repository,                        E::SignatureVerified(..))         => (&mut commitLogView, &mut commitDiffView).handle(source, &event),
+        (S::Repository,                        E::TagPushFailed(_))              => commitLogView.handle(source, &event),
+        (S::Repository,                        E::UpdatedInStaged(_))            => (&stagedChangesStore, &mut commitMessageView).handle(source, &event),
         (S::Repository,                        E::UpdatedInUnstaged(_))          => unstagedChangesStore.handle(source, &event),
         (S::StagedChangesStore,                E::Refreshed)                     => stagedChangesView.handle(source, &event),
         (S::StagedChangesView,                 E::FileChangeRefreshed(_))        => diffView.handle(source, &event),
         (S::StagedChangesView,                 E::FileChangeSelected(_))         => (&mut diffView, &mut unstagedChangesView).handle(source, &event),
         (S::StagedChangesView,                 E::FileChangeUnselected)          => diffView.handle(source, &event),
+        (S::StagedChangesView,                 E::MenuRequested)                 => stagedChangesView.handle(source, &event),
+        (S::StagedChangesView,                 E::OpenSubmoduleRequested(_))     => repository.handle(source, &event),
         (S::StagedChangesView,                 E::RightClicked(_))               => stagedChangesView.handle(source, &event),
         (S::StagedChangesView,                 E::RowActivated(_))               => stagedChangesView.handle(source, &event),
         (S::StagedChangesView,                 E::SelectionChanged(_))           => stagedChangesView.handle(source, &event),
+        (S::StagedChangesView,                 E::SetIndexFlagRequested(..))     => repository.handle(source, &event),
+        (S::StagedChangesView,                 E::StagePathsRequested(_))        => repository.handle(source, &event),
+        (S::StagedChangesView,                 E::UnstageManyRequested(_))       => repository.handle(source, &event),
         (S::StagedChangesView,                 E::UnstageRequested(_))           => repository.handle(source, &event),
+        (S::StagedChangesView,                 E::UntrackRequested(_))           => repository.handle(source, &event),
+        (S::StagedChangesView,                 E::UpdateSubmoduleRequested(_))   => repository.handle(source, &event),
         (S::UnstagedChangesStore,              E::Refreshed)                     => unstagedChangesView.handle(source, &event),
         (S::UnstagedChangesView,               E::FileChangeRefreshed(_))        => diffView.handle(source, &event),
         (S::UnstagedChangesView,               E::FileChangeSelected(_))         => (&mut diffView, &mut stagedChangesView).handle(source, &event),
         (S::UnstagedChangesView,               E::FileChangeUnselected)          => diffView.handle(source, &event),
+        (S::UnstagedChangesView,               E::IgnoreRequested(_))            => repository.handle(source, &event),
+        (S::UnstagedChangesView,               E::MenuRequested)                 => unstagedChangesView.handle(source, &event),
+        (S::UnstagedChangesView,               E::OpenSubmoduleRequested(_))     => repository.handle(source, &event),
         (S::UnstagedChangesView,               E::RightClicked(_))               => unstagedChangesView.handle(source, &event),
         (S::UnstagedChangesView,               E::RowActivated(_))               => unstagedChangesView.handle(source, &event),
         (S::UnstagedChangesView,               E::SelectionChanged(_))           => unstagedChangesView.handle(source, &event),
+        (S::UnstagedChangesView,               E::SetIndexFlagRequested(..))     => repository.handle(source, &event),
+        (S::UnstagedChangesView,               E::StageManyRequested(_))         => repository.handle(source, &event),
         (S::UnstagedChangesView,               E::StageRequested(_))             => repository.handle(source, &event),
+        (S::UnstagedChangesView,               E::UnstagePathsRequested(_))      => repository.handle(source, &event),
+        (S::UnstagedChangesView,               E::UntrackRequested(_))           => repository.handle(source, &event),
+        (S::UnstagedChangesView,               E::UpdateSubmoduleRequested(_))   => repository.handle(source, &event),
+        (S::WorktreesButton,                   E::Clicked)                       => worktreesButton.handle(source, &event),
+        (S::WorktreesButton,                   E::CreateWorktreeRequested(_))    => repository.handle(source, &event),
+        (S::WorktreesButton,                   E::PruneWorktreesRequested)       => repository.handle(source, &event),
+        (S::WorktreesButton,                   E::QuitRequested)                 => (&mut configStore, &mut commitMessageView, &mut appQuitter).handle(source, &event),
         (source, event) => handleUnknown(source, event) }
 
         glib::ControlFlow::Continue
@@ -0,0 +1,57 @@
+use crate::commit_id::shortenCommitId;
+use crate::event::{Event, handleUnknown, IEventHandler, Source};
+use crate::gui_element_provider::GuiElementProvider;
+use crate::head_state::HeadState;
+use crate::repository::Repository;
+
+use gtk::glib;
+use gtk::prelude::LabelExt as _;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+
+pub(crate) struct HeadLabel
+{
+    widget: gtk::Label,
+    repository: Rc<RefCell<Repository>>
+}
+
+impl IEventHandler for HeadLabel
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::Committed   => self.update(),
+            Event::HeadChanged => self.update(),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl HeadLabel
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, repository: Rc<RefCell<Repository>>) -> Self
+    {
+        let newSelf = Self{widget: guiElementProvider.get::<gtk::Label>("Head label"), repository};
+        newSelf.update();
+        newSelf
+    }
+
+
+    // private
+
+    fn update(&self)
+    {
+        self.widget.set_markup(&formatHeadState(&self.repository.borrow().getHeadState()));
+    }
+}
+
+fn formatHeadState(headState: &HeadState) -> String
+{
+    match headState {
+        HeadState::Branch(name) => format!("On branch <b>{}</b>", glib::markup_escape_text(name)),
+        HeadState::Unborn(name) => format!("On branch <b>{}</b> (no commits yet)", glib::markup_escape_text(name)),
+        HeadState::Detached(id) => format!(
+            r#"<span foreground="red"><b>Detached HEAD</b></span> at {}"#, shortenCommitId(*id))
+    }
+}
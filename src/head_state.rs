@@ -0,0 +1,9 @@
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum HeadState
+{
+    Branch(BranchName),
+    Unborn(BranchName),
+    Detached(git2::Oid)
+}
+
+pub(crate) type BranchName = String;
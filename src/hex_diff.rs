@@ -0,0 +1,256 @@
+use crate::blob_source::FileContent;
+use crate::diff_formatter::{CharacterFormat, FormattedCharacters, FormattedDiff, LineFormat};
+
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+const BYTES_PER_ROW: usize = 16;
+// A row is a sign, an eight digit offset, two spaces, the hex bytes, two spaces and the bytes as text between bars.
+const HEX_COLUMN: usize = 11;
+const HEX_WIDTH: usize = BYTES_PER_ROW * 3 - 1;
+const TEXT_COLUMN: usize = HEX_COLUMN + HEX_WIDTH + 3;
+// Unchanged bytes around changes are shown up to this many rows, longer runs are collapsed.
+const CONTEXT_ROWS: usize = 1;
+const DIFF_TIME_LIMIT: Duration = Duration::from_secs(1);
+const MAGIC_NUMBERS: [(usize, &[u8], &str); 22] = [
+    (0, b"\x89PNG\r\n\x1a\n", "PNG image"),
+    (0, b"\xff\xd8\xff", "JPEG image"),
+    (0, b"GIF8", "GIF image"),
+    (0, b"BM", "BMP image"),
+    (0, b"II*\x00", "TIFF image"),
+    (0, b"MM\x00*", "TIFF image"),
+    (0, b"%PDF-", "PDF document"),
+    (0, b"PK\x03\x04", "ZIP archive"),
+    (0, b"\x1f\x8b", "gzip archive"),
+    (0, b"BZh", "bzip2 archive"),
+    (0, b"\xfd7zXZ\x00", "xz archive"),
+    (0, b"7z\xbc\xaf\x27\x1c", "7-Zip archive"),
+    (257, b"ustar", "tar archive"),
+    (0, b"\x7fELF", "ELF executable"),
+    (0, b"MZ", "Windows executable"),
+    (0, b"\xcf\xfa\xed\xfe", "Mach-O executable"),
+    (0, b"\xca\xfe\xba\xbe", "Java class or Mach-O universal binary"),
+    (0, b"\x00asm", "WebAssembly module"),
+    (0, b"SQLite format 3\x00", "SQLite database"),
+    (0, b"OggS", "Ogg media"),
+    (0, b"ID3", "MP3 audio"),
+    (0, b"RIFF", "RIFF media")
+];
+
+
+pub(crate) fn formatHexDiff(oldContent: Option<&FileContent>, newContent: Option<&FileContent>, maxSize: usize)
+    -> FormattedDiff
+{
+    let mut output = HexDiffOutput::default();
+    output.push(LineFormat::TopHeader, format!(
+        "binary file\nold: {}\nnew: {}\nsize delta: {}\n",
+        describeContent(oldContent), describeContent(newContent), formatSizeDelta(oldContent, newContent)));
+
+    let (old, new) = match (getData(oldContent), getData(newContent)) {
+        (Some(old), Some(new)) => (old, new),
+        _ => {
+            output.push(LineFormat::ContextLine,
+                        format!(" files larger than {} bytes are not compared byte by byte\n", maxSize));
+            return output.diff;
+        }
+    };
+
+    let deadline = Instant::now() + DIFF_TIME_LIMIT;
+    let operations = similar::capture_diff_slices_deadline(similar::Algorithm::Myers, old, new, Some(deadline));
+    let hunks = collectHunks(&operations, old.len(), new.len());
+    let mut unchangedStart = 0;
+    for (index, hunk) in hunks.iter().enumerate() {
+        output.pushUnchanged(new, unchangedStart..hunk.newRows.start, index == 0, false);
+        output.pushChangedRows('-', old, hunk.oldRows.clone(), &hunk.removedBytes, CharacterFormat::Removed);
+        output.pushChangedRows('+', new, hunk.newRows.clone(), &hunk.addedBytes, CharacterFormat::Added);
+        unchangedStart = hunk.newRows.end;
+    }
+    output.pushUnchanged(new, unchangedStart..new.len(), hunks.is_empty(), true);
+    output.diff
+}
+
+pub(crate) fn detectFileFormat(content: &[u8]) -> Option<&'static str>
+{
+    MAGIC_NUMBERS.iter()
+        .find(|(offset, magic, _name)| content.get(*offset..).is_some_and(|content| content.starts_with(magic)))
+        .map(|(_offset, _magic, name)| *name)
+}
+
+
+// private
+
+// Rows of both versions, which contain changed bytes, with unchanged bytes around them kept unhighlighted.
+struct HexHunk
+{
+    oldRows: Range<usize>,
+    newRows: Range<usize>,
+    removedBytes: Vec<Range<usize>>,
+    addedBytes: Vec<Range<usize>>
+}
+
+impl HexHunk
+{
+    fn new() -> Self
+    {
+        Self{oldRows: 0..0, newRows: 0..0, removedBytes: vec![], addedBytes: vec![]}
+    }
+
+    fn add(&mut self, oldRange: Range<usize>, newRange: Range<usize>, oldSize: usize, newSize: usize)
+    {
+        if self.removedBytes.is_empty() && self.addedBytes.is_empty() {
+            self.oldRows.start = alignToRowStart(oldRange.start);
+            self.newRows.start = alignToRowStart(newRange.start);
+        }
+        self.oldRows.end = alignToRowEnd(oldRange.end, oldSize);
+        self.newRows.end = alignToRowEnd(newRange.end, newSize);
+        if !oldRange.is_empty() {
+            self.removedBytes.push(oldRange);
+        }
+        if !newRange.is_empty() {
+            self.addedBytes.push(newRange);
+        }
+    }
+
+    // Changes are kept together, when showing them apart would repeat rows or leave too little context between them.
+    fn isNear(&self, oldRange: &Range<usize>, newRange: &Range<usize>) -> bool
+    {
+        alignToRowStart(oldRange.start) < self.oldRows.end
+            || alignToRowStart(newRange.start) < self.newRows.end + 2 * CONTEXT_ROWS * BYTES_PER_ROW
+    }
+}
+
+fn collectHunks(operations: &[similar::DiffOp], oldSize: usize, newSize: usize) -> Vec<HexHunk>
+{
+    let mut hunks: Vec<HexHunk> = vec![];
+    for operation in operations {
+        let (tag, oldRange, newRange) = operation.as_tag_tuple();
+        if tag == similar::DiffTag::Equal {
+            continue;
+        }
+        match hunks.last_mut() {
+            Some(hunk) if hunk.isNear(&oldRange, &newRange) => hunk.add(oldRange, newRange, oldSize, newSize),
+            _ => {
+                let mut hunk = HexHunk::new();
+                hunk.add(oldRange, newRange, oldSize, newSize);
+                hunks.push(hunk);
+            }
+        }
+    }
+    hunks
+}
+
+const fn alignToRowStart(offset: usize) -> usize
+{
+    offset - offset % BYTES_PER_ROW
+}
+
+fn alignToRowEnd(offset: usize, size: usize) -> usize
+{
+    alignToRowStart(offset + BYTES_PER_ROW - 1).min(size)
+}
+
+#[derive(Default)]
+struct HexDiffOutput
+{
+    diff: FormattedDiff
+}
+
+impl HexDiffOutput
+{
+    fn push(&mut self, format: LineFormat, text: String)
+    {
+        self.diff.lineFormats.extend(vec![format; text.lines().count()]);
+        self.diff.text.push_str(&text);
+    }
+
+    fn pushRows(&mut self, format: LineFormat, sign: char, content: &[u8], range: Range<usize>)
+    {
+        for rowStart in range.clone().step_by(BYTES_PER_ROW) {
+            let rowEnd = (rowStart + BYTES_PER_ROW).min(range.end);
+            self.push(format.clone(), formatRow(sign, rowStart, &content[rowStart..rowEnd]));
+        }
+    }
+
+    fn pushChangedRows(
+        &mut self,
+        sign: char,
+        content: &[u8],
+        rows: Range<usize>,
+        changedBytes: &[Range<usize>],
+        format: CharacterFormat)
+    {
+        for rowStart in rows.clone().step_by(BYTES_PER_ROW) {
+            let rowEnd = (rowStart + BYTES_PER_ROW).min(rows.end);
+            let line = self.diff.lineFormats.len();
+            self.push(LineFormat::ContextLine, formatRow(sign, rowStart, &content[rowStart..rowEnd]));
+            for changed in changedBytes {
+                let start = changed.start.max(rowStart) - rowStart;
+                let end = changed.end.min(rowEnd).saturating_sub(rowStart);
+                if start >= end {
+                    continue;
+                }
+                self.diff.characterFormats.push(FormattedCharacters{
+                    line, columns: HEX_COLUMN + 3 * start..HEX_COLUMN + 3 * end - 1, format});
+                self.diff.characterFormats.push(FormattedCharacters{
+                    line, columns: TEXT_COLUMN + start..TEXT_COLUMN + end, format});
+            }
+        }
+    }
+
+    fn pushUnchanged(&mut self, content: &[u8], range: Range<usize>, isFirst: bool, isLast: bool)
+    {
+        let contextSize = CONTEXT_ROWS * BYTES_PER_ROW;
+        let leadingContext = if isFirst { 0 } else { contextSize };
+        let trailingContext = if isLast { 0 } else { contextSize };
+        if range.len() <= leadingContext + trailingContext {
+            self.pushRows(LineFormat::ContextLine, ' ', content, range);
+            return;
+        }
+
+        let skippedStart = range.start + leadingContext;
+        let skippedEnd = range.end - trailingContext;
+        self.pushRows(LineFormat::ContextLine, ' ', content, range.start..skippedStart);
+        self.push(LineFormat::HunkHeader, format!("@@ {} unchanged bytes @@\n", skippedEnd - skippedStart));
+        self.pushRows(LineFormat::ContextLine, ' ', content, skippedEnd..range.end);
+    }
+}
+
+fn formatRow(sign: char, offset: usize, bytes: &[u8]) -> String
+{
+    let hex = bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(" ");
+    let text = bytes.iter()
+        .map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { char::from(*byte) } else { '.' })
+        .collect::<String>();
+    format!("{}{:08x}  {:<width$}  |{}|\n", sign, offset, hex, text, width = HEX_WIDTH)
+}
+
+// A missing file is compared as an empty one, a file too large to be read is not compared.
+fn getData(content: Option<&FileContent>) -> Option<&[u8]>
+{
+    match content {
+        Some(content) => content.data.as_deref(),
+        None => Some(&[])
+    }
+}
+
+fn describeContent(content: Option<&FileContent>) -> String
+{
+    match content {
+        None => "none".into(),
+        Some(content) => match content.data.as_deref().and_then(detectFileFormat) {
+            Some(format) => format!("{} bytes, {}", content.size, format),
+            None => format!("{} bytes", content.size)
+        }
+    }
+}
+
+fn formatSizeDelta(oldContent: Option<&FileContent>, newContent: Option<&FileContent>) -> String
+{
+    let oldSize = oldContent.map_or(0, |content| content.size);
+    let newSize = newContent.map_or(0, |content| content.size);
+    match newSize.cmp(&oldSize) {
+        std::cmp::Ordering::Greater => format!("+{} bytes", newSize - oldSize),
+        std::cmp::Ordering::Less => format!("-{} bytes", oldSize - newSize),
+        std::cmp::Ordering::Equal => "0 bytes".into()
+    }
+}
@@ -0,0 +1,252 @@
+use crate::commit_signing::CommitSigner;
+
+use itertools::Itertools;
+use std::cell::RefCell;
+
+const NO_REFERENCE_UPDATE: Option<&str> = None;
+const DEFAULT_SIGNATURE_FIELD: Option<&str> = None;
+
+
+#[derive(Debug)]
+pub(crate) enum HistoryEdit
+{
+    Reword(String),
+    Drop,
+    Split(SplitCommit)
+}
+
+// Each part gets the changes of its hunks on top of the previous parts, the last part gets all remaining changes.
+#[derive(Debug)]
+pub(crate) struct SplitCommit
+{
+    pub parts: Vec<SplitCommitPart>
+}
+
+#[derive(Debug)]
+pub(crate) struct SplitCommitPart
+{
+    pub hunks: Vec<CommitHunk>,
+    pub message: String
+}
+
+// Files without textual hunks, like binary ones, are represented by a single hunk with an empty header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct CommitHunk
+{
+    pub path: String,
+    pub header: String
+}
+
+pub(crate) fn collectCommitHunks(gitRepo: &git2::Repository, commit: &git2::Commit) -> Result<Vec<CommitHunk>, String>
+{
+    let diff = makeDiffOfCommit(gitRepo, commit)?;
+    let hunks = RefCell::new(vec![]);
+    diff.foreach(
+        &mut |delta, _progress| {
+            hunks.borrow_mut().push(CommitHunk{path: getDeltaPath(&delta), header: "".into()});
+            true
+        },
+        None,
+        Some(&mut |delta, hunk| {
+            let path = getDeltaPath(&delta);
+            let mut hunks = hunks.borrow_mut();
+            if hunks.last().is_some_and(|last| last.path == path && last.header.is_empty()) {
+                hunks.pop();
+            }
+            hunks.push(CommitHunk{path, header: getHunkHeader(&hunk)});
+            true
+        }),
+        None)
+        .map_err(|e| format!("Failed to list changes of commit {}: {}", commit.id(), e))?;
+    Ok(hunks.into_inner())
+}
+
+// Commits from the edited one up to HEAD are recreated, the new HEAD commit is returned. Like in a rebase,
+// the current user becomes the committer and the commits are signed only if the user signs new commits.
+pub(crate) fn rewriteHistory(
+    gitRepo: &git2::Repository, target: git2::Oid, edit: &HistoryEdit, signer: Option<&CommitSigner>)
+    -> Result<git2::Oid, String>
+{
+    let committer = gitRepo.signature()
+        .map_err(|e| format!("Failed to get a name and/or email of the committer: {}", e))?;
+    let recreator = CommitRecreator{gitRepo, committer, signer};
+    let descendants = collectDescendants(gitRepo, target)?;
+    let targetCommit = findCommit(gitRepo, target)?;
+    let mut newParent = match edit {
+        HistoryEdit::Reword(message) => {
+            let parents = targetCommit.parents().collect_vec();
+            recreator.recreate(&targetCommit, &parents.iter().collect_vec(), &targetCommit.tree().unwrap(), message)?
+        },
+        HistoryEdit::Drop => targetCommit.parent_id(0).map_err(|_| "The root commit cannot be dropped.".to_owned())?,
+        HistoryEdit::Split(splitCommit) => splitTargetCommit(&recreator, &targetCommit, splitCommit)?
+    };
+
+    for descendant in descendants.iter().rev() {
+        let descendant = findCommit(gitRepo, *descendant)?;
+        let parent = findCommit(gitRepo, newParent)?;
+        let tree = match edit {
+            HistoryEdit::Drop => cherryPick(gitRepo, &descendant, &parent)?,
+            _ => descendant.tree().unwrap()
+        };
+        let message = String::from_utf8_lossy(descendant.message_bytes()).into_owned();
+        newParent = recreator.recreate(&descendant, &[&parent], &tree, &message)?;
+    }
+    Ok(newParent)
+}
+
+// The signatures of these commits are lost when the history is rewritten without signing.
+pub(crate) fn countSignedCommitsToRewrite(gitRepo: &git2::Repository, target: git2::Oid) -> Result<usize, String>
+{
+    let mut commits = collectDescendants(gitRepo, target)?;
+    commits.push(target);
+    Ok(commits.into_iter().filter(|commit| gitRepo.extract_signature(commit, None).is_ok()).count())
+}
+
+
+// private
+
+fn collectDescendants(gitRepo: &git2::Repository, target: git2::Oid) -> Result<Vec<git2::Oid>, String>
+{
+    let mut descendants = vec![];
+    let mut current = gitRepo.head().and_then(|head| head.peel_to_commit())
+        .map_err(|e| format!("Failed to find the HEAD commit: {}", e))?;
+    while current.id() != target {
+        if current.parent_count() != 1 {
+            return Err(format!("Commit {} is not on the current branch or there is a merge commit after it.", target));
+        }
+        descendants.push(current.id());
+        current = current.parent(0).unwrap();
+    }
+    if current.parent_count() > 1 {
+        return Err(format!("Commit {} is a merge commit, which cannot be edited.", target));
+    }
+    Ok(descendants)
+}
+
+fn splitTargetCommit(recreator: &CommitRecreator, target: &git2::Commit, splitCommit: &SplitCommit)
+    -> Result<git2::Oid, String>
+{
+    let gitRepo = recreator.gitRepo;
+    let parentTree = match target.parent(0) {
+        Ok(parent) => parent.tree().unwrap(),
+        Err(_) => makeEmptyTree(gitRepo)?
+    };
+    let diff = makeDiffOfCommit(gitRepo, target)?;
+
+    let mut parents = target.parents().collect_vec();
+    let mut appliedHunks = vec![];
+    let mut newCommit = target.id();
+    for (index, part) in splitCommit.parts.iter().enumerate() {
+        let isLastPart = index + 1 == splitCommit.parts.len();
+        let tree = match isLastPart {
+            true => target.tree().unwrap(),
+            false => {
+                appliedHunks.extend(part.hunks.iter().cloned());
+                applyHunks(gitRepo, &parentTree, &diff, &appliedHunks).map_err(|e| format!(
+                    "Failed to apply the changes of part {} of commit {}: {}", index + 1, target.id(), e))?
+            }
+        };
+        newCommit = recreator.recreate(target, &parents.iter().collect_vec(), &tree, &part.message)?;
+        parents = vec![findCommit(gitRepo, newCommit)?];
+    }
+    Ok(newCommit)
+}
+
+fn applyHunks<'a>(gitRepo: &'a git2::Repository, tree: &git2::Tree, diff: &git2::Diff, hunks: &[CommitHunk])
+    -> Result<git2::Tree<'a>, String>
+{
+    let currentPath = RefCell::new(String::new());
+    let mut applyOptions = git2::ApplyOptions::new();
+    applyOptions.delta_callback(|delta| match delta {
+        Some(delta) => {
+            let path = getDeltaPath(&delta);
+            let isSelected = hunks.iter().any(|hunk| hunk.path == path);
+            *currentPath.borrow_mut() = path;
+            isSelected
+        },
+        None => false
+    });
+    applyOptions.hunk_callback(|hunk| hunk.is_some_and(|hunk| {
+        let header = getHunkHeader(&hunk);
+        hunks.iter().any(|selected| selected.path == *currentPath.borrow() && selected.header == header)
+    }));
+    let index = gitRepo.apply_to_tree(tree, diff, Some(&mut applyOptions)).map_err(|e| e.to_string())?;
+    writeTree(gitRepo, index)
+}
+
+fn cherryPick<'a>(gitRepo: &'a git2::Repository, commit: &git2::Commit, onto: &git2::Commit)
+    -> Result<git2::Tree<'a>, String>
+{
+    let index = gitRepo.cherrypick_commit(commit, onto, 0, None)
+        .map_err(|e| format!("Failed to reapply commit {}: {}", commit.id(), e))?;
+    if index.has_conflicts() {
+        return Err(format!("Commit {} conflicts with the edited history.", commit.id()));
+    }
+    writeTree(gitRepo, index)
+}
+
+fn makeEmptyTree(gitRepo: &git2::Repository) -> Result<git2::Tree<'_>, String>
+{
+    let treeId = gitRepo.treebuilder(None).and_then(|builder| builder.write())
+        .map_err(|e| format!("Failed to create an empty tree: {}", e))?;
+    Ok(gitRepo.find_tree(treeId).unwrap())
+}
+
+fn writeTree(gitRepo: &git2::Repository, mut index: git2::Index) -> Result<git2::Tree<'_>, String>
+{
+    let treeId = index.write_tree_to(gitRepo).map_err(|e| format!("Failed to write a tree: {}", e))?;
+    Ok(gitRepo.find_tree(treeId).unwrap())
+}
+
+fn makeDiffOfCommit<'a>(gitRepo: &'a git2::Repository, commit: &git2::Commit) -> Result<git2::Diff<'a>, String>
+{
+    let parentTree = commit.parent(0).ok().map(|parent| parent.tree().unwrap());
+    gitRepo.diff_tree_to_tree(parentTree.as_ref(), Some(&commit.tree().unwrap()), None)
+        .map_err(|e| format!("Failed to get changes of commit {}: {}", commit.id(), e))
+}
+
+fn findCommit(gitRepo: &git2::Repository, id: git2::Oid) -> Result<git2::Commit<'_>, String>
+{
+    gitRepo.find_commit(id).map_err(|e| format!("Failed to find commit {}: {}", id, e))
+}
+
+fn getDeltaPath(delta: &git2::DiffDelta) -> String
+{
+    let path = delta.new_file().path().or_else(|| delta.old_file().path()).unwrap_or_else(|| "".as_ref());
+    path.to_string_lossy().into()
+}
+
+fn getHunkHeader(hunk: &git2::DiffHunk) -> String
+{
+    String::from_utf8_lossy(hunk.header()).trim_end().into()
+}
+
+
+struct CommitRecreator<'a>
+{
+    gitRepo: &'a git2::Repository,
+    committer: git2::Signature<'static>,
+    signer: Option<&'a CommitSigner>
+}
+
+impl CommitRecreator<'_>
+{
+    fn recreate(&self, original: &git2::Commit, parents: &[&git2::Commit], tree: &git2::Tree, message: &str)
+        -> Result<git2::Oid, String>
+    {
+        let makeError = |e: String| format!("Failed to recreate commit {}: {}", original.id(), e);
+        let author = original.author();
+        match self.signer {
+            Some(signer) => {
+                let buffer = self.gitRepo.commit_create_buffer(&author, &self.committer, message, tree, parents)
+                    .map_err(|e| makeError(e.to_string()))?;
+                let content = buffer.as_str().ok_or_else(|| makeError("its content is not valid UTF-8.".into()))?;
+                let signature = signer.sign(content).map_err(makeError)?;
+                self.gitRepo.commit_signed(content, &signature, DEFAULT_SIGNATURE_FIELD)
+                    .map_err(|e| makeError(e.to_string()))
+            },
+            None => self.gitRepo.commit(NO_REFERENCE_UPDATE, &author, &self.committer, message, tree, parents)
+                .map_err(|e| makeError(e.to_string()))
+        }
+    }
+}
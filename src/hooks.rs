@@ -0,0 +1,140 @@
+use crate::worktree::findCommonGitDir;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const COMMIT_MESSAGE_FILE_NAME: &str = "COMMIT_EDITMSG";
+const COMMENT_CHAR: Option<u8> = Some(b'#');
+
+
+pub(crate) struct Hooks
+{
+    hooksDir: PathBuf,
+    workingDir: PathBuf,
+    gitDir: PathBuf
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum HooksUsage
+{
+    All,
+    // Mirrors "git commit --no-verify", which bypasses only pre-commit and commit-msg.
+    SkipVerification
+}
+
+// Passed to prepare-commit-msg like git does, which gives "commit HEAD" when amending.
+#[derive(Clone, Copy)]
+pub(crate) enum CommitMessageSource
+{
+    Message,
+    AmendedCommit
+}
+
+impl CommitMessageSource
+{
+    fn getHookArguments(self) -> &'static [&'static str]
+    {
+        match self {
+            Self::Message => &["message"],
+            Self::AmendedCommit => &["commit", "HEAD"]
+        }
+    }
+}
+
+impl Hooks
+{
+    pub fn new(gitRepo: &git2::Repository) -> Self
+    {
+        let gitDir = gitRepo.path().to_owned();
+        let workingDir = gitRepo.workdir().unwrap_or(&gitDir).to_owned();
+        let hooksDir = match gitRepo.config().and_then(|config| config.get_path("core.hooksPath")) {
+            Ok(path) if path.is_relative() => workingDir.join(path),
+            Ok(path) => path,
+            Err(_) => findCommonGitDir(gitRepo).join("hooks")
+        };
+        Self{hooksDir, workingDir, gitDir}
+    }
+
+    pub fn hasBeforeCommitHooks(&self, usage: HooksUsage) -> bool
+    {
+        let hookNames: &[&str] = match usage {
+            HooksUsage::All => &["pre-commit", "prepare-commit-msg", "commit-msg"],
+            HooksUsage::SkipVerification => &["prepare-commit-msg"]
+        };
+        hookNames.iter().any(|hookName| isExecutable(&self.hooksDir.join(hookName)))
+    }
+
+    pub fn runBeforeCommit(&self, message: &str, source: CommitMessageSource, usage: HooksUsage)
+        -> Result<String, String>
+    {
+        if usage == HooksUsage::All {
+            self.run("pre-commit", &[])?;
+        }
+
+        let messagePath = self.gitDir.join(COMMIT_MESSAGE_FILE_NAME);
+        fs::write(&messagePath, message)
+            .map_err(|e| format!("Failed to write {}: {}", messagePath.display(), e))?;
+        let messagePathStr = messagePath.to_string_lossy();
+        let arguments = [&[&*messagePathStr], source.getHookArguments()].concat();
+        self.run("prepare-commit-msg", &arguments)?;
+        if usage == HooksUsage::All {
+            self.run("commit-msg", &[&messagePathStr])?;
+        }
+
+        let newMessage = fs::read_to_string(&messagePath)
+            .map_err(|e| format!("Failed to read {}: {}", messagePath.display(), e))?;
+        match newMessage == message {
+            true => Ok(newMessage),
+            false => git2::message_prettify(newMessage, COMMENT_CHAR).map_err(|e| e.to_string())
+        }
+    }
+
+    pub fn runAfterCommit(&self)
+    {
+        if let Err(e) = self.run("post-commit", &[]) {
+            eprintln!("{}", e);
+        }
+    }
+
+
+    // private
+
+    fn run(&self, hookName: &str, args: &[&str]) -> Result<(), String>
+    {
+        let hookPath = self.hooksDir.join(hookName);
+        if !isExecutable(&hookPath) {
+            return Ok(());
+        }
+
+        let output = Command::new(&hookPath)
+            .args(args)
+            .current_dir(&self.workingDir)
+            .env("GIT_DIR", &self.gitDir)
+            .output()
+            .map_err(|e| format!("Failed to run the {} hook: {}", hookName, e))?;
+
+        match output.status.success() {
+            true => Ok(()),
+            false => Err(format!(
+                "The {} hook failed ({}):\n{}{}",
+                hookName,
+                output.status,
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)).trim_end().into())
+        }
+    }
+}
+
+#[cfg(unix)]
+fn isExecutable(path: &Path) -> bool
+{
+    use std::os::unix::fs::PermissionsExt as _;
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn isExecutable(path: &Path) -> bool
+{
+    path.is_file()
+}
@@ -0,0 +1,155 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+
+// Only plain prefix and suffix rules are expanded; compounding and morphology are not supported,
+// which is enough to recognize the inflected forms of commonly used words.
+pub(crate) fn loadHunspellWords(dictionaryPath: &Path, affixPath: &Path) -> Result<HashSet<String>, String>
+{
+    let dictionary = readLossy(dictionaryPath)?;
+    let affixes = match affixPath.is_file() {
+        true => parseAffixes(&readLossy(affixPath)?),
+        false => Affixes::default()
+    };
+
+    let mut words = HashSet::new();
+    // The first line of a dictionary holds the approximate number of entries.
+    for entry in dictionary.lines().skip(1) {
+        let entry = match entry.split_whitespace().next() {
+            Some(entry) => entry,
+            None => continue
+        };
+        let (word, flags) = entry.split_once('/').unwrap_or((entry, ""));
+        for flag in affixes.flagFormat.split(flags) {
+            if let Some(rules) = affixes.rules.get(&flag) {
+                for rule in rules {
+                    if let Some(affixedWord) = rule.apply(word) {
+                        words.insert(affixedWord.to_lowercase());
+                    }
+                }
+            }
+        }
+        words.insert(word.to_lowercase());
+    }
+    Ok(words)
+}
+
+
+// private
+
+#[derive(Clone, Copy, Default)]
+enum FlagFormat
+{
+    #[default]
+    SingleCharacter,
+    TwoCharacters,
+    Numbers
+}
+
+impl FlagFormat
+{
+    fn split(self, flags: &str) -> Vec<String>
+    {
+        match self {
+            Self::SingleCharacter => flags.chars().map(String::from).collect(),
+            Self::TwoCharacters => {
+                let characters = flags.chars().collect::<Vec<_>>();
+                characters.chunks(2).map(|chunk| chunk.iter().collect()).collect()
+            },
+            Self::Numbers => flags.split(',').filter(|flag| !flag.is_empty()).map(String::from).collect()
+        }
+    }
+}
+
+#[derive(Default)]
+struct Affixes
+{
+    flagFormat: FlagFormat,
+    rules: HashMap<String, Vec<AffixRule>>
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+enum AffixKind
+{
+    Prefix,
+    Suffix
+}
+
+struct AffixRule
+{
+    kind: AffixKind,
+    strip: String,
+    add: String,
+    condition: Regex
+}
+
+impl AffixRule
+{
+    fn apply(&self, word: &str) -> Option<String>
+    {
+        if !self.condition.is_match(word) {
+            return None;
+        }
+        match self.kind {
+            AffixKind::Prefix => word.strip_prefix(&self.strip).map(|rest| format!("{}{}", self.add, rest)),
+            AffixKind::Suffix => word.strip_suffix(&self.strip).map(|rest| format!("{}{}", rest, self.add))
+        }
+    }
+}
+
+fn parseAffixes(content: &str) -> Affixes
+{
+    let mut affixes = Affixes::default();
+    let mut declaredFlags = HashSet::new();
+    for line in content.lines() {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        match fields.as_slice() {
+            ["FLAG", "long", ..] => affixes.flagFormat = FlagFormat::TwoCharacters,
+            ["FLAG", "num", ..] => affixes.flagFormat = FlagFormat::Numbers,
+            [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                let kind = match *kind {
+                    "PFX" => AffixKind::Prefix,
+                    _ => AffixKind::Suffix
+                };
+                // The first line of every affix class is a header with the cross product flag and the rule count.
+                if declaredFlags.insert((kind, (*flag).to_owned())) {
+                    continue;
+                }
+                let condition = rest.first().copied().unwrap_or(".");
+                if let Some(rule) = makeAffixRule(kind, strip, add, condition) {
+                    affixes.rules.entry((*flag).into()).or_default().push(rule);
+                }
+            },
+            _ => ()
+        }
+    }
+    affixes
+}
+
+fn makeAffixRule(kind: AffixKind, strip: &str, add: &str, condition: &str) -> Option<AffixRule>
+{
+    let strip = match strip {
+        "0" => "",
+        strip => strip
+    };
+    // Affixes can have continuation flags, which are not supported.
+    let add = match add.split('/').next().unwrap_or_default() {
+        "0" => "",
+        add => add
+    };
+    let condition = match kind {
+        AffixKind::Prefix => format!("^(?:{})", condition),
+        AffixKind::Suffix => format!("(?:{})$", condition)
+    };
+    let condition = Regex::new(&condition).ok()?;
+    Some(AffixRule{kind, strip: strip.into(), add: add.into(), condition})
+}
+
+fn readLossy(path: &Path) -> Result<String, String>
+{
+    fs::read(path)
+        .map(|bytes| String::from_utf8_lossy(&bytes).into())
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
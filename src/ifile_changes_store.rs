@@ -1,10 +1,26 @@
 use crate::file_change::FileChange;
-use crate::file_path::FilePathStr;
+use crate::file_path::{FilePathStr, FilePathString};
 
 
 pub(crate) trait IFileChangesStore
 {
-    fn getFileChange(&self, row: usize) -> &FileChange;
-    fn getFilePath(&self, row: usize) -> &FilePathStr;
+    fn getFileChanges(&self) -> &[FileChange];
+    fn getFileChange(&self, rowPath: &gtk::TreePath) -> Option<&FileChange>;
+    fn getFilePath(&self, rowPath: &gtk::TreePath) -> Option<FilePathString>;
+    fn collectFileChanges(&self, rowPath: &gtk::TreePath) -> Vec<FileChange>;
     fn findFilePath(&self, path: &FilePathStr) -> Option<usize>;
+
+    // A file change is collected once even when both it and its directory are among the rows.
+    fn collectFileChangesInRows(&self, rowPaths: &[gtk::TreePath]) -> Vec<FileChange>
+    {
+        let mut fileChanges: Vec<FileChange> = vec![];
+        for rowPath in rowPaths {
+            for fileChange in self.collectFileChanges(rowPath) {
+                if !fileChanges.iter().any(|collected| collected.path == fileChange.path) {
+                    fileChanges.push(fileChange);
+                }
+            }
+        }
+        fileChanges
+    }
 }
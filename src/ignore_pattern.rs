@@ -0,0 +1,102 @@
+use crate::file_path::FilePathStr;
+
+use std::io::Write as _;
+use std::path::Path;
+
+const PATH_SEPARATOR: char = '/';
+const GLOB_SPECIAL_CHARACTERS: &[char] = &['*', '?', '[', '\\'];
+const LINE_SPECIAL_PREFIXES: &[char] = &['#', '!'];
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum IgnoreFile
+{
+    // .gitignore in the root of the working directory, which is shared through commits
+    Gitignore,
+    // .git/info/exclude, which stays local to the repository
+    InfoExclude
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct IgnorePattern
+{
+    pub pattern: String,
+    pub file: IgnoreFile
+}
+
+// Labels and patterns of the ways to ignore an untracked file or directory, as offered in the context menu.
+pub(crate) fn makeIgnorePatterns(path: &FilePathStr, isDirectory: bool) -> Vec<(String, IgnorePattern)>
+{
+    if isDirectory {
+        return vec![
+            ("Ignore this directory".into(), makeGitignorePattern(anchorDirectory(path))),
+            ("Add to .git/info/exclude".into(), makeInfoExcludePattern(anchorDirectory(path)))];
+    }
+
+    let mut patterns = vec![("Ignore this file".into(), makeGitignorePattern(anchor(path)))];
+    if let Some(extension) = Path::new(path).extension().and_then(|extension| extension.to_str()) {
+        patterns.push((format!("Ignore all *.{}", extension), makeGitignorePattern(format!("*.{}", escape(extension)))));
+    }
+    if let Some((directory, _fileName)) = path.rsplit_once(PATH_SEPARATOR) {
+        patterns.push(("Ignore this directory".into(), makeGitignorePattern(anchorDirectory(directory))));
+    }
+    patterns.push(("Add to .git/info/exclude".into(), makeInfoExcludePattern(anchor(path))));
+    patterns
+}
+
+// A line break is added first when the file does not end with one, so that the pattern gets its own line.
+pub(crate) fn appendIgnorePattern(ignoreFilePath: &Path, pattern: &str) -> std::io::Result<()>
+{
+    let content = match std::fs::read(ignoreFilePath) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+        Err(e) => return Err(e)
+    };
+    if let Some(directory) = ignoreFilePath.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(ignoreFilePath)?;
+    if content.last().is_some_and(|byte| *byte != b'\n') {
+        file.write_all(b"\n")?;
+    }
+    file.write_all(format!("{}\n", pattern).as_bytes())
+}
+
+
+// private
+
+const fn makeGitignorePattern(pattern: String) -> IgnorePattern
+{
+    IgnorePattern{pattern, file: IgnoreFile::Gitignore}
+}
+
+const fn makeInfoExcludePattern(pattern: String) -> IgnorePattern
+{
+    IgnorePattern{pattern, file: IgnoreFile::InfoExclude}
+}
+
+// A leading separator matches the path only relative to the root of the working directory.
+fn anchor(path: &FilePathStr) -> String
+{
+    format!("{}{}", PATH_SEPARATOR, escape(path))
+}
+
+fn anchorDirectory(path: &FilePathStr) -> String
+{
+    format!("{}{}", anchor(path), PATH_SEPARATOR)
+}
+
+fn escape(text: &str) -> String
+{
+    let mut escaped = String::new();
+    for (index, character) in text.char_indices() {
+        if GLOB_SPECIAL_CHARACTERS.contains(&character) || (index == 0 && LINE_SPECIAL_PREFIXES.contains(&character)) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    if escaped.ends_with(' ') {
+        escaped.insert(escaped.len() - 1, '\\');
+    }
+    escaped
+}
@@ -0,0 +1,237 @@
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::{cairo, gdk_pixbuf};
+use gtk::gdk::prelude::GdkContextExt as _;
+use gtk::prelude::ComboBoxExt as _;
+use gtk::prelude::ComboBoxExtManual as _;
+use gtk::prelude::LabelExt as _;
+use gtk::prelude::PixbufLoaderExt as _;
+use gtk::prelude::RangeExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// SVG is left out, because it is text, whose diff tells more than the rendered images.
+const IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "gif", "webp"];
+const SIDE_BY_SIDE_GAP: f64 = 8.0;
+const BACKGROUND_COLOR: (f64, f64, f64) = (0.85, 0.85, 0.85);
+const STOP_DRAWING: gtk::glib::Propagation = gtk::glib::Propagation::Stop;
+
+
+pub(crate) struct ImageDiffView
+{
+    replacedWidget: Option<gtk::ScrolledWindow>,
+    widget: gtk::Box,
+    infoLabel: gtk::Label,
+    drawingArea: gtk::DrawingArea,
+    images: Rc<RefCell<ImagePair>>
+}
+
+// The replaced widget, if any, is hidden while images are shown in its place.
+pub(crate) struct ImageDiffWidgetData
+{
+    pub boxName: &'static str,
+    pub infoLabelName: &'static str,
+    pub drawingAreaName: &'static str,
+    pub modeComboBoxName: &'static str,
+    pub opacityScaleName: &'static str,
+    pub replacedWidgetName: Option<&'static str>
+}
+
+#[derive(Default)]
+struct ImagePair
+{
+    old: Option<gdk_pixbuf::Pixbuf>,
+    new: Option<gdk_pixbuf::Pixbuf>
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum ImageDiffMode
+{
+    SideBySide,
+    OnionSkin,
+    Difference
+}
+
+impl ImageDiffView
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, widgetData: &ImageDiffWidgetData) -> Self
+    {
+        let replacedWidget = widgetData.replacedWidgetName
+            .map(|name| guiElementProvider.get::<gtk::ScrolledWindow>(name));
+        let newSelf = Self{
+            replacedWidget,
+            widget: guiElementProvider.get::<gtk::Box>(widgetData.boxName),
+            infoLabel: guiElementProvider.get::<gtk::Label>(widgetData.infoLabelName),
+            drawingArea: guiElementProvider.get::<gtk::DrawingArea>(widgetData.drawingAreaName),
+            images: Rc::new(RefCell::new(ImagePair::default()))
+        };
+        newSelf.connectWidgets(guiElementProvider, widgetData);
+        newSelf
+    }
+
+    // Either version is None when the file does not exist on that side, e.g. it is new or deleted.
+    pub fn show(&self, oldContent: Option<&[u8]>, newContent: Option<&[u8]>)
+    {
+        let old = oldContent.and_then(loadImage);
+        let new = newContent.and_then(loadImage);
+        self.infoLabel.set_text(&format!(
+            "Old: {}    New: {}", describeImage(oldContent, old.as_ref()), describeImage(newContent, new.as_ref())));
+        *self.images.borrow_mut() = ImagePair{old, new};
+        if let Some(replacedWidget) = &self.replacedWidget {
+            replacedWidget.set_visible(false);
+        }
+        self.widget.set_visible(true);
+        self.drawingArea.queue_draw();
+    }
+
+    pub fn hide(&self)
+    {
+        *self.images.borrow_mut() = ImagePair::default();
+        self.widget.set_visible(false);
+        if let Some(replacedWidget) = &self.replacedWidget {
+            replacedWidget.set_visible(true);
+        }
+    }
+
+
+    // private
+
+    fn connectWidgets(&self, guiElementProvider: &GuiElementProvider, widgetData: &ImageDiffWidgetData)
+    {
+        let modeComboBox = guiElementProvider.get::<gtk::ComboBoxText>(widgetData.modeComboBoxName);
+        let opacityScale = guiElementProvider.get::<gtk::Scale>(widgetData.opacityScaleName);
+
+        let drawingArea = self.drawingArea.clone();
+        let opacityScaleClone = opacityScale.clone();
+        modeComboBox.connect_changed(move |comboBox| {
+            opacityScaleClone.set_sensitive(getMode(comboBox) == ImageDiffMode::OnionSkin);
+            drawingArea.queue_draw();
+        });
+        let drawingArea = self.drawingArea.clone();
+        opacityScale.connect_value_changed(move |_scale| drawingArea.queue_draw());
+
+        let images = Rc::clone(&self.images);
+        self.drawingArea.connect_draw(move |area, context| {
+            let size = (f64::from(area.allocated_width()), f64::from(area.allocated_height()));
+            let mode = getMode(&modeComboBox);
+            if let Err(e) = drawImages(context, &images.borrow(), mode, opacityScale.value(), size) {
+                eprintln!("Failed to draw the image diff: {}", e);
+            }
+            STOP_DRAWING
+        });
+    }
+}
+
+pub(crate) fn isImagePath(path: &str) -> bool
+{
+    std::path::Path::new(path).extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| IMAGE_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+}
+
+fn getMode(comboBox: &gtk::ComboBoxText) -> ImageDiffMode
+{
+    match comboBox.active() {
+        Some(1) => ImageDiffMode::OnionSkin,
+        Some(2) => ImageDiffMode::Difference,
+        _ => ImageDiffMode::SideBySide
+    }
+}
+
+fn loadImage(content: &[u8]) -> Option<gdk_pixbuf::Pixbuf>
+{
+    let loader = gdk_pixbuf::PixbufLoader::new();
+    let result = loader.write(content).and_then(|()| loader.close());
+    if let Err(e) = result {
+        eprintln!("Failed to decode an image: {}", e);
+        return None;
+    }
+    loader.pixbuf()
+}
+
+fn describeImage(content: Option<&[u8]>, image: Option<&gdk_pixbuf::Pixbuf>) -> String
+{
+    match (content, image) {
+        (None, _) => "none".into(),
+        (Some(content), Some(image)) =>
+            format!("{}×{} px, {}", image.width(), image.height(), formatFileSize(content.len())),
+        (Some(content), None) => format!("cannot be decoded, {}", formatFileSize(content.len()))
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn formatFileSize(size: usize) -> String
+{
+    match size {
+        0..=1023 => format!("{} B", size),
+        1024..=1_048_575 => format!("{:.1} KiB", size as f64 / 1024.0),
+        _ => format!("{:.1} MiB", size as f64 / 1_048_576.0)
+    }
+}
+
+fn drawImages(context: &cairo::Context, images: &ImagePair, mode: ImageDiffMode, opacity: f64, size: (f64, f64))
+    -> Result<(), cairo::Error>
+{
+    let (red, green, blue) = BACKGROUND_COLOR;
+    context.set_source_rgb(red, green, blue);
+    context.paint()?;
+
+    let (width, height) = size;
+    match (mode, &images.old, &images.new) {
+        (ImageDiffMode::SideBySide, old, new) => {
+            let halfWidth = ((width - SIDE_BY_SIDE_GAP) / 2.0).max(1.0);
+            if let Some(old) = old {
+                let scale = fitScale(&[old], halfWidth, height);
+                drawImage(context, old, 0.0, scale, |context| context.paint())?;
+            }
+            if let Some(new) = new {
+                let scale = fitScale(&[new], halfWidth, height);
+                drawImage(context, new, halfWidth + SIDE_BY_SIDE_GAP, scale, |context| context.paint())?;
+            }
+        },
+        (_, Some(old), Some(new)) => {
+            let scale = fitScale(&[old, new], width, height);
+            drawImage(context, old, 0.0, scale, |context| context.paint())?;
+            match mode {
+                ImageDiffMode::OnionSkin =>
+                    drawImage(context, new, 0.0, scale, |context| context.paint_with_alpha(opacity))?,
+                _ => drawImage(context, new, 0.0, scale, |context| {
+                    context.set_operator(cairo::Operator::Difference);
+                    context.paint()
+                })?
+            }
+        },
+        (_, Some(image), None) | (_, None, Some(image)) => {
+            let scale = fitScale(&[image], width, height);
+            drawImage(context, image, 0.0, scale, |context| context.paint())?;
+        },
+        (_, None, None) => ()
+    }
+    Ok(())
+}
+
+// Images are only scaled down, so that small ones keep their pixels sharp.
+fn fitScale(images: &[&gdk_pixbuf::Pixbuf], width: f64, height: f64) -> f64
+{
+    let imagesWidth = images.iter().map(|image| image.width()).max().unwrap_or(1).max(1);
+    let imagesHeight = images.iter().map(|image| image.height()).max().unwrap_or(1).max(1);
+    (width / f64::from(imagesWidth)).min(height / f64::from(imagesHeight)).min(1.0)
+}
+
+fn drawImage(
+    context: &cairo::Context,
+    image: &gdk_pixbuf::Pixbuf,
+    x: f64,
+    scale: f64,
+    paint: impl FnOnce(&cairo::Context) -> Result<(), cairo::Error>)
+    -> Result<(), cairo::Error>
+{
+    context.save()?;
+    context.translate(x, 0.0);
+    context.scale(scale, scale);
+    context.set_source_pixbuf(image, 0.0, 0.0);
+    let result = paint(context);
+    context.restore()?;
+    result
+}
@@ -0,0 +1,25 @@
+use crate::event::{Event, Sender, Source};
+use crate::file_path::FilePathStr;
+use crate::index_flags::{INDEX_FLAGS, IndexFlags};
+
+use gtk::prelude::CheckMenuItemExt as _;
+
+
+// The items show the current state of the flags and toggling one asks to change it in the index.
+pub(crate) fn makeIndexFlagMenuItems(path: &FilePathStr, flags: IndexFlags, sender: &Sender, source: Source)
+    -> Vec<gtk::CheckMenuItem>
+{
+    INDEX_FLAGS.iter()
+        .map(|flag| {
+            let menuItem = gtk::CheckMenuItem::with_label(flag.getLabel());
+            menuItem.set_active(flags.isSet(*flag));
+            let path = path.to_owned();
+            let flag = *flag;
+            let sender = sender.clone();
+            menuItem.connect_toggled(move |item| {
+                sender.send((source, Event::SetIndexFlagRequested(path.clone(), flag, item.is_active()))).unwrap();
+            });
+            menuItem
+        })
+        .collect()
+}
@@ -0,0 +1,85 @@
+use crate::file_path::FilePathString;
+
+// Bits of git index entries, which git2 does not name.
+const ASSUME_UNCHANGED_BIT: u16 = 0x8000;
+const SKIP_WORKTREE_EXTENDED_BIT: u16 = 0x4000;
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum IndexFlag
+{
+    AssumeUnchanged,
+    SkipWorktree
+}
+
+impl IndexFlag
+{
+    pub const fn getLabel(self) -> &'static str
+    {
+        match self {
+            Self::AssumeUnchanged => "Assume unchanged",
+            Self::SkipWorktree    => "Skip worktree"
+        }
+    }
+
+    pub const fn getName(self) -> &'static str
+    {
+        match self {
+            Self::AssumeUnchanged => "assume-unchanged",
+            Self::SkipWorktree    => "skip-worktree"
+        }
+    }
+}
+
+pub(crate) const INDEX_FLAGS: [IndexFlag; 2] = [IndexFlag::AssumeUnchanged, IndexFlag::SkipWorktree];
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct IndexFlags
+{
+    pub assumesUnchanged: bool,
+    pub skipsWorktree: bool
+}
+
+impl IndexFlags
+{
+    pub const fn from(entry: &git2::IndexEntry) -> Self
+    {
+        Self{
+            assumesUnchanged: entry.flags & ASSUME_UNCHANGED_BIT != 0,
+            skipsWorktree: entry.flags_extended & SKIP_WORKTREE_EXTENDED_BIT != 0
+        }
+    }
+
+    pub const fn isSet(&self, flag: IndexFlag) -> bool
+    {
+        match flag {
+            IndexFlag::AssumeUnchanged => self.assumesUnchanged,
+            IndexFlag::SkipWorktree    => self.skipsWorktree
+        }
+    }
+
+    pub const fn isAnySet(&self) -> bool
+    {
+        self.assumesUnchanged || self.skipsWorktree
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FlaggedFile
+{
+    pub path: FilePathString,
+    pub flags: IndexFlags
+}
+
+// libgit2 marks the entry as extended by itself when writing the index, so only the flag bit is changed here.
+pub(crate) fn setIndexFlag(entry: &mut git2::IndexEntry, flag: IndexFlag, isEnabled: bool)
+{
+    let (bits, bit) = match flag {
+        IndexFlag::AssumeUnchanged => (&mut entry.flags, ASSUME_UNCHANGED_BIT),
+        IndexFlag::SkipWorktree    => (&mut entry.flags_extended, SKIP_WORKTREE_EXTENDED_BIT)
+    };
+    match isEnabled {
+        true => *bits |= bit,
+        false => *bits &= !bit
+    }
+}
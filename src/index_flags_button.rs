@@ -0,0 +1,121 @@
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::gui_element_provider::GuiElementProvider;
+use crate::index_flag_menu_items::makeIndexFlagMenuItems;
+use crate::index_flags::{FlaggedFile, INDEX_FLAGS};
+use crate::repository::Repository;
+
+use gtk::gdk;
+use gtk::prelude::ButtonExt as _;
+use gtk::prelude::GtkMenuExt as _;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MenuShellExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::SystemTime;
+
+const LABEL: &str = "Index flags";
+
+
+// Files marked as assume-unchanged or skip-worktree do not show up as changed, so they are listed here
+// and counted in the label, so that they are not forgotten.
+pub(crate) struct IndexFlagsButton
+{
+    widget: gtk::Button,
+    repository: Rc<RefCell<Repository>>,
+    indexModificationTime: Option<SystemTime>,
+    sender: Sender
+}
+
+impl IEventHandler for IndexFlagsButton
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::Clicked   => self.showFlaggedFilesMenu(),
+            Event::Refreshed => self.onRefreshed(),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl IndexFlagsButton
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, repository: Rc<RefCell<Repository>>, sender: Sender) -> Self
+    {
+        let widget = guiElementProvider.get::<gtk::Button>("Index flags button");
+        let indexModificationTime = repository.borrow().getIndexModificationTime();
+        let newSelf = Self{widget, repository, indexModificationTime, sender};
+        newSelf.connectWidget();
+        newSelf.updateLabel();
+        newSelf
+    }
+
+
+    // private
+
+    fn connectWidget(&self)
+    {
+        let sender = self.sender.clone();
+        self.widget.connect_clicked(move |_button| {
+            sender.send((Source::IndexFlagsButton, Event::Clicked)).unwrap();
+        });
+    }
+
+    // Counting flagged files goes through the whole index, so it is done only when the index has changed.
+    fn onRefreshed(&mut self)
+    {
+        let indexModificationTime = self.repository.borrow().getIndexModificationTime();
+        if indexModificationTime.is_some() && indexModificationTime == self.indexModificationTime {
+            return;
+        }
+        self.indexModificationTime = indexModificationTime;
+        self.updateLabel();
+    }
+
+    fn updateLabel(&self)
+    {
+        match self.repository.borrow().collectFlaggedFiles().len() {
+            0 => self.widget.set_label(LABEL),
+            count => self.widget.set_label(&format!("{} ({})", LABEL, count))
+        }
+    }
+
+    fn showFlaggedFilesMenu(&self)
+    {
+        let flaggedFiles = self.repository.borrow().collectFlaggedFiles();
+        let menu = gtk::Menu::new();
+        for flaggedFile in &flaggedFiles {
+            menu.append(&self.makeFlaggedFileMenuItem(flaggedFile));
+        }
+        if flaggedFiles.is_empty() {
+            let menuItem = gtk::MenuItem::with_label("No files are assume-unchanged or skip-worktree");
+            menuItem.set_sensitive(false);
+            menu.append(&menuItem);
+        }
+
+        menu.show_all();
+        menu.popup_at_widget(&self.widget, gdk::Gravity::SouthWest, gdk::Gravity::NorthWest, None);
+    }
+
+    fn makeFlaggedFileMenuItem(&self, flaggedFile: &FlaggedFile) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label(&formatFlaggedFile(flaggedFile));
+        let submenu = gtk::Menu::new();
+        for flagMenuItem in makeIndexFlagMenuItems(
+            &flaggedFile.path, flaggedFile.flags, &self.sender, Source::IndexFlagsButton) {
+            submenu.append(&flagMenuItem);
+        }
+        menuItem.set_submenu(Some(&submenu));
+        menuItem
+    }
+}
+
+fn formatFlaggedFile(flaggedFile: &FlaggedFile) -> String
+{
+    let flagNames = INDEX_FLAGS.iter()
+        .filter(|flag| flaggedFile.flags.isSet(**flag))
+        .map(|flag| flag.getName())
+        .collect::<Vec<_>>();
+    format!("{} ({})", flaggedFile.path, flagNames.join(", "))
+}
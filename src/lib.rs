@@ -28,13 +28,19 @@
 pub mod app_setup;
 pub mod gui;
 
+mod app_launcher;
 mod app_quitter;
 mod application_window;
+mod blob_source;
 mod color;
 mod commit_amend_checkbox;
+mod commit_author_button;
+mod commit_author_dialog;
+mod commit_author_options;
 mod commit_button;
 mod commit_diff;
 mod commit_diff_view;
+mod commit_id;
 mod commit_log;
 mod commit_log_author_filter_widgets;
 mod commit_log_column;
@@ -48,53 +54,98 @@ mod commit_log_save_filter_dialog;
 mod commit_log_selections_comparer;
 mod commit_log_show_filter_button;
 mod commit_log_summary_filter_widgets;
-mod commit_log_text_filter_widgets;
 mod commit_log_view;
 mod commit_message;
+mod commit_message_dialog;
+mod commit_message_draft;
+mod commit_message_linter;
 mod commit_message_reader;
+mod commit_message_ruler;
+mod commit_message_spelling;
+mod commit_message_template;
+mod commit_message_trailers;
 mod commit_message_view;
+mod commit_signing;
+mod commit_skip_hooks_checkbox;
 mod config;
 mod config_path;
 mod config_store;
+mod confirmation_dialog;
 mod date_time;
 mod diff_and_commit_pane;
 mod diff_colorizer;
 mod diff_formatter;
 mod diff_view;
+mod directory_tree_checkbox;
 mod error_handling;
 mod event;
 mod event_constants;
 mod file_change;
 mod file_changes_column;
+mod file_changes_drag_and_drop;
+mod file_changes_filter_widgets;
+mod file_changes_model_filter;
 mod file_changes_pane;
+mod file_changes_sort_combo_box;
+mod file_changes_sort_order;
 mod file_changes_store;
+mod file_changes_tree;
 mod file_changes_view;
 mod file_changes_view_entry;
 mod file_path;
+mod file_status;
+mod fixup_commit;
+mod git_command;
 mod grouped_file_changes;
 mod gui_element_provider;
+mod head_label;
+mod head_state;
+mod hex_diff;
+mod history_rewriting;
+mod hooks;
+mod hunspell_dictionary;
 mod ifile_changes_store;
+mod ignore_pattern;
+mod ignored_files_checkbox;
+mod index_flag_menu_items;
+mod index_flags;
+mod index_flags_button;
+mod image_diff_view;
 mod line_count;
 mod line_number;
 mod main_context;
 mod main_pane;
 mod main_stack;
+mod new_tag;
 mod number_casts;
 mod original_row;
 mod pane;
+mod path_glob;
+mod ref_label;
 mod refresh_button;
 mod repository;
+mod reset_mode;
 mod selections_comparer;
+mod spell_checker;
+mod split_commit_dialog;
 mod staged_changes;
 mod staged_changes_store;
 mod staged_changes_view;
 mod line_diff;
+mod submodule_change;
+mod tag_dialog;
 mod text_filter;
+mod text_filter_widgets;
+mod text_search;
 mod text_view;
 mod tool_bar_stack;
+mod trailer_dialog;
 mod tree_model_utils;
 mod tree_selection;
 mod tree_view;
 mod unstaged_changes;
 mod unstaged_changes_store;
 mod unstaged_changes_view;
+mod worktree;
+mod worktree_dialog;
+mod worktrees_button;
@@ -0,0 +1,6 @@
+#[derive(Debug)]
+pub(crate) struct NewTag
+{
+    pub name: String,
+    pub message: Option<String>
+}
@@ -0,0 +1,58 @@
+const GLOB_SPECIAL_CHARACTERS: &[char] = &['*', '?', '['];
+const PATH_SEPARATOR: char = '/';
+
+
+#[must_use]
+pub(crate) fn isGlob(text: &str) -> bool
+{
+    text.contains(GLOB_SPECIAL_CHARACTERS)
+}
+
+// Like in .gitignore, a glob matches the whole path or its trailing components, so "*.rs" matches files
+// in all directories. "*" and "?" do not cross directories, "**" does and "**/" also matches no directory at all.
+#[must_use]
+pub(crate) fn convertGlobToRegex(glob: &str) -> String
+{
+    let mut regex = format!("(^|{})", PATH_SEPARATOR);
+    let mut characters = glob.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '*' if characters.peek() == Some(&'*') => {
+                characters.next();
+                match characters.next_if_eq(&PATH_SEPARATOR) {
+                    Some(_) => regex.push_str("(.*/)?"),
+                    None => regex.push_str(".*")
+                }
+            },
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => regex.push_str(&convertBracketExpression(&mut characters)),
+            _ => regex.push_str(&regex::escape(&character.to_string()))
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+
+// private
+
+// An unclosed bracket is matched literally.
+fn convertBracketExpression(characters: &mut std::iter::Peekable<std::str::Chars>) -> String
+{
+    let rest = characters.clone().collect::<String>();
+    let end = match rest.find(']') {
+        Some(end) => end,
+        None => return regex::escape("[")
+    };
+    let expression = &rest[..end];
+    for _ in expression.chars().chain(std::iter::once(']')) {
+        characters.next();
+    }
+    // Like "*" and "?", a negated bracket expression does not match the separator.
+    let expression = match expression.strip_prefix('!').or_else(|| expression.strip_prefix('^')) {
+        Some(negatedExpression) => format!("^{}{}", PATH_SEPARATOR, negatedExpression),
+        None => expression.into()
+    };
+    format!("[{}]", expression.replace('\\', "\\\\"))
+}
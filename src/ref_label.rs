@@ -0,0 +1,17 @@
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct RefLabel
+{
+    pub name: String,
+    pub kind: RefKind
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum RefKind
+{
+    Head,
+    LocalBranch,
+    // A local branch checked out in another worktree, so it cannot be checked out here.
+    OtherWorktreeBranch,
+    RemoteBranch,
+    Tag
+}
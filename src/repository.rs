@@ -1,32 +1,55 @@
+use crate::app_launcher::launchApp;
+use crate::blob_source::{BlobSource, FileContent};
+use crate::commit_author_options::CommitAuthorOptions;
+use crate::commit_id::shortenCommitId;
+use crate::commit_message::CommitMessage;
+use crate::commit_message_template::loadCommitMessageTemplate;
+use crate::commit_signing::{CommitSigner, SignatureFormat, SignatureVerification};
 use crate::config::Config;
+use crate::confirmation_dialog::askForConfirmation;
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
-use crate::error_handling::{exit, showErrorDialog};
-use crate::file_change::{FileChange, FileChangeUpdate};
+use crate::error_handling::{exit, showErrorDialog, showInfoDialog};
+use crate::file_change::{FileChange, FileChangeUpdate, LineStats};
+use crate::file_path::{FilePathStr, FilePathString};
+use crate::file_status::{FileStatus, FileStatusKind, FileStatusSide};
+use crate::fixup_commit::{AutosquashBase, findFixupTarget, isFixupTarget};
+use crate::git_command::runGitCommand;
 use crate::grouped_file_changes::GroupedFileChanges;
+use crate::head_state::HeadState;
+use crate::history_rewriting::{
+    collectCommitHunks,
+    CommitHunk,
+    countSignedCommitsToRewrite,
+    HistoryEdit,
+    rewriteHistory};
+use crate::hooks::{CommitMessageSource, Hooks, HooksUsage};
+use crate::ignore_pattern::{appendIgnorePattern, IgnoreFile, IgnorePattern};
+use crate::index_flags::{FlaggedFile, IndexFlag, IndexFlags, setIndexFlag};
+use crate::main_context::{attach, makeChannel};
+use crate::new_tag::NewTag;
+use crate::ref_label::{RefKind, RefLabel};
+use crate::reset_mode::ResetMode;
 use crate::staged_changes::StagedChanges;
+use crate::submodule_change::{findSubmoduleChange, SubmoduleChange, SubmoduleChangeKind};
 use crate::unstaged_changes::UnstagedChanges;
+use crate::worktree::{collectWorktrees, findCommonGitDir, NewWorktree, Worktree};
 
+use gtk::glib;
 use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::io::Read as _;
 use std::path::Path;
+use std::time::SystemTime;
 
 const CURRENT_INDEX : Option<&git2::Index> = None;
-const UNSTAGED_STATUSES : [git2::Status; 5] = [
-    git2::Status::WT_NEW,
-    git2::Status::WT_MODIFIED,
-    git2::Status::WT_DELETED,
-    git2::Status::WT_TYPECHANGE,
-    git2::Status::WT_RENAMED];
-const STAGED_STATUSES : [git2::Status; 5] = [
-    git2::Status::INDEX_NEW,
-    git2::Status::INDEX_MODIFIED,
-    git2::Status::INDEX_DELETED,
-    git2::Status::INDEX_TYPECHANGE,
-    git2::Status::INDEX_RENAMED];
 const STATUS_FOUND : bool = true;
 const STATUS_NOT_FOUND : bool = false;
-const NO_AUTHOR_UPDATE: Option<&git2::Signature> = None;
-const NO_COMMITTER_UPDATE: Option<&git2::Signature> = None;
 const NO_MESSAGE_ENCODING_UPDATE: Option<&str> = None;
+const NO_CHECKOUT_OPTIONS: Option<&mut git2::build::CheckoutBuilder> = None;
+const INVALID_UTF8: &str = "<invalid UTF-8>";
+const DEFAULT_SIGNATURE_FIELD: Option<&str> = None;
+const MAX_COMMITS_SEARCHED_FOR_RECENT_MESSAGES: usize = 1000;
+const SIMILARITY_HEADER_PREFIX: &str = "similarity index ";
 
 
 pub(crate) struct Repository
@@ -36,7 +59,13 @@ pub(crate) struct Repository
     sender: Sender,
     stager: Stager,
     unstager: Unstager,
-    diffContextSize: u32
+    manyStager: ManyStager,
+    manyUnstager: ManyUnstager,
+    hooksUsage: HooksUsage,
+    isCommitHookRunning: bool,
+    authorOptions: CommitAuthorOptions,
+    diffContextSize: u32,
+    showsIgnoredFiles: bool
 }
 
 impl IEventHandler for Repository
@@ -44,13 +73,40 @@ impl IEventHandler for Repository
     fn handle(&mut self, source: Source, event: &Event)
     {
         match event {
-            Event::AmendCommitRequested(message) => self.amendCommit(message),
-            Event::CommitAmendDisabled           => self.disableCommitAmendMode(),
-            Event::CommitAmendEnabled            => self.enableCommitAmendMode(),
-            Event::CommitRequested(message)      => self.commit(message),
-            Event::RefreshRequested              => self.refresh(),
-            Event::StageRequested(fileChange)    => self.stage(fileChange),
-            Event::UnstageRequested(fileChange)  => self.unstage(fileChange),
+            Event::AmendCommitHooksFinished(result)       => self.onAmendCommitHooksFinished(result),
+            Event::AmendCommitRequested(message)          => self.amendCommit(message),
+            Event::AutosquashFinished(error)              => self.onAutosquashFinished(error.as_deref()),
+            Event::AutosquashRequested                    => self.autosquash(),
+            Event::CheckoutCommitRequested(commitId)      => self.checkoutCommit(*commitId),
+            Event::CommitAmendDisabled                    => self.disableCommitAmendMode(),
+            Event::CommitAmendEnabled                     => self.enableCommitAmendMode(),
+            Event::CommitAuthorOptionsChanged(options)    => self.setCommitAuthorOptions(options),
+            Event::CommitHooksFinished(result)            => self.onCommitHooksFinished(result),
+            Event::CommitHooksDisabled                    => self.disableCommitHooks(),
+            Event::CommitHooksEnabled                     => self.enableCommitHooks(),
+            Event::CommitRequested(message)               => self.commit(message),
+            Event::CreateTagRequested(commitId, newTag)   => self.createTag(*commitId, newTag),
+            Event::CreateWorktreeRequested(newWorktree)   => self.createWorktree(newWorktree),
+            Event::DeleteTagRequested(name)               => self.deleteTag(name),
+            Event::EditCommitRequested(commitId, edit)    => self.editCommit(*commitId, edit),
+            Event::IgnoreRequested(pattern)               => self.ignore(pattern),
+            Event::IgnoredFilesDisabled                   => self.setShowsIgnoredFiles(false),
+            Event::IgnoredFilesEnabled                    => self.setShowsIgnoredFiles(true),
+            Event::OpenSubmoduleRequested(path)           => self.openSubmodule(path),
+            Event::PruneWorktreesRequested                => self.pruneWorktrees(),
+            Event::PushTagRequested(name)                 => self.pushTag(name),
+            Event::RefreshRequested                       => self.refresh(),
+            Event::ResetToCommitConfirmed(commitId, mode) => self.resetToCommit(*commitId, *mode),
+            Event::ResetToCommitRequested(commitId, mode) => self.requestResetConfirmation(*commitId, *mode),
+            Event::SetIndexFlagRequested(path, flag, set) => self.changeIndexFlag(path, *flag, *set),
+            Event::StageManyRequested(fileChanges)        => self.stageMany(fileChanges),
+            Event::StagePathsRequested(paths)             => self.stagePaths(paths),
+            Event::StageRequested(fileChange)             => self.stage(fileChange),
+            Event::UnstageManyRequested(fileChanges)      => self.unstageMany(fileChanges),
+            Event::UnstagePathsRequested(paths)           => self.unstagePaths(paths),
+            Event::UnstageRequested(fileChange)           => self.unstage(fileChange),
+            Event::UntrackRequested(path)                 => self.untrack(path),
+            Event::UpdateSubmoduleRequested(path)         => self.updateSubmodule(path),
             _ => handleUnknown(source, event)
         }
     }
@@ -67,7 +123,13 @@ impl Repository
             sender,
             stager: Self::stageNormally,
             unstager: Self::unstageNormally,
-            diffContextSize: config.repository.diffContextSize
+            manyStager: Self::stageManyNormally,
+            manyUnstager: Self::unstageManyNormally,
+            hooksUsage: HooksUsage::All,
+            isCommitHookRunning: false,
+            authorOptions: CommitAuthorOptions::default(),
+            diffContextSize: config.repository.diffContextSize,
+            showsIgnoredFiles: config.fileChangesPane.showsIgnoredFiles
         };
         newSelf.collectCurrentFileChanges();
         newSelf
@@ -87,6 +149,10 @@ impl Repository
                   fileStatusEntry.status(), getFilePath(&fileStatusEntry)));
             }
         }
+        self.addSubmoduleStatuses(&mut unstaged, FileStatusSide::Worktree);
+        self.addSubmoduleStatuses(&mut staged, FileStatusSide::Index);
+        self.addUnstagedDiffStats(&mut unstaged);
+        self.addStagedDiffStats(&mut staged);
         self.fileChanges = GroupedFileChanges{unstaged, staged};
         &self.fileChanges
     }
@@ -126,12 +192,259 @@ impl Repository
         }
     }
 
+    #[must_use]
+    pub fn getHeadState(&self) -> HeadState
+    {
+        match self.gitRepo.head() {
+            Ok(head) => match head.is_branch() {
+                true => HeadState::Branch(head.shorthand().unwrap_or(INVALID_UTF8).into()),
+                false => HeadState::Detached(head.target()
+                    .unwrap_or_else(|| exit("Failed to get the commit pointed to by the detached HEAD.")))
+            },
+            Err(e) if e.class() == git2::ErrorClass::Reference && e.code() == git2::ErrorCode::UnbornBranch =>
+                HeadState::Unborn(self.findUnbornBranchName()),
+            Err(e) => exit(&format!("Failed to get reference to HEAD: {}", e))
+        }
+    }
+
+    #[must_use]
+    pub fn collectRefLabels(&self) -> HashMap<git2::Oid, Vec<RefLabel>>
+    {
+        let headName = self.gitRepo.head().ok().filter(git2::Reference::is_branch)
+            .and_then(|head| head.name().map(str::to_owned));
+        let otherWorktreeBranches = self.collectWorktrees().into_iter()
+            .filter(|worktree| !worktree.isCurrent)
+            .filter_map(|worktree| worktree.branch)
+            .collect::<HashSet<_>>();
+        let mut refLabels: HashMap<git2::Oid, Vec<RefLabel>> = HashMap::new();
+        let references = self.gitRepo.references()
+            .unwrap_or_else(|e| exit(&format!("Failed to list references: {}", e)));
+        for reference in references.flatten() {
+            if reference.kind() != Some(git2::ReferenceType::Direct) {
+                continue;
+            }
+            let mut kind = match getRefKind(&reference, headName.as_deref()) {
+                Some(kind) => kind,
+                None => continue
+            };
+            if let Ok(commit) = reference.peel_to_commit() {
+                let name: String = reference.shorthand().unwrap_or(INVALID_UTF8).into();
+                if kind == RefKind::LocalBranch && otherWorktreeBranches.contains(&name) {
+                    kind = RefKind::OtherWorktreeBranch;
+                }
+                refLabels.entry(commit.id()).or_default().push(RefLabel{name, kind});
+            }
+        }
+
+        if let HeadState::Detached(commitId) = self.getHeadState() {
+            refLabels.entry(commitId).or_default().insert(0, RefLabel{name: "HEAD".into(), kind: RefKind::Head});
+        }
+        refLabels
+    }
+
+    #[must_use]
+    pub fn collectWorktrees(&self) -> Vec<Worktree>
+    {
+        match collectWorktrees(&self.gitRepo) {
+            Ok(worktrees) => worktrees,
+            Err(e) => {
+                eprintln!("Failed to list worktrees: {}", e);
+                vec![]
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn collectLocalBranchNames(&self) -> Vec<String>
+    {
+        let branches = match self.gitRepo.branches(Some(git2::BranchType::Local)) {
+            Ok(branches) => branches,
+            Err(e) => {
+                eprintln!("Failed to list local branches: {}", e);
+                return vec![];
+            }
+        };
+        branches.flatten()
+            .filter_map(|(branch, _branchType)| branch.name().ok().flatten().map(str::to_owned))
+            .sorted()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn getWorkingDir(&self) -> &Path
+    {
+        self.gitRepo.workdir().unwrap_or_else(|| self.gitRepo.path())
+    }
+
+    // Only finds out whether and how the commit is signed, which is cheap enough to do for every commit in the log.
+    #[must_use]
+    pub fn getSignatureFormat(&self, commitId: git2::Oid) -> Option<SignatureFormat>
+    {
+        let (signature, _signedData) = self.gitRepo.extract_signature(&commitId, DEFAULT_SIGNATURE_FIELD).ok()?;
+        SignatureFormat::fromSignature(&signature)
+    }
+
+    // Running the verification can take a while, e.g. when a keyring has to be opened, so it is done
+    // in a worker thread, which reports the result with SignatureVerified.
+    pub fn verifySignature(&self, commitId: git2::Oid)
+    {
+        let format = match self.getSignatureFormat(commitId) {
+            Some(format) => format,
+            None => return
+        };
+
+        let (resultSender, resultReceiver) = makeChannel();
+        let workingDir = self.getWorkingDir().to_owned();
+        std::thread::spawn(move || {
+            resultSender.send(runGitCommand(&workingDir, &["verify-commit", &commitId.to_string()])).unwrap();
+        });
+
+        let sender = self.sender.clone();
+        attach(resultReceiver, move |result: Result<String, String>| {
+            let failure = result.err().map(|e| e.lines().next().unwrap_or_default().to_owned());
+            let verification = SignatureVerification{format, failure};
+            sender.send((Source::Repository, Event::SignatureVerified(commitId, verification))).unwrap();
+            glib::ControlFlow::Break
+        });
+    }
+
+    #[must_use]
+    pub fn loadCommitMessageTemplate(&self) -> Option<String>
+    {
+        loadCommitMessageTemplate(&self.gitRepo)
+    }
+
+    #[must_use]
+    pub fn getGitDir(&self) -> &Path
+    {
+        self.gitRepo.path()
+    }
+
+    #[must_use]
+    pub fn getUserIdentity(&self) -> Option<String>
+    {
+        let signature = self.gitRepo.signature().ok()?;
+        Some(formatIdentity(&signature))
+    }
+
+    #[must_use]
+    pub fn collectAuthorIdentities(&self) -> Vec<String>
+    {
+        let mut identities = vec![];
+        if self.isEmpty() {
+            return identities;
+        }
+        if let Err(e) = self.iterateCommits(|commit| identities.push(formatIdentity(&commit.author()))) {
+            eprintln!("Failed to collect author identities: {}", e);
+        }
+        identities.into_iter().unique().collect()
+    }
+
+    #[must_use]
+    pub fn getCommitSummary(&self, id: git2::Oid) -> Option<String>
+    {
+        self.findCommit(id).ok().map(|commit| getSummary(&commit))
+    }
+
+    #[must_use]
+    pub fn getCommitMessage(&self, id: git2::Oid) -> Option<String>
+    {
+        self.findCommit(id).ok().map(|commit| String::from_utf8_lossy(commit.message_bytes()).into())
+    }
+
+    pub fn collectCommitHunks(&self, id: git2::Oid) -> Result<Vec<CommitHunk>, String>
+    {
+        let commit = self.findCommit(id).map_err(|e| format!("Failed to find commit {}: {}", id, e))?;
+        collectCommitHunks(&self.gitRepo, &commit)
+    }
+
+    #[must_use]
+    pub fn isSubmodule(&self, path: &str) -> bool
+    {
+        self.gitRepo.find_submodule(path).is_ok()
+    }
+
+    // Files with conflicts have no entry in stage 0, so they get no flags.
+    #[must_use]
+    pub fn getIndexFlags(&self, path: &str) -> Option<IndexFlags>
+    {
+        let index = self.gitRepo.index().ok()?;
+        index.get_path(Path::new(path), 0).map(|entry| IndexFlags::from(&entry))
+    }
+
+    // The index file is written anew on every change, so its modification time tells whether it has changed.
+    #[must_use]
+    pub fn getIndexModificationTime(&self) -> Option<SystemTime>
+    {
+        std::fs::metadata(self.gitRepo.path().join("index")).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    #[must_use]
+    pub fn collectFlaggedFiles(&self) -> Vec<FlaggedFile>
+    {
+        let index = match self.gitRepo.index() {
+            Ok(index) => index,
+            Err(e) => {
+                eprintln!("Failed to collect files with index flags, because index could not be acquired: {}", e);
+                return vec![];
+            }
+        };
+        index.iter()
+            .filter_map(|entry| {
+                let flags = IndexFlags::from(&entry);
+                flags.isAnySet().then(|| FlaggedFile{path: String::from_utf8_lossy(&entry.path).into(), flags})
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn findSubmoduleChange(&self, path: &str, kind: SubmoduleChangeKind) -> Option<SubmoduleChange>
+    {
+        findSubmoduleChange(&self.gitRepo, path, kind)
+    }
+
+    // Messages of the user's own commits reachable from HEAD, the most recent first.
+    #[must_use]
+    pub fn collectRecentUserCommitMessages(&self, maxCount: usize) -> Vec<String>
+    {
+        if self.isEmpty() {
+            return vec![];
+        }
+        self.tryCollectRecentUserCommitMessages(maxCount).unwrap_or_else(|e| {
+            eprintln!("Failed to collect recent commit messages: {}", e);
+            vec![]
+        })
+    }
+
     #[must_use]
     pub fn getLastCommitMessage(&self) -> Option<String>
     {
         self.findHeadCommit().map(|commit| String::from_utf8_lossy(commit.message_bytes()).into())
     }
 
+    fn tryCollectRecentUserCommitMessages(&self, maxCount: usize) -> Result<Vec<String>, git2::Error>
+    {
+        let userEmail = self.gitRepo.signature()?.email().map(str::to_owned);
+        let mut revwalk = self.gitRepo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+        let mut messages = Vec::<String>::new();
+        for oid in revwalk.take(MAX_COMMITS_SEARCHED_FOR_RECENT_MESSAGES) {
+            let commit = self.gitRepo.find_commit(oid?)?;
+            if commit.author().email() != userEmail.as_deref() {
+                continue;
+            }
+            let message = String::from_utf8_lossy(commit.message_bytes()).trim_end().to_owned();
+            if !messages.contains(&message) {
+                messages.push(message);
+            }
+            if messages.len() == maxCount {
+                break;
+            }
+        }
+        Ok(messages)
+    }
+
     fn collectLastCommitChanges(&self) -> Vec<FileChange>
     {
         let mut fileChanges = vec![];
@@ -140,6 +453,7 @@ impl Repository
             for delta in amendDiff.deltas() {
                 fileChanges.push(FileChange::from(&delta));
             }
+            addDiffStats(&amendDiff, &mut fileChanges);
         }
         fileChanges
     }
@@ -153,6 +467,36 @@ impl Repository
                 &format!("Failed to get index-to-workdir diff for path {}: {}", path, e)))
     }
 
+    // None when the file does not exist in the given source, e.g. it was added or deleted.
+    #[must_use]
+    pub fn loadFileContent(&self, path: &str, source: BlobSource, maxSize: usize) -> Option<FileContent>
+    {
+        let tree = match source {
+            BlobSource::Head => self.findCurrentTree(),
+            BlobSource::ParentOfHead => self.findTreeOfParentOfHeadCommit(),
+            BlobSource::Index => {
+                let index = self.gitRepo.index().ok()?;
+                let entry = index.get_path(Path::new(path), 0)?;
+                return self.loadBlobContent(entry.id, maxSize);
+            },
+            BlobSource::Workdir => return loadWorkdirFileContent(&self.getWorkingDir().join(path), maxSize)
+        };
+        let entry = tree?.get_path(Path::new(path)).ok()?;
+        self.loadBlobContent(entry.id(), maxSize)
+    }
+
+    // The size is read from the object header, so that blobs larger than the maximum size are not decompressed.
+    #[must_use]
+    pub fn loadBlobContent(&self, id: git2::Oid, maxSize: usize) -> Option<FileContent>
+    {
+        let (size, _kind) = self.gitRepo.odb().ok()?.read_header(id).ok()?;
+        if size > maxSize {
+            return Some(FileContent{size, data: None});
+        }
+        let blob = self.gitRepo.find_blob(id).ok()?;
+        Some(FileContent{size, data: Some(blob.content().to_owned())})
+    }
+
     #[must_use]
     pub fn makeDiffOfTreeToIndex(&self, path: &str) -> git2::Diff
     {
@@ -171,7 +515,7 @@ impl Repository
             .unwrap_or_else(|e| exit(
                 &format!("Failed to get index-to-workdir diff for path {}: {}", oldPath, e)));
         let mut diffFindOptions = git2::DiffFindOptions::new();
-        diffFindOptions.for_untracked(true);
+        diffFindOptions.renames(true).for_untracked(true);
         diff.find_similar(Some(&mut diffFindOptions)).unwrap();
         diff
     }
@@ -190,6 +534,29 @@ impl Repository
         diff
     }
 
+    // File headers are included, so the text contains the paths of staged files too.
+    #[must_use]
+    pub fn collectStagedDiffText(&self) -> String
+    {
+        let tree = self.findCurrentTree();
+        let diff = match self.gitRepo.diff_tree_to_index(tree.as_ref(), CURRENT_INDEX, Some(&mut self.makeDiffOptions())) {
+            Ok(diff) => diff,
+            Err(e) => {
+                eprintln!("Failed to get tree-to-index diff: {}", e);
+                return "".into();
+            }
+        };
+        let mut text = String::new();
+        let result = diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
+            text.push_str(&String::from_utf8_lossy(line.content()));
+            true
+        });
+        if let Err(e) = result {
+            eprintln!("Failed to print tree-to-index diff: {}", e);
+        }
+        text
+    }
+
     #[must_use]
     pub fn makeDiffToAmend(&self, commit: &git2::Commit) -> git2::Diff
     {
@@ -220,33 +587,47 @@ impl Repository
         (self.unstager)(self, fileChange);
     }
 
-    pub fn commit(&mut self, message: &str)
+    // Many file changes are staged or unstaged with one index write and reported with one refresh.
+    pub fn stageMany(&mut self, fileChanges: &[FileChange])
     {
-        {
-            let author = self.gitRepo.signature()
-                .unwrap_or_else(|e| exit(&format!("Failed to get a name and/or email of the commit author: {}", e)));
-            let commiter = &author;
-            let tree = self.storeIndexAsTree();
-            let parentCommits = self.findParentCommits();
-            let parentCommits = parentCommits.iter().collect_vec();
+        (self.manyStager)(self, fileChanges);
+    }
+
+    pub fn unstageMany(&mut self, fileChanges: &[FileChange])
+    {
+        (self.manyUnstager)(self, fileChanges);
+    }
 
-            self.gitRepo.commit(Some("HEAD"), &author, commiter, message, &tree, &parentCommits)
-                .unwrap_or_else(|e| exit(&format!("Failed to commit changes: {}", e)));
+    // A path of a directory stages all changes under it and an empty path stands for the whole working directory.
+    // Ignored files are staged only by their own paths, so that dropping a directory does not force-add them.
+    pub fn stagePaths(&mut self, paths: &[FilePathString])
+    {
+        let fileChanges = collectFileChangesUnderPaths(&self.fileChanges.unstaged, paths);
+        match fileChanges.as_slice() {
+            [] => (),
+            [fileChange] => self.stage(fileChange),
+            _ => self.stageMany(&fileChanges)
         }
+    }
 
-        self.collectCurrentFileChanges();
-        self.notifyOnCommitted();
+    pub fn unstagePaths(&mut self, paths: &[FilePathString])
+    {
+        let fileChanges = collectFileChangesUnderPaths(&self.fileChanges.staged, paths);
+        match fileChanges.as_slice() {
+            [] => (),
+            [fileChange] => self.unstage(fileChange),
+            _ => self.unstageMany(&fileChanges)
+        }
+    }
+
+    pub fn commit(&mut self, message: &str)
+    {
+        self.runBeforeCommitHooks(message, CommitMessageSource::Message, Event::CommitHooksFinished);
     }
 
     pub fn amendCommit(&mut self, newMessage: &str)
     {
-        match self.tryAmendCommit(newMessage) {
-            Ok(_) => {
-                self.collectCurrentFileChanges();
-                self.notifyOnAmendedCommit();
-            },
-            Err(e) => showErrorDialog(e)
-        }
+        self.runBeforeCommitHooks(newMessage, CommitMessageSource::AmendedCommit, Event::AmendCommitHooksFinished);
     }
 
     pub fn refresh(&mut self)
@@ -255,16 +636,159 @@ impl Repository
         self.notifyOnRefreshed();
     }
 
-    pub fn iterateCommits(&self, mut handler: impl FnMut(&git2::Commit))
+    pub fn ignore(&mut self, pattern: &IgnorePattern)
+    {
+        let ignoreFilePath = match pattern.file {
+            IgnoreFile::Gitignore => self.getWorkingDir().join(".gitignore"),
+            IgnoreFile::InfoExclude => findCommonGitDir(&self.gitRepo).join("info").join("exclude")
+        };
+        match appendIgnorePattern(&ignoreFilePath, &pattern.pattern) {
+            Ok(()) => self.refresh(),
+            Err(e) => showErrorDialog(&format!(
+                "Failed to add pattern {} to {}: {}", pattern.pattern, ignoreFilePath.display(), e))
+        }
+    }
+
+    // Like "git rm --cached", the file is kept in the working directory and becomes untracked.
+    pub fn untrack(&mut self, path: &str)
+    {
+        let result = self.gitRepo.index().and_then(|mut index| {
+            index.remove_path(Path::new(path))?;
+            index.write()
+        });
+        match result {
+            Ok(()) => self.refresh(),
+            Err(e) => showErrorDialog(&format!("Failed to stop tracking file {}: {}", path, e))
+        }
+    }
+
+    pub fn changeIndexFlag(&mut self, path: &str, flag: IndexFlag, isEnabled: bool)
+    {
+        let result = self.gitRepo.index().and_then(|mut index| {
+            let mut entry = index.get_path(Path::new(path), 0)
+                .ok_or_else(|| git2::Error::from_str("the file is not in the index"))?;
+            setIndexFlag(&mut entry, flag, isEnabled);
+            index.add(&entry)?;
+            index.write()
+        });
+        match result {
+            Ok(()) => self.refresh(),
+            Err(e) => showErrorDialog(&format!(
+                "Failed to {} {} for file {}: {}", if isEnabled { "set" } else { "unset" }, flag.getName(), path, e))
+        }
+    }
+
+    // The losses are described for the commit log view, which asks for confirmation before the reset is done.
+    pub fn requestResetConfirmation(&self, commitId: git2::Oid, mode: ResetMode)
+    {
+        match self.describeLossesOfReset(commitId, mode) {
+            Ok(losses) => self.sender.send(
+                (Source::Repository, Event::ResetConfirmationNeeded(commitId, mode, losses))).unwrap(),
+            Err(e) => showErrorDialog(&format!("Failed to reset to commit {}: {}", commitId, e))
+        }
+    }
+
+    pub fn resetToCommit(&mut self, commitId: git2::Oid, mode: ResetMode)
+    {
+        let result = self.findCommit(commitId)
+            .and_then(|commit| self.gitRepo.reset(commit.as_object(), mode.into(), NO_CHECKOUT_OPTIONS));
+        match result {
+            Ok(()) => self.onHeadMoved(),
+            Err(e) => showErrorDialog(&format!("Failed to reset to commit {}: {}", commitId, e))
+        }
+    }
+
+    pub fn checkoutCommit(&mut self, commitId: git2::Oid)
     {
-        let mut revwalk = self.gitRepo.revwalk().unwrap();
-        revwalk.push_head().unwrap();
-        revwalk.simplify_first_parent().unwrap();
-        revwalk.set_sorting(git2::Sort::TOPOLOGICAL).unwrap();
+        let result = self.findCommit(commitId).and_then(|commit| {
+            self.gitRepo.checkout_tree(commit.as_object(), Some(git2::build::CheckoutBuilder::new().safe()))?;
+            self.gitRepo.set_head_detached(commitId)
+        });
+
+        match result {
+            Ok(()) => self.onHeadMoved(),
+            Err(e) => showErrorDialog(&format!("Failed to checkout commit {}: {}", commitId, e))
+        }
+    }
+
+    pub fn autosquash(&mut self)
+    {
+        let (base, rewrittenCount) = match self.findAutosquashBase() {
+            Ok(found) => found,
+            Err(e) => {
+                showErrorDialog(&e);
+                return;
+            }
+        };
+        let (baseDescription, baseArgument) = match base {
+            AutosquashBase::Commit(id) => (format!("commit {}", shortenCommitId(id)), id.to_string()),
+            AutosquashBase::Root => ("the root commit".to_owned(), "--root".to_owned())
+        };
+        let question = "Autosquash fixup and squash commits?";
+        let details = format!("{} {} since {} will be rewritten.",
+                              rewrittenCount, getCommitWord(rewrittenCount), baseDescription);
+        if !askForConfirmation(question, &details) {
+            return;
+        }
+
+        let (resultSender, resultReceiver) = makeChannel();
+        let workingDir = self.getWorkingDir().to_owned();
+        std::thread::spawn(move || {
+            resultSender.send(runAutosquash(&workingDir, &baseArgument)).unwrap();
+        });
+
+        let sender = self.sender.clone();
+        attach(resultReceiver, move |result: Result<(), String>| {
+            sender.send((Source::Repository, Event::AutosquashFinished(result.err()))).unwrap();
+            glib::ControlFlow::Break
+        });
+    }
+
+    pub fn editCommit(&mut self, commitId: git2::Oid, edit: &HistoryEdit)
+    {
+        let signer = match CommitSigner::fromRepository(&self.gitRepo) {
+            Ok(signer) => signer,
+            Err(e) => {
+                showErrorDialog(&format!("Failed to edit commit {}:\n{}", shortenCommitId(commitId), e));
+                return;
+            }
+        };
+        if !self.confirmEditingCommit(commitId, edit, signer.is_some()) {
+            return;
+        }
+        if matches!(edit, HistoryEdit::Drop) && self.hasLocalChanges() {
+            showErrorDialog("Commit or discard the local changes before dropping a commit.");
+            return;
+        }
+
+        let result = rewriteHistory(&self.gitRepo, commitId, edit, signer.as_ref()).and_then(|newHeadId| {
+            let reflogMessage = format!("{}: {}", describeHistoryEdit(edit), shortenCommitId(commitId));
+            self.moveHeadTo(newHeadId, &reflogMessage)?;
+            // Only dropping changes the content of the following commits.
+            if matches!(edit, HistoryEdit::Drop) {
+                let newHead = self.gitRepo.find_object(newHeadId, None).unwrap();
+                self.gitRepo.reset(&newHead, git2::ResetType::Hard, NO_CHECKOUT_OPTIONS)
+                    .map_err(|e| format!("Failed to update the working directory: {}", e))?;
+            }
+            Ok(())
+        });
+        match result {
+            Ok(()) => self.onHeadMoved(),
+            Err(e) => showErrorDialog(&format!("Failed to edit commit {}:\n{}", shortenCommitId(commitId), e))
+        }
+    }
+
+    pub fn iterateCommits(&self, mut handler: impl FnMut(&git2::Commit)) -> Result<(), git2::Error>
+    {
+        let mut revwalk = self.gitRepo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.simplify_first_parent()?;
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL)?;
         for oid in revwalk {
-            let commit = self.gitRepo.find_commit(oid.unwrap()).unwrap();
+            let commit = self.gitRepo.find_commit(oid?)?;
             handler(&commit);
         }
+        Ok(())
     }
 
     pub fn findCommit(&self, id: git2::Oid) -> Result<git2::Commit, git2::Error>
@@ -288,6 +812,8 @@ impl Repository
     {
         self.stager = Self::stageToAmend;
         self.unstager = Self::unstageToAmend;
+        self.manyStager = Self::stageManyToAmend;
+        self.manyUnstager = Self::unstageManyToAmend;
         self.collectCurrentFileChangesToAmend();
         self.notifyOnRefreshed();
     }
@@ -296,18 +822,21 @@ impl Repository
     {
         self.stager = Self::stageNormally;
         self.unstager = Self::unstageNormally;
+        self.manyStager = Self::stageManyNormally;
+        self.manyUnstager = Self::unstageManyNormally;
         self.collectCurrentFileChanges();
         self.notifyOnRefreshed();
     }
 
     pub fn stageNormally(&mut self, fileChange: &FileChange)
     {
-        match fileChange.status.as_str() {
-            "Status(WT_DELETED)" => self.removePathFromIndex(&fileChange.path),
-            "Status(WT_RENAMED)" => {
+        match fileChange.status.kind {
+            FileStatusKind::Deleted => self.removePathFromIndex(&fileChange.path),
+            FileStatusKind::Renamed => {
                 self.addPathToIndex(&fileChange.path);
                 self.removePathFromIndex(fileChange.oldPath.as_ref().unwrap());
             }
+            FileStatusKind::Ignored => self.updateIndexToStage(std::slice::from_ref(fileChange)),
             _ => self.addPathToIndex(&fileChange.path)
         }
 
@@ -328,8 +857,8 @@ impl Repository
 
     pub fn stageToAmend(&mut self, fileChange: &FileChange)
     {
-        match fileChange.status.as_str() {
-            "Status(WT_DELETED)" => self.removePathFromIndex(&fileChange.path),
+        match fileChange.status.kind {
+            FileStatusKind::Deleted => self.removePathFromIndex(&fileChange.path),
             _ => self.addPathToIndex(&fileChange.path)
         }
 
@@ -398,18 +927,509 @@ impl Repository
         }
     }
 
+    pub fn stageManyNormally(&mut self, fileChanges: &[FileChange])
+    {
+        self.updateIndexToStage(fileChanges);
+        self.collectCurrentFileChanges();
+        self.notifyOnRefreshed();
+    }
+
+    fn stageManyToAmend(&mut self, fileChanges: &[FileChange])
+    {
+        self.updateIndexToStage(fileChanges);
+        self.collectCurrentFileChangesToAmend();
+        self.notifyOnRefreshed();
+    }
+
+    pub fn unstageManyNormally(&mut self, fileChanges: &[FileChange])
+    {
+        {
+            let commitObject = self.findHeadCommit().map(git2::Commit::into_object);
+            self.resetPathsInIndex(commitObject.as_ref(), fileChanges);
+        }
+        self.collectCurrentFileChanges();
+        self.notifyOnRefreshed();
+    }
+
+    fn unstageManyToAmend(&mut self, fileChanges: &[FileChange])
+    {
+        {
+            let parentObject = self.findParentOfHeadCommit().map(git2::Commit::into_object);
+            self.resetPathsInIndex(parentObject.as_ref(), fileChanges);
+        }
+        self.collectCurrentFileChangesToAmend();
+        self.notifyOnRefreshed();
+    }
+
+    pub fn createTag(&mut self, commitId: git2::Oid, newTag: &NewTag)
+    {
+        let result = self.findCommit(commitId).and_then(|commit| match &newTag.message {
+            Some(message) => {
+                let tagger = self.gitRepo.signature()?;
+                self.gitRepo.tag(&newTag.name, commit.as_object(), &tagger, message, false)
+            },
+            None => self.gitRepo.tag_lightweight(&newTag.name, commit.as_object(), false)
+        });
+
+        match result {
+            Ok(_) => self.notifyOnReferencesChanged(),
+            Err(e) => showErrorDialog(&format!("Failed to create tag {}: {}", newTag.name, e))
+        }
+    }
+
+    pub fn deleteTag(&mut self, name: &str)
+    {
+        let question = format!("Delete tag {}?", name);
+        if !askForConfirmation(&question, "The tag will be deleted only in the local repository.") {
+            return;
+        }
+
+        match self.gitRepo.tag_delete(name) {
+            Ok(()) => self.notifyOnReferencesChanged(),
+            Err(e) => showErrorDialog(&format!("Failed to delete tag {}: {}", name, e))
+        }
+    }
+
+    // Pushing waits for the network, so git runs in another thread, which can report back only through a channel.
+    pub fn pushTag(&self, name: &str)
+    {
+        let remote = match self.findRemoteToPushTo() {
+            Some(remote) => remote,
+            None => {
+                showErrorDialog(&format!("Failed to push tag {}: no remote repository is configured.", name));
+                return;
+            }
+        };
+
+        let (resultSender, resultReceiver) = makeChannel();
+        let workingDir = self.getWorkingDir().to_owned();
+        let refSpec = format!("refs/tags/{}", name);
+        let pushedRemote = remote.clone();
+        std::thread::spawn(move || {
+            resultSender.send(runGitCommand(&workingDir, &["push", &pushedRemote, &refSpec])).unwrap();
+        });
+
+        let sender = self.sender.clone();
+        let name = name.to_owned();
+        attach(resultReceiver, move |result: Result<String, String>| {
+            if let Err(e) = result {
+                let message = format!("Failed to push tag {} to {}:\n{}", name, remote, e);
+                sender.send((Source::Repository, Event::TagPushFailed(message))).unwrap();
+            }
+            glib::ControlFlow::Break
+        });
+    }
+
+    pub fn updateSubmodule(&mut self, path: &str)
+    {
+        if let Err(e) = runGitCommand(self.getWorkingDir(), &["submodule", "update", "--init", "--", path]) {
+            showErrorDialog(&format!("Failed to initialize and update submodule {}:\n{}", path, e));
+        }
+        self.refresh();
+    }
+
+    pub fn openSubmodule(&self, path: &str)
+    {
+        if let Err(e) = launchApp(&self.getWorkingDir().join(path)) {
+            showErrorDialog(&format!("Failed to open submodule {}: {}", path, e));
+        }
+    }
+
+    pub fn createWorktree(&mut self, newWorktree: &NewWorktree)
+    {
+        let path = newWorktree.path.to_string_lossy();
+        let branch = newWorktree.branch.as_str();
+        let args = match self.gitRepo.find_branch(branch, git2::BranchType::Local) {
+            Ok(_) => vec!["worktree", "add", &path, branch],
+            Err(_) => vec!["worktree", "add", "-b", branch, &path]
+        };
+        match runGitCommand(self.getWorkingDir(), &args) {
+            Ok(_) => self.notifyOnReferencesChanged(),
+            Err(e) => showErrorDialog(&format!("Failed to create worktree {} for branch {}:\n{}", path, branch, e))
+        }
+    }
+
+    pub fn pruneWorktrees(&mut self)
+    {
+        let stalePaths = self.collectWorktrees().into_iter()
+            .filter(|worktree| worktree.isPrunable)
+            .map(|worktree| worktree.path.display().to_string())
+            .collect_vec();
+        if stalePaths.is_empty() {
+            showInfoDialog("There are no stale worktrees to prune.");
+            return;
+        }
+        let details = format!("Administrative files of these missing worktrees will be removed:\n{}", stalePaths.join("\n"));
+        if !askForConfirmation("Prune stale worktrees?", &details) {
+            return;
+        }
+
+        match runGitCommand(self.getWorkingDir(), &["worktree", "prune"]) {
+            Ok(_) => self.notifyOnReferencesChanged(),
+            Err(e) => showErrorDialog(&format!("Failed to prune worktrees:\n{}", e))
+        }
+    }
+
+    fn setCommitAuthorOptions(&mut self, options: &CommitAuthorOptions)
+    {
+        self.authorOptions = options.clone();
+    }
+
+    // The options apply to a single commit, so that the next ones are not attributed to someone else by accident.
+    fn resetCommitAuthorOptions(&mut self)
+    {
+        self.authorOptions = CommitAuthorOptions::default();
+    }
+
+    fn enableCommitHooks(&mut self)
+    {
+        self.hooksUsage = HooksUsage::All;
+    }
+
+    fn disableCommitHooks(&mut self)
+    {
+        self.hooksUsage = HooksUsage::SkipVerification;
+    }
+
+    fn setShowsIgnoredFiles(&mut self, showsIgnoredFiles: bool)
+    {
+        self.showsIgnoredFiles = showsIgnoredFiles;
+        self.refresh();
+    }
+
+    fn onCommitFailed(&self, error: &str)
+    {
+        showErrorDialog(error);
+        self.notifyOnCommitFailed();
+    }
+
+    fn confirmEditingCommit(&self, commitId: git2::Oid, edit: &HistoryEdit, willSign: bool) -> bool
+    {
+        let remoteBranches = self.findRemoteBranchesContaining(commitId);
+        let lostSignatureCount = match willSign {
+            true => 0,
+            false => countSignedCommitsToRewrite(&self.gitRepo, commitId).unwrap_or_default()
+        };
+        if remoteBranches.is_empty() && lostSignatureCount == 0 && !matches!(edit, HistoryEdit::Drop) {
+            return true;
+        }
+
+        let question = format!("{} commit {}?", describeHistoryEdit(edit), shortenCommitId(commitId));
+        let mut details = vec![];
+        if matches!(edit, HistoryEdit::Drop) {
+            details.push(format!("The changes of \"{}\" will be removed from the current branch.",
+                                 self.getCommitSummary(commitId).unwrap_or_default()));
+        }
+        if !remoteBranches.is_empty() {
+            details.push(format!("The commit is already on remote-tracking branches: {}. \
+                                  Publishing the rewritten history will require a force push.",
+                                 remoteBranches.join(", ")));
+        }
+        if lostSignatureCount > 0 {
+            details.push(format!("Signatures of {} rewritten {} will be lost, because commit.gpgsign is not enabled.",
+                                 lostSignatureCount, getCommitWord(lostSignatureCount)));
+        }
+        askForConfirmation(&question, &details.join("\n\n"))
+    }
+
+    fn findRemoteBranchesContaining(&self, commitId: git2::Oid) -> Vec<String>
+    {
+        let branches = match self.gitRepo.branches(Some(git2::BranchType::Remote)) {
+            Ok(branches) => branches,
+            Err(_) => return vec![]
+        };
+        branches.filter_map(Result::ok)
+            .filter_map(|(branch, _branchType)| {
+                let target = branch.get().target()?;
+                let containsCommit = target == commitId
+                    || self.gitRepo.graph_descendant_of(target, commitId).unwrap_or(false);
+                containsCommit.then(|| branch.name().ok().flatten().map(String::from)).flatten()
+            })
+            .collect()
+    }
+
+    fn hasLocalChanges(&self) -> bool
+    {
+        let mut statusOptions = git2::StatusOptions::new();
+        statusOptions.include_untracked(false).include_ignored(false);
+        self.gitRepo.statuses(Some(&mut statusOptions)).map_or(true, |statuses| !statuses.is_empty())
+    }
+
+    // The base is the parent of the oldest commit targeted by a fixup or squash commit. The whole branch is walked,
+    // because fixup commits of older targets can be interleaved with targets which were already found.
+    fn findAutosquashBase(&self) -> Result<(AutosquashBase, usize), String>
+    {
+        let mut pendingTargets = HashSet::new();
+        let mut oldestTarget = None;
+        let mut walkedCount = 0;
+        self.iterateCommits(|commit| {
+            walkedCount += 1;
+            let summary = getSummary(commit);
+            if let Some(target) = findFixupTarget(&summary) {
+                pendingTargets.insert(target.to_owned());
+                return;
+            }
+
+            let pendingCount = pendingTargets.len();
+            pendingTargets.retain(|target| !isFixupTarget(target, &summary, commit.id()));
+            if pendingTargets.len() < pendingCount {
+                oldestTarget = Some((commit.parent_id(0).ok(), walkedCount));
+            }
+        }).map_err(|e| format!("Failed to walk the commits of the current branch: {}", e))?;
+
+        if let Some(target) = pendingTargets.iter().min() {
+            return Err(format!(
+                r#"Failed to autosquash commits, no commit matching "{}" was found on the current branch."#, target));
+        }
+        match oldestTarget {
+            Some((Some(parentId), rewrittenCount)) => Ok((AutosquashBase::Commit(parentId), rewrittenCount)),
+            Some((None, rewrittenCount)) => Ok((AutosquashBase::Root, rewrittenCount)),
+            None => Err("There are no fixup or squash commits on the current branch.".into())
+        }
+    }
+
+    // Hooks like linters or test suites can take long, so they run in another thread, which reports back through
+    // a channel. Without hooks to run, the commit is made without waiting for one.
+    fn runBeforeCommitHooks(
+        &mut self,
+        message: &str,
+        messageSource: CommitMessageSource,
+        makeEvent: fn(Result<CommitMessage, String>) -> Event)
+    {
+        if self.isCommitHookRunning {
+            return;
+        }
+
+        let hooks = Hooks::new(&self.gitRepo);
+        let usage = self.hooksUsage;
+        if !hooks.hasBeforeCommitHooks(usage) {
+            let result = hooks.runBeforeCommit(message, messageSource, usage);
+            self.sender.send((Source::Repository, makeEvent(result))).unwrap();
+            return;
+        }
+
+        self.isCommitHookRunning = true;
+        let (resultSender, resultReceiver) = makeChannel();
+        let message = message.to_owned();
+        std::thread::spawn(move || {
+            resultSender.send(hooks.runBeforeCommit(&message, messageSource, usage)).unwrap();
+        });
+
+        let sender = self.sender.clone();
+        attach(resultReceiver, move |result: Result<CommitMessage, String>| {
+            sender.send((Source::Repository, makeEvent(result))).unwrap();
+            glib::ControlFlow::Break
+        });
+    }
+
+    fn onCommitHooksFinished(&mut self, result: &Result<CommitMessage, String>)
+    {
+        self.isCommitHookRunning = false;
+        let message = match result {
+            Ok(message) => message,
+            Err(e) => {
+                self.onCommitFailed(e);
+                return;
+            }
+        };
+
+        if let Err(e) = self.createCommit(message) {
+            self.onCommitFailed(&e);
+            return;
+        }
+
+        self.runAfterCommitHooks();
+        self.resetCommitAuthorOptions();
+        self.collectCurrentFileChanges();
+        self.notifyOnCommitted();
+    }
+
+    fn onAmendCommitHooksFinished(&mut self, result: &Result<CommitMessage, String>)
+    {
+        self.isCommitHookRunning = false;
+        let newMessage = match result {
+            Ok(message) => message,
+            Err(e) => {
+                self.onCommitFailed(e);
+                return;
+            }
+        };
+
+        match self.tryAmendCommit(newMessage) {
+            Ok(_) => {
+                self.runAfterCommitHooks();
+                self.resetCommitAuthorOptions();
+                self.collectCurrentFileChanges();
+                self.notifyOnAmendedCommit();
+            },
+            Err(e) => self.onCommitFailed(&e)
+        }
+    }
+
+    // The commit is already made, so nothing waits for the post-commit hook.
+    fn runAfterCommitHooks(&self)
+    {
+        let hooks = Hooks::new(&self.gitRepo);
+        std::thread::spawn(move || hooks.runAfterCommit());
+    }
+
+    fn onAutosquashFinished(&mut self, error: Option<&str>)
+    {
+        if let Some(error) = error {
+            showErrorDialog(&format!("Failed to autosquash commits, the rebase was aborted:\n{}", error));
+        }
+        self.onHeadMoved();
+    }
+
+    fn onHeadMoved(&mut self)
+    {
+        self.stager = Self::stageNormally;
+        self.unstager = Self::unstageNormally;
+        self.manyStager = Self::stageManyNormally;
+        self.manyUnstager = Self::unstageManyNormally;
+        self.collectCurrentFileChanges();
+        self.notifyOnHeadChanged();
+    }
+
+    fn describeLossesOfReset(&self, commitId: git2::Oid, mode: ResetMode) -> Result<String, git2::Error>
+    {
+        let removedCommits = self.collectCommitsUnreachableFrom(commitId)?;
+        let commitsDescription = match removedCommits.len() {
+            0 => "No commits will be removed from the current branch.".to_owned(),
+            count => format!("{} {} will be removed from the current branch:\n{}",
+                             count, getCommitWord(count), removedCommits.join("\n"))
+        };
+
+        let changesDescription = match mode {
+            ResetMode::Soft =>
+                "Changes from removed commits will be staged. Current staged and unstaged changes will be kept."
+                    .to_owned(),
+            ResetMode::Mixed =>
+                "Changes from removed commits and current staged changes will become unstaged.".to_owned(),
+            ResetMode::Hard => format!(
+                "Changes from removed commits will be lost, as well as current changes in tracked files: \
+                 {} staged and {} unstaged. Untracked files will be kept.",
+                self.fileChanges.staged.len(),
+                self.fileChanges.unstaged.iter().filter(|fileChange| fileChange.status.isTracked()).count())
+        };
+
+        Ok(format!("{}\n\n{}", commitsDescription, changesDescription))
+    }
+
+    fn collectCommitsUnreachableFrom(&self, commitId: git2::Oid) -> Result<Vec<String>, git2::Error>
+    {
+        let mut revwalk = self.gitRepo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.hide(commitId)?;
+        revwalk
+            .map(|oid| {
+                let commit = self.gitRepo.find_commit(oid?)?;
+                Ok(format!("{} {}", shortenCommitId(commit.id()), getSummary(&commit)))
+            })
+            .collect()
+    }
+
+    fn findRemoteToPushTo(&self) -> Option<String>
+    {
+        if let Ok(head) = self.gitRepo.head() {
+            if let Some(upstreamRemote) = head.name().and_then(|name| self.gitRepo.branch_upstream_remote(name).ok()) {
+                return upstreamRemote.as_str().map(str::to_owned);
+            }
+        }
+
+        let remotes = self.gitRepo.remotes().ok()?;
+        match remotes.iter().flatten().find(|remote| *remote == "origin") {
+            Some(origin) => Some(origin.into()),
+            None => remotes.iter().flatten().next().map(str::to_owned)
+        }
+    }
+
+    fn findUnbornBranchName(&self) -> String
+    {
+        let head = self.gitRepo.find_reference("HEAD")
+            .unwrap_or_else(|e| exit(&format!("Failed to find reference to HEAD: {}", e)));
+        let target = head.symbolic_target().unwrap_or(INVALID_UTF8);
+        target.strip_prefix("refs/heads/").unwrap_or(target).into()
+    }
+
     fn collectFileStatuses(&self) -> git2::Statuses
     {
-        self.gitRepo.statuses(Some(&mut makeStatusOptions()))
+        self.gitRepo.statuses(Some(&mut makeStatusOptions(self.showsIgnoredFiles)))
             .unwrap_or_else(|e| exit(&format!("Failed to get statuses: {}", e)))
     }
 
+    fn addUnstagedDiffStats(&self, fileChanges: &mut [FileChange])
+    {
+        if fileChanges.is_empty() {
+            return;
+        }
+        let mut diffOptions = makeDiffOptionsForFileChanges(fileChanges);
+        diffOptions
+            .include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .show_untracked_content(true);
+        let mut diff = match self.gitRepo.diff_index_to_workdir(CURRENT_INDEX, Some(&mut diffOptions)) {
+            Ok(diff) => diff,
+            Err(e) => {
+                eprintln!("Failed to get index-to-workdir diff to count changed lines: {}", e);
+                return;
+            }
+        };
+        let mut diffFindOptions = git2::DiffFindOptions::new();
+        diffFindOptions.renames(true).for_untracked(true);
+        if let Err(e) = diff.find_similar(Some(&mut diffFindOptions)) {
+            eprintln!("Failed to find renamed files to count changed lines: {}", e);
+        }
+        addDiffStats(&diff, fileChanges);
+    }
+
+    fn addStagedDiffStats(&self, fileChanges: &mut [FileChange])
+    {
+        if fileChanges.is_empty() {
+            return;
+        }
+        let tree = self.findCurrentTree();
+        let mut diffOptions = makeDiffOptionsForFileChanges(fileChanges);
+        let mut diff = match self.gitRepo.diff_tree_to_index(tree.as_ref(), CURRENT_INDEX, Some(&mut diffOptions)) {
+            Ok(diff) => diff,
+            Err(e) => {
+                eprintln!("Failed to get tree-to-index diff to count changed lines: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = diff.find_similar(Some(git2::DiffFindOptions::new().renames(true))) {
+            eprintln!("Failed to find renamed files to count changed lines: {}", e);
+        }
+        addDiffStats(&diff, fileChanges);
+    }
+
+    fn addSubmoduleStatuses(&self, fileChanges: &mut [FileChange], side: FileStatusSide)
+    {
+        let submodulePaths = match self.gitRepo.submodules() {
+            Ok(submodules) => submodules.iter()
+                .filter_map(|submodule| submodule.path().to_str().map(String::from))
+                .collect::<HashSet<_>>(),
+            Err(e) => {
+                eprintln!("Failed to list submodules: {}", e);
+                return;
+            }
+        };
+        for fileChange in fileChanges.iter_mut().filter(|fileChange| submodulePaths.contains(&fileChange.path)) {
+            match self.gitRepo.submodule_status(&fileChange.path, git2::SubmoduleIgnore::None) {
+                Ok(flags) => fileChange.status.setSubmoduleStatus(flags, side),
+                Err(e) => eprintln!("Failed to get status of submodule {}: {}", fileChange.path, e)
+            }
+        }
+    }
+
     pub fn collectCurrentFileChangesToAmend(&mut self) -> &GroupedFileChanges
     {
         let mut unstaged = UnstagedChanges::new();
         for fileStatusEntry in self.collectFileStatuses().iter() {
             maybeAddToUnstaged(&fileStatusEntry, &mut unstaged);
         }
+        self.addSubmoduleStatuses(&mut unstaged, FileStatusSide::Worktree);
+        self.addUnstagedDiffStats(&mut unstaged);
         let staged = StagedChanges(self.collectLastCommitChanges());
         self.fileChanges = GroupedFileChanges{unstaged, staged};
         &self.fileChanges
@@ -440,6 +1460,36 @@ impl Repository
                 "Failed to stage file {} for removal, because writing the index to disk failed: {}", filePath, e)));
     }
 
+    fn updateIndexToStage(&self, fileChanges: &[FileChange])
+    {
+        let mut index = self.gitRepo.index()
+            .unwrap_or_else(|e| exit(&format!("Failed to stage files, because index could not be acquired: {}", e)));
+        for fileChange in fileChanges {
+            let result = match fileChange.status.kind {
+                FileStatusKind::Deleted => index.remove_path(Path::new(&fileChange.path)),
+                FileStatusKind::Renamed => index.add_path(Path::new(&fileChange.path)).and_then(|()|
+                    index.remove_path(Path::new(fileChange.oldPath.as_ref().unwrap()))),
+                // Like "git add --force", which also adds the files of an ignored directory.
+                FileStatusKind::Ignored => index.add_all([&fileChange.path], git2::IndexAddOption::FORCE, None),
+                _ => index.add_path(Path::new(&fileChange.path))
+            };
+            result.unwrap_or_else(|e| exit(&format!("Failed to stage file {}: {}", fileChange.path, e)));
+        }
+        index.write()
+            .unwrap_or_else(|e| exit(&format!(
+                "Failed to stage files, because writing the index to disk failed: {}", e)));
+    }
+
+    fn resetPathsInIndex(&self, target: Option<&git2::Object>, fileChanges: &[FileChange])
+    {
+        let paths = fileChanges.iter()
+            .flat_map(|fileChange| std::iter::once(&fileChange.path).chain(fileChange.oldPath.as_ref()))
+            .collect::<Vec<_>>();
+        self.gitRepo.reset_default(target, &paths)
+            .unwrap_or_else(|e| exit(&format!(
+                "Failed to unstage {}: {:?}, cause: {}", getFileWord(&paths), paths, e)));
+    }
+
     fn findHeadCommit(&self) -> Option<git2::Commit>
     {
         if self.isEmpty() {
@@ -487,7 +1537,7 @@ impl Repository
     {
         match newFileChange {
             Some(newFileChange) => {
-                if oldFileChange.status != newFileChange.status {
+                if oldFileChange.status != newFileChange.status || oldFileChange.lineStats != newFileChange.lineStats {
                     self.notifyOnUpdatedInStaged(
                         &FileChangeUpdate {old: oldFileChange.clone(), new: (*newFileChange).clone()});
                 }
@@ -507,7 +1557,7 @@ impl Repository
     {
         match newFileChange {
             Some(newFileChange) => {
-                if oldFileChange.status != newFileChange.status {
+                if oldFileChange.status != newFileChange.status || oldFileChange.lineStats != newFileChange.lineStats {
                     self.notifyOnUpdatedInUnstaged(
                         &FileChangeUpdate {old: oldFileChange.clone(), new: (*newFileChange).clone()});
                 }
@@ -523,29 +1573,137 @@ impl Repository
         }
     }
 
-    fn tryAmendCommit(&self, newMessage: &str) -> Result<(), &str>
+    fn createCommit(&self, message: &str) -> Result<(), String>
     {
-        match self.findHeadCommit() {
-            Some(headCommit) => {
-                let newTree = self.storeIndexAsTree();
+        let commiter = self.gitRepo.signature()
+            .unwrap_or_else(|e| exit(&format!("Failed to get a name and/or email of the commit author: {}", e)));
+        let author = self.makeAuthor(Ok(&commiter), None)?;
+        let commiter = &commiter;
+        let tree = self.storeIndexAsTree();
+        let parentCommits = self.findParentCommits();
+        let parentCommits = parentCommits.iter().collect_vec();
+
+        match CommitSigner::fromRepository(&self.gitRepo)? {
+            Some(signer) => {
+                let reflogMessage = match parentCommits.is_empty() {
+                    true => format!("commit (initial): {}", getMessageSummary(message)),
+                    false => format!("commit: {}", getMessageSummary(message))
+                };
+                self.createSignedCommit(&signer, &author, commiter, message, &tree, &parentCommits, &reflogMessage)
+            },
+            None => {
+                self.gitRepo.commit(Some("HEAD"), &author, commiter, message, &tree, &parentCommits)
+                    .unwrap_or_else(|e| exit(&format!("Failed to commit changes: {}", e)));
+                Ok(())
+            }
+        }
+    }
+
+    fn tryAmendCommit(&self, newMessage: &str) -> Result<(), String>
+    {
+        let headCommit = self.findHeadCommit().ok_or("Failed to amend commit - no HEAD commit was found.")?;
+        let newTree = self.storeIndexAsTree();
+        let currentIdentity = self.gitRepo.signature();
+        let author = self.makeAuthor(currentIdentity.as_ref(), Some(&headCommit.author()))?;
+        let committer = match self.authorOptions.resetAuthorOnAmend {
+            true => currentIdentity.map_err(|e| format!("Failed to get a name and/or email of the committer: {}", e))?,
+            false => headCommit.committer().to_owned()
+        };
+        match CommitSigner::fromRepository(&self.gitRepo)? {
+            Some(signer) => {
+                let parentCommits = headCommit.parents().collect_vec();
+                let parentCommits = parentCommits.iter().collect_vec();
+                let reflogMessage = format!("commit (amend): {}", getMessageSummary(newMessage));
+                self.createSignedCommit(
+                    &signer,
+                    &author,
+                    &committer,
+                    newMessage,
+                    &newTree,
+                    &parentCommits,
+                    &reflogMessage)
+            },
+            None => {
                 headCommit.amend(
                     Some("HEAD"),
-                    NO_AUTHOR_UPDATE,
-                    NO_COMMITTER_UPDATE,
+                    Some(&author),
+                    Some(&committer),
                     NO_MESSAGE_ENCODING_UPDATE,
                     Some(newMessage),
                     Some(&newTree))
                     .unwrap();
                 Ok(())
-            },
-            None => Err("Failed to amend commit - no HEAD commit was found.")
+            }
         }
     }
 
+    // The original author of an amended commit is kept unless it is overridden or reset.
+    fn makeAuthor(
+        &self,
+        currentIdentity: Result<&git2::Signature, &git2::Error>,
+        amendedAuthor: Option<&git2::Signature>)
+        -> Result<git2::Signature<'static>, String>
+    {
+        let originalAuthor = amendedAuthor.filter(|_| !self.authorOptions.resetAuthorOnAmend);
+        let getCurrentIdentity = || currentIdentity
+            .map_err(|e| format!("Failed to get a name and/or email of the commit author: {}", e));
+
+        let (name, email) = match (&self.authorOptions.author, originalAuthor) {
+            (Some(author), _) => (author.name.clone(), author.email.clone()),
+            (None, Some(original)) => getNameAndEmail(original),
+            (None, None) => getNameAndEmail(getCurrentIdentity()?)
+        };
+        let time = match (&self.authorOptions.authorDate, originalAuthor) {
+            (Some(authorDate), _) => {
+                let localOffsetMinutes = currentIdentity.map_or(0, |identity| identity.when().offset_minutes());
+                authorDate.toGitTime(localOffsetMinutes)
+            },
+            (None, Some(original)) => original.when(),
+            (None, None) => getCurrentIdentity()?.when()
+        };
+        git2::Signature::new(&name, &email, &time)
+            .map_err(|e| format!("Failed to create the commit author {} <{}>: {}", name, email, e))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn createSignedCommit(
+        &self,
+        signer: &CommitSigner,
+        author: &git2::Signature,
+        committer: &git2::Signature,
+        message: &str,
+        tree: &git2::Tree,
+        parentCommits: &[&git2::Commit],
+        reflogMessage: &str)
+        -> Result<(), String>
+    {
+        let buffer = self.gitRepo.commit_create_buffer(author, committer, message, tree, parentCommits)
+            .map_err(|e| format!("Failed to create a commit: {}", e))?;
+        let content = buffer.as_str().ok_or("Failed to sign the commit - its content is not valid UTF-8.")?;
+        let signature = signer.sign(content)?;
+        let commitId = self.gitRepo.commit_signed(content, &signature, DEFAULT_SIGNATURE_FIELD)
+            .map_err(|e| format!("Failed to write the signed commit: {}", e))?;
+        self.moveHeadTo(commitId, reflogMessage)
+    }
+
+    fn moveHeadTo(&self, commitId: git2::Oid, reflogMessage: &str) -> Result<(), String>
+    {
+        let head = self.gitRepo.find_reference("HEAD")
+            .map_err(|e| format!("Failed to find reference to HEAD: {}", e))?;
+        let result = match head.symbolic_target() {
+            Some(branchName) => self.gitRepo.reference(branchName, commitId, true, reflogMessage).map(|_| ()),
+            None => self.gitRepo.set_head_detached(commitId)
+        };
+        result.map_err(|e| format!("Failed to move HEAD to commit {}: {}", commitId, e))
+    }
+
+    // The pre-commit hook may have staged more changes, so the index is read again before it is stored.
     fn storeIndexAsTree(&self) -> git2::Tree
     {
         let mut index = self.gitRepo.index()
             .unwrap_or_else(|e| exit(&format!("Failed to acquire repository index: {}", e)));
+        index.read(true)
+            .unwrap_or_else(|e| exit(&format!("Failed to read repository index from disk: {}", e)));
         let treeId = index.write_tree()
             .unwrap_or_else(|e| exit(&format!("Failed to write repository index as tree to disk: {}", e)));
         self.gitRepo.find_tree(treeId)
@@ -617,6 +1775,21 @@ impl Repository
     {
         self.sender.send((Source::Repository, Event::Refreshed)).unwrap();
     }
+
+    fn notifyOnCommitFailed(&self)
+    {
+        self.sender.send((Source::Repository, Event::CommitFailed)).unwrap();
+    }
+
+    fn notifyOnHeadChanged(&self)
+    {
+        self.sender.send((Source::Repository, Event::HeadChanged)).unwrap();
+    }
+
+    fn notifyOnReferencesChanged(&self)
+    {
+        self.sender.send((Source::Repository, Event::ReferencesChanged)).unwrap();
+    }
 }
 
 fn openRepository(repositoryDir: &Path) -> git2::Repository
@@ -625,11 +1798,11 @@ fn openRepository(repositoryDir: &Path) -> git2::Repository
         .unwrap_or_else(|e| exit(&format!("Failed to open repository: {}", e)))
 }
 
-fn makeStatusOptions() -> git2::StatusOptions
+fn makeStatusOptions(includesIgnored: bool) -> git2::StatusOptions
 {
     let mut options = git2::StatusOptions::new();
     options
-        .include_ignored(false)
+        .include_ignored(includesIgnored)
         .include_untracked(true)
         .recurse_untracked_dirs(true)
         .renames_head_to_index(true)
@@ -639,59 +1812,163 @@ fn makeStatusOptions() -> git2::StatusOptions
 
 fn maybeAddToUnstaged(fileStatusEntry: &git2::StatusEntry, unstaged: &mut UnstagedChanges) -> bool
 {
-    maybeAddToFileChanges(
-        fileStatusEntry, unstaged, &UNSTAGED_STATUSES, git2::Status::WT_RENAMED, extractRenamedPathFromUnstaged)
+    maybeAddToFileChanges(fileStatusEntry, unstaged, FileStatusSide::Worktree, extractRenamedPathFromUnstaged)
 }
 
 fn maybeAddToStaged(fileStatusEntry: &git2::StatusEntry, staged: &mut StagedChanges) -> bool
 {
-    maybeAddToFileChanges(
-        fileStatusEntry, staged, &STAGED_STATUSES, git2::Status::INDEX_RENAMED, extractRenamedPathFromStaged)
+    maybeAddToFileChanges(fileStatusEntry, staged, FileStatusSide::Index, extractRenamedPathFromStaged)
 }
 
 fn maybeAddToFileChanges(
     fileStatusEntry: &git2::StatusEntry,
     fileChanges: &mut Vec<FileChange>,
-    statusTypes: &[git2::Status],
-    renamedStatus: git2::Status,
+    side: FileStatusSide,
     renamedPathExtractor: RenamedPathExtractor)
     -> bool
 {
-    let statusFlags = fileStatusEntry.status();
-    for statusType in statusTypes {
-        if statusFlags.intersects(*statusType) {
-            if *statusType == renamedStatus {
-                fileChanges.push(makeRenamedFileChange(fileStatusEntry, *statusType, renamedPathExtractor));
-            } else {
-                fileChanges.push(makeFileChange(fileStatusEntry, *statusType));
-            }
-            return STATUS_FOUND;
-        }
+    let status = match FileStatus::fromFlags(fileStatusEntry.status(), side) {
+        Some(status) => status,
+        None => return STATUS_NOT_FOUND
+    };
+    match status.kind {
+        FileStatusKind::Renamed => fileChanges.push(makeRenamedFileChange(fileStatusEntry, status, renamedPathExtractor)),
+        _ => fileChanges.push(makeFileChange(fileStatusEntry, status))
     }
-    STATUS_NOT_FOUND
+    STATUS_FOUND
+}
+
+fn collectFileChangesUnderPaths(fileChanges: &[FileChange], paths: &[FilePathString]) -> Vec<FileChange>
+{
+    fileChanges.iter()
+        .filter(|fileChange| paths.iter().any(|path| match fileChange.status.isIgnored() {
+            true => fileChange.path == *path,
+            false => isPathUnder(&fileChange.path, path)
+        }))
+        .cloned()
+        .collect()
 }
 
-fn makeFileChange(statusEntry: &git2::StatusEntry, status: git2::Status) -> FileChange
+fn isPathUnder(filePath: &FilePathStr, path: &FilePathStr) -> bool
 {
-    FileChange{status: format!("{:?}", status), path: getFilePath(statusEntry), oldPath: None}
+    path.is_empty()
+        || filePath == path
+        || filePath.strip_prefix(path).is_some_and(|rest| rest.starts_with('/'))
 }
 
-fn makeRenamedFileChange(statusEntry: &git2::StatusEntry, status: git2::Status, pathExtractor: RenamedPathExtractor)
+fn makeFileChange(statusEntry: &git2::StatusEntry, status: FileStatus) -> FileChange
+{
+    FileChange{status, path: getFilePath(statusEntry), oldPath: None, lineStats: LineStats::default()}
+}
+
+fn makeRenamedFileChange(statusEntry: &git2::StatusEntry, status: FileStatus, pathExtractor: RenamedPathExtractor)
     -> FileChange
 {
     let fileChange = makeFileChange(statusEntry, status);
     FileChange{
         status: fileChange.status,
         path: pathExtractor(statusEntry),
-        oldPath: Some(fileChange.path)
+        oldPath: Some(fileChange.path),
+        lineStats: fileChange.lineStats
+    }
+}
+
+fn addDiffStats(diff: &git2::Diff, fileChanges: &mut [FileChange])
+{
+    let lineStats = collectLineStats(diff);
+    let similarities = collectRenameSimilarities(diff);
+    for fileChange in fileChanges {
+        if let Some(stats) = lineStats.get(&fileChange.path) {
+            fileChange.lineStats = *stats;
+        }
+        if fileChange.status.kind == FileStatusKind::Renamed {
+            fileChange.status.similarity = similarities.get(&fileChange.path).copied();
+        }
     }
 }
 
+// Lines are counted in one pass over the diff, rather than by making a patch of every file.
+fn collectLineStats(diff: &git2::Diff) -> HashMap<FilePathString, LineStats>
+{
+    let mut lineStats = HashMap::<FilePathString, LineStats>::new();
+    let result = diff.foreach(
+        &mut |_delta, _progress| true,
+        None,
+        None,
+        Some(&mut |delta, _hunk, line| {
+            let path = match delta.new_file().path().and_then(Path::to_str) {
+                Some(path) => path,
+                None => return true
+            };
+            let stats = lineStats.entry(path.to_owned()).or_default();
+            match line.origin_value() {
+                git2::DiffLineType::Addition => stats.added += 1,
+                git2::DiffLineType::Deletion => stats.removed += 1,
+                _ => ()
+            }
+            true
+        }));
+    if let Err(e) = result {
+        eprintln!("Failed to count changed lines: {}", e);
+    }
+    lineStats
+}
+
+// Only the paths found changed by the status are diffed, together with old paths of renamed files to find them again.
+fn makeDiffOptionsForFileChanges(fileChanges: &[FileChange]) -> git2::DiffOptions
+{
+    let mut diffOptions = git2::DiffOptions::new();
+    diffOptions.disable_pathspec_match(true);
+    for fileChange in fileChanges {
+        diffOptions.pathspec(&fileChange.path);
+        if let Some(oldPath) = &fileChange.oldPath {
+            diffOptions.pathspec(oldPath);
+        }
+    }
+    diffOptions
+}
+
+// git2 does not expose the similarity libgit2 found for renamed files, but it is printed in their patch headers.
+fn collectRenameSimilarities(diff: &git2::Diff) -> HashMap<FilePathString, u8>
+{
+    let mut similarities = HashMap::new();
+    let result = diff.print(git2::DiffFormat::PatchHeader, |delta, _hunk, line| {
+        if delta.status() != git2::Delta::Renamed {
+            return true;
+        }
+        let path = delta.new_file().path().and_then(Path::to_str);
+        let similarity = String::from_utf8_lossy(line.content()).lines()
+            .find_map(|headerLine| headerLine.strip_prefix(SIMILARITY_HEADER_PREFIX)?.strip_suffix('%')?.parse().ok());
+        if let (Some(path), Some(similarity)) = (path, similarity) {
+            similarities.insert(path.to_owned(), similarity);
+        }
+        true
+    });
+    if let Err(e) = result {
+        eprintln!("Failed to find similarities of renamed files: {}", e);
+    }
+    similarities
+}
+
+fn loadWorkdirFileContent(path: &Path, maxSize: usize) -> Option<FileContent>
+{
+    let file = std::fs::File::open(path).ok()?;
+    let size = usize::try_from(file.metadata().ok()?.len()).unwrap_or(usize::MAX);
+    if size > maxSize {
+        return Some(FileContent{size, data: None});
+    }
+    // Reading is limited, because the file may have grown since its size was checked.
+    let mut data = Vec::with_capacity(size);
+    file.take(u64::try_from(maxSize).unwrap_or(u64::MAX)).read_to_end(&mut data).ok()?;
+    Some(FileContent{size: data.len(), data: Some(data)})
+}
+
+// Ignored directories are not recursed into and come with a trailing separator, which is dropped to show them as files.
 fn getFilePath(statusEntry: &git2::StatusEntry) -> String
 {
     statusEntry.path().unwrap_or_else(
         || exit(&format!("Failed to convert status entry file path to UTF-8: {}",
-             String::from_utf8_lossy(statusEntry.path_bytes())))).to_string()
+             String::from_utf8_lossy(statusEntry.path_bytes())))).trim_end_matches('/').to_string()
 }
 
 fn findTreeOfParentOfCommit<'a>(commit: &git2::Commit<'a>) -> Option<git2::Tree<'a>>
@@ -709,6 +1986,70 @@ fn extractRenamedPathFromUnstaged(statusEntry: &git2::StatusEntry) -> String
     statusEntry.index_to_workdir().unwrap().new_file().path().unwrap().to_str().unwrap().into()
 }
 
+fn formatIdentity(signature: &git2::Signature) -> String
+{
+    format!("{} <{}>", signature.name().unwrap_or(INVALID_UTF8), signature.email().unwrap_or(INVALID_UTF8))
+}
+
+const fn describeHistoryEdit(edit: &HistoryEdit) -> &'static str
+{
+    match edit {
+        HistoryEdit::Reword(_) => "Reword",
+        HistoryEdit::Drop => "Drop",
+        HistoryEdit::Split(_) => "Split"
+    }
+}
+
+fn getNameAndEmail(signature: &git2::Signature) -> (String, String)
+{
+    (String::from_utf8_lossy(signature.name_bytes()).into(), String::from_utf8_lossy(signature.email_bytes()).into())
+}
+
+fn getRefKind(reference: &git2::Reference, headName: Option<&str>) -> Option<RefKind>
+{
+    if headName.is_some() && reference.name() == headName {
+        Some(RefKind::Head)
+    } else if reference.is_branch() {
+        Some(RefKind::LocalBranch)
+    } else if reference.is_remote() {
+        Some(RefKind::RemoteBranch)
+    } else if reference.is_tag() {
+        Some(RefKind::Tag)
+    } else {
+        None
+    }
+}
+
+// The editors are replaced, so that the rebase runs without any interaction.
+fn runAutosquash(workingDir: &Path, baseArgument: &str) -> Result<(), String>
+{
+    let result = runGitCommand(workingDir, &[
+        "-c", "sequence.editor=true", "-c", "core.editor=true",
+        "rebase", "--interactive", "--autosquash", "--autostash", baseArgument]);
+    if result.is_err() {
+        let _ = runGitCommand(workingDir, &["rebase", "--abort"]);
+    }
+    result.map(|_output| ())
+}
+
+fn getSummary(commit: &git2::Commit) -> String
+{
+    getMessageSummary(&String::from_utf8_lossy(commit.message_bytes())).into()
+}
+
+fn getMessageSummary(message: &str) -> &str
+{
+    message.lines().next().unwrap_or_default().trim()
+}
+
+const fn getCommitWord(count: usize) -> &'static str
+{
+    match count {
+        1 => "commit",
+        _ => "commits"
+    }
+}
+
 const fn getFileWord(paths: &[&String]) -> &'static str
 {
     match paths.len() {
@@ -720,3 +2061,5 @@ const fn getFileWord(paths: &[&String]) -> &'static str
 type RenamedPathExtractor = fn(&git2::StatusEntry) -> String;
 type Stager = fn(&mut Repository, &FileChange);
 type Unstager = fn(&mut Repository, &FileChange);
+type ManyStager = fn(&mut Repository, &[FileChange]);
+type ManyUnstager = fn(&mut Repository, &[FileChange]);
@@ -0,0 +1,19 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ResetMode
+{
+    Soft,
+    Mixed,
+    Hard
+}
+
+impl From<ResetMode> for git2::ResetType
+{
+    fn from(value: ResetMode) -> Self
+    {
+        match value {
+            ResetMode::Soft  => Self::Soft,
+            ResetMode::Mixed => Self::Mixed,
+            ResetMode::Hard  => Self::Hard
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::config;
+use crate::hunspell_dictionary::loadHunspellWords;
+
+use std::cell::OnceCell;
+use std::collections::HashSet;
+use std::ops::Range;
+
+const MAX_SUGGESTIONS: usize = 5;
+const MIN_CHECKED_WORD_LENGTH: usize = 2;
+
+
+// The dictionary is loaded on the first check, as reading and expanding it takes a noticeable time.
+pub(crate) struct SpellChecker
+{
+    config: config::SpellChecking,
+    dictionaryWords: OnceCell<Option<HashSet<String>>>,
+    repositoryWords: HashSet<String>
+}
+
+impl SpellChecker
+{
+    pub fn new(config: &config::SpellChecking) -> Self
+    {
+        Self{config: config.clone(), dictionaryWords: OnceCell::new(), repositoryWords: HashSet::new()}
+    }
+
+    // Identifiers and file names taken from the repository are accepted as correctly spelled.
+    pub fn setRepositoryText(&mut self, text: &str)
+    {
+        self.repositoryWords = splitIntoTokens(text).into_iter()
+            .map(|(_range, token)| token.to_lowercase())
+            .collect();
+    }
+
+    pub fn isMisspelled(&self, word: &str) -> bool
+    {
+        if !isCheckable(word) {
+            return false;
+        }
+        let dictionaryWords = match self.getDictionaryWords() {
+            Some(dictionaryWords) => dictionaryWords,
+            None => return false
+        };
+        let word = word.to_lowercase();
+        !dictionaryWords.contains(&word) && !self.repositoryWords.contains(&word)
+    }
+
+    // Ranges are in characters, like text buffer offsets.
+    pub fn findMisspelledWords(&self, text: &str) -> Vec<Range<usize>>
+    {
+        splitIntoTokens(text).into_iter()
+            .filter(|(_range, token)| self.isMisspelled(token))
+            .map(|(range, _token)| range)
+            .collect()
+    }
+
+    pub fn suggest(&self, word: &str) -> Vec<String>
+    {
+        let dictionaryWords = match self.getDictionaryWords() {
+            Some(dictionaryWords) => dictionaryWords,
+            None => return vec![]
+        };
+        let lowercaseWord = word.to_lowercase();
+        let mut suggestions = vec![];
+        for candidate in makeSingleEdits(&lowercaseWord) {
+            if suggestions.len() == MAX_SUGGESTIONS {
+                break;
+            }
+            if dictionaryWords.contains(&candidate) && !suggestions.contains(&candidate) {
+                suggestions.push(candidate);
+            }
+        }
+        match word.starts_with(char::is_uppercase) {
+            true => suggestions.iter().map(|suggestion| capitalize(suggestion)).collect(),
+            false => suggestions
+        }
+    }
+
+
+    // private
+
+    fn getDictionaryWords(&self) -> Option<&HashSet<String>>
+    {
+        self.dictionaryWords.get_or_init(|| {
+            loadDictionary(&self.config).map_err(|e| eprintln!("Spell checking is disabled. {}", e)).ok()
+        }).as_ref()
+    }
+}
+
+fn loadDictionary(config: &config::SpellChecking) -> Result<HashSet<String>, String>
+{
+    let dictionaryDir = config.dictionaryDirs.iter()
+        .find(|dir| dir.join(format!("{}.dic", config.language)).is_file())
+        .ok_or_else(|| format!("Failed to find a dictionary for language {} in {:?}",
+                               config.language, config.dictionaryDirs))?;
+    loadHunspellWords(
+        &dictionaryDir.join(format!("{}.dic", config.language)),
+        &dictionaryDir.join(format!("{}.aff", config.language)))
+}
+
+fn splitIntoTokens(text: &str) -> Vec<(Range<usize>, String)>
+{
+    let mut tokens = vec![];
+    let mut token = String::new();
+    let mut tokenStart = 0;
+    for (index, character) in text.chars().chain(std::iter::once(' ')).enumerate() {
+        if character.is_alphanumeric() || character == '_' || character == '\'' {
+            if token.is_empty() {
+                tokenStart = index;
+            }
+            token.push(character);
+        } else if !token.is_empty() {
+            let leadingQuotes = token.chars().take_while(|c| *c == '\'').count();
+            let trimmed = token.trim_matches('\'');
+            if !trimmed.is_empty() {
+                let start = tokenStart + leadingQuotes;
+                tokens.push((start..start + trimmed.chars().count(), trimmed.to_owned()));
+            }
+            token.clear();
+        }
+    }
+    tokens
+}
+
+// Identifiers, numbers and acronyms are not words that could be found in a dictionary.
+fn isCheckable(word: &str) -> bool
+{
+    word.chars().count() >= MIN_CHECKED_WORD_LENGTH
+        && word.chars().all(|c| c.is_alphabetic() || c == '\'')
+        && !word.chars().skip(1).any(char::is_uppercase)
+}
+
+fn makeSingleEdits(word: &str) -> Vec<String>
+{
+    let characters = word.chars().collect::<Vec<_>>();
+    let alphabet = ('a'..='z').chain(characters.iter().copied()).collect::<Vec<_>>();
+    let mut edits = vec![];
+    for index in 0..characters.len() {
+        if index + 1 < characters.len() {
+            let mut transposed = characters.clone();
+            transposed.swap(index, index + 1);
+            edits.push(transposed.iter().collect());
+        }
+        let mut deleted = characters.clone();
+        deleted.remove(index);
+        edits.push(deleted.iter().collect());
+        for letter in &alphabet {
+            let mut replaced = characters.clone();
+            replaced[index] = *letter;
+            edits.push(replaced.iter().collect());
+        }
+    }
+    for index in 0..=characters.len() {
+        for letter in &alphabet {
+            let mut inserted = characters.clone();
+            inserted.insert(index, *letter);
+            edits.push(inserted.iter().collect());
+        }
+    }
+    edits
+}
+
+fn capitalize(word: &str) -> String
+{
+    let mut characters = word.chars();
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new()
+    }
+}
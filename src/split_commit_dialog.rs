@@ -0,0 +1,203 @@
+use crate::commit_id::shortenCommitId;
+use crate::gui_element_provider::GuiElementProvider;
+use crate::history_rewriting::{CommitHunk, SplitCommit, SplitCommitPart};
+use crate::text_view::EXCLUDE_HIDDEN_CHARACTERS;
+
+use gtk::glib;
+use gtk::glib::Cast as _;
+use gtk::prelude::AdjustmentExt as _;
+use gtk::prelude::BinExt as _;
+use gtk::prelude::CellRendererSpinExt as _;
+use gtk::prelude::CellRendererTextExt as _;
+use gtk::prelude::ContainerExt as _;
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::GtkListStoreExtManual as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::NotebookExtManual as _;
+use gtk::prelude::ScrolledWindowExt as _;
+use gtk::prelude::SpinButtonExt as _;
+use gtk::prelude::SpinButtonSignals as _;
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::TreeModelExt as _;
+use gtk::prelude::TreeViewColumnExt as _;
+use gtk::prelude::TreeViewExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::rc::Rc;
+
+const PART_COLUMN: u32 = 0;
+const PATH_COLUMN: u32 = 1;
+const HEADER_COLUMN: u32 = 2;
+const WHOLE_FILE_LABEL: &str = "(whole file)";
+const MIN_PART_COUNT: u32 = 2;
+const MESSAGE_VIEW_MIN_HEIGHT: i32 = 120;
+
+
+// Every hunk is assigned to one of the parts, which are numbered from 1. All hunks start in the last part.
+pub(crate) fn askForSplitCommit(commitId: git2::Oid, hunks: &[CommitHunk], message: &str) -> Option<SplitCommit>
+{
+    let guiElementProvider = GuiElementProvider::new(include_str!("split_commit_dialog.glade"));
+    let dialog = guiElementProvider.get::<gtk::Dialog>("dialog");
+    let partCountSpinButton = guiElementProvider.get::<gtk::SpinButton>("Part count spin button");
+    let hunksView = guiElementProvider.get::<gtk::TreeView>("Hunks view");
+    let messagesNotebook = guiElementProvider.get::<gtk::Notebook>("Part messages notebook");
+    let applyButton = guiElementProvider.get::<gtk::Button>("Apply button");
+
+    dialog.set_title(&format!("Split commit {}", shortenCommitId(commitId)));
+    let store = gtk::ListStore::new(&[glib::Type::U32, glib::Type::STRING, glib::Type::STRING]);
+    for hunk in hunks {
+        let header = match hunk.header.is_empty() {
+            true => WHOLE_FILE_LABEL,
+            false => &hunk.header
+        };
+        store.insert_with_values(
+            None, &[(PART_COLUMN, &MIN_PART_COUNT), (PATH_COLUMN, &hunk.path), (HEADER_COLUMN, &header)]);
+    }
+    hunksView.set_model(Some(&store));
+    partCountSpinButton.set_range(MIN_PART_COUNT.into(), hunks.len() as f64);
+    partCountSpinButton.set_increments(1.0, 1.0);
+    partCountSpinButton.set_value(MIN_PART_COUNT.into());
+    let partAdjustment = gtk::Adjustment::new(1.0, 1.0, MIN_PART_COUNT.into(), 1.0, 1.0, 0.0);
+    setupColumns(&hunksView, &store, &partAdjustment);
+    updateMessagePages(&messagesNotebook, MIN_PART_COUNT, message);
+    applyButton.set_sensitive(false);
+    setupPartCountUpdates(&partCountSpinButton, &store, &messagesNotebook, partAdjustment, message);
+    let updateApplyButton = Rc::new(move |store: &gtk::ListStore, partCount| {
+        applyButton.set_sensitive(areAllPartsNonEmpty(store, partCount));
+    });
+    let spinButton = partCountSpinButton.clone();
+    let update = Rc::clone(&updateApplyButton);
+    store.connect_row_changed(move |store, _path, _iter| update(store, getPartCount(&spinButton)));
+    let changedStore = store.clone();
+    partCountSpinButton.connect_value_changed(
+        move |spinButton| updateApplyButton(&changedStore, getPartCount(spinButton)));
+
+    let response = dialog.run();
+    let parts = collectParts(&store, hunks, &messagesNotebook, getPartCount(&partCountSpinButton));
+    let splitCommit = SplitCommit{parts};
+    dialog.close();
+
+    match response {
+        gtk::ResponseType::Ok => Some(splitCommit),
+        _ => None
+    }
+}
+
+fn setupColumns(hunksView: &gtk::TreeView, store: &gtk::ListStore, partAdjustment: &gtk::Adjustment)
+{
+    let partRenderer = gtk::CellRendererSpin::new();
+    partRenderer.set_adjustment(Some(partAdjustment));
+    partRenderer.set_editable(true);
+    let editedStore = store.clone();
+    let adjustment = partAdjustment.clone();
+    partRenderer.connect_edited(move |_renderer, path, newText| {
+        if let Ok(part) = newText.trim().parse::<u32>() {
+            let part = part.clamp(1, adjustment.upper() as u32);
+            let iter = editedStore.iter(&path).unwrap();
+            editedStore.set_value(&iter, PART_COLUMN, &part.into());
+        }
+    });
+    let partColumn = gtk::TreeViewColumn::with_attributes(
+        "Commit", &partRenderer, &[("text", PART_COLUMN.try_into().unwrap())]);
+    hunksView.append_column(&partColumn);
+
+    for (title, column) in [("File", PATH_COLUMN), ("Hunk", HEADER_COLUMN)] {
+        let textColumn = gtk::TreeViewColumn::with_attributes(
+            title, &gtk::CellRendererText::new(), &[("text", column.try_into().unwrap())]);
+        textColumn.set_resizable(true);
+        hunksView.append_column(&textColumn);
+    }
+}
+
+// Hunks of removed parts move to the new last part.
+fn setupPartCountUpdates(
+    partCountSpinButton: &gtk::SpinButton,
+    store: &gtk::ListStore,
+    messagesNotebook: &gtk::Notebook,
+    partAdjustment: gtk::Adjustment,
+    message: &str)
+{
+    let store = store.clone();
+    let messagesNotebook = messagesNotebook.clone();
+    let message = message.to_owned();
+    partCountSpinButton.connect_value_changed(move |spinButton| {
+        let partCount = getPartCount(spinButton);
+        partAdjustment.set_upper(partCount.into());
+        store.foreach(|_model, _path, iter| {
+            if getPart(&store, iter) > partCount {
+                store.set_value(iter, PART_COLUMN, &partCount.into());
+            }
+            false
+        });
+        updateMessagePages(&messagesNotebook, partCount, &message);
+    });
+}
+
+fn updateMessagePages(messagesNotebook: &gtk::Notebook, partCount: u32, message: &str)
+{
+    while messagesNotebook.n_pages() > partCount {
+        messagesNotebook.remove_page(None);
+    }
+    for part in messagesNotebook.n_pages() + 1 ..= partCount {
+        let messageView = gtk::TextView::new();
+        messageView.set_widget_name(&getMessageViewName(part));
+        messageView.set_monospace(true);
+        messageView.set_hexpand(true);
+        messageView.set_vexpand(true);
+        messageView.buffer().unwrap().set_text(message);
+        let scrolledWindow = gtk::ScrolledWindow::new(gtk::Adjustment::NONE, gtk::Adjustment::NONE);
+        scrolledWindow.set_min_content_height(MESSAGE_VIEW_MIN_HEIGHT);
+        scrolledWindow.add(&messageView);
+        scrolledWindow.show_all();
+        messagesNotebook.append_page(&scrolledWindow, Some(&gtk::Label::new(Some(&format!("Commit {}", part)))));
+    }
+}
+
+fn areAllPartsNonEmpty(store: &gtk::ListStore, partCount: u32) -> bool
+{
+    let mut partSizes = vec![0; partCount as usize];
+    store.foreach(|_model, _path, iter| {
+        partSizes[getPart(store, iter) as usize - 1] += 1;
+        false
+    });
+    partSizes.iter().all(|size| *size > 0)
+}
+
+fn collectParts(store: &gtk::ListStore, hunks: &[CommitHunk], messagesNotebook: &gtk::Notebook, partCount: u32)
+    -> Vec<SplitCommitPart>
+{
+    let mut parts = (1..=partCount)
+        .map(|part| SplitCommitPart{hunks: vec![], message: getMessage(messagesNotebook, part)})
+        .collect::<Vec<_>>();
+    let mut row = 0;
+    store.foreach(|_model, _path, iter| {
+        parts[getPart(store, iter) as usize - 1].hunks.push(hunks[row].clone());
+        row += 1;
+        false
+    });
+    parts
+}
+
+fn getPartCount(spinButton: &gtk::SpinButton) -> u32
+{
+    spinButton.value_as_int().try_into().unwrap()
+}
+
+fn getPart(store: &gtk::ListStore, iter: &gtk::TreeIter) -> u32
+{
+    store.value(iter, PART_COLUMN.try_into().unwrap()).get::<u32>().unwrap()
+}
+
+fn getMessageViewName(part: u32) -> String
+{
+    format!("Commit {} message view", part)
+}
+
+fn getMessage(messagesNotebook: &gtk::Notebook, part: u32) -> String
+{
+    let page = messagesNotebook.nth_page(Some(part - 1)).unwrap();
+    let scrolledWindow = page.downcast::<gtk::ScrolledWindow>().unwrap();
+    let messageView = scrolledWindow.child().unwrap().downcast::<gtk::TextView>().unwrap();
+    let buffer = messageView.buffer().unwrap();
+    buffer.text(&buffer.start_iter(), &buffer.end_iter(), EXCLUDE_HIDDEN_CHARACTERS).unwrap().into()
+}
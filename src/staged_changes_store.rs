@@ -1,10 +1,13 @@
+use crate::config::Config;
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::file_change::{FileChange, FileChangeUpdate};
-use crate::file_changes_store::FileChangesStore;
-use crate::file_path::FilePathStr;
+use crate::file_changes_sort_order::FileChangesSortOrder;
+use crate::file_changes_store::{FileChangesStore, FileChangesStoreWidgetData};
+use crate::file_path::{FilePathStr, FilePathString};
 use crate::gui_element_provider::GuiElementProvider;
 use crate::ifile_changes_store::IFileChangesStore;
 use crate::repository::Repository;
+use crate::text_filter_widgets::WidgetData;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -25,8 +28,12 @@ impl IEventHandler for StagedChangesStore
             E::AddedToStaged(fileChange)         => self.onAddedToStaged(fileChange),
             E::AmendedCommit                     => self.onAmendedCommit(),
             E::Committed                         => self.onCommitted(),
+            E::DirectoryTreeDisabled             => self.onDirectoryTreeToggled(false),
+            E::DirectoryTreeEnabled              => self.onDirectoryTreeToggled(true),
+            E::HeadChanged                       => self.onRefreshed(),
             E::Refreshed                         => self.onRefreshed(),
             E::RemovedFromStaged(fileChange)     => self.onRemovedFromStaged(fileChange),
+            E::SortOrderChosen(sortOrder)        => self.onSortOrderChosen(*sortOrder),
             E::UpdatedInStaged(fileChangeUpdate) => self.onUpdatedInStaged(fileChangeUpdate),
             _ => handleUnknown(source, event)
         }
@@ -35,15 +42,21 @@ impl IEventHandler for StagedChangesStore
 
 impl StagedChangesStore
 {
-    pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender, repository: &Rc<RefCell<Repository>>) -> Self
+    pub fn new(
+        guiElementProvider: &GuiElementProvider,
+        sender: Sender,
+        repository: &Rc<RefCell<Repository>>,
+        config: &Config)
+        -> Self
     {
         Self{
             store: FileChangesStore::new(
                 guiElementProvider,
-                "Staged changes store",
-                Source::StagedChangesStore,
+                &makeWidgetData(),
                 sender,
-                repository.borrow().getStagedChanges()),
+                repository.borrow().getStagedChanges(),
+                config.fileChangesPane.showsDirectoryTree,
+                config.fileChangesPane.sortOrder),
             repository: Rc::clone(repository)
         }
     }
@@ -76,6 +89,16 @@ impl StagedChangesStore
         self.store.clear();
     }
 
+    fn onDirectoryTreeToggled(&mut self, showsDirectoryTree: bool)
+    {
+        self.store.setShowsDirectoryTree(showsDirectoryTree);
+    }
+
+    fn onSortOrderChosen(&mut self, sortOrder: FileChangesSortOrder)
+    {
+        self.store.setSortOrder(sortOrder);
+    }
+
     fn onRefreshed(&mut self)
     {
         self.store.refresh(self.repository.borrow().getStagedChanges());
@@ -84,14 +107,24 @@ impl StagedChangesStore
 
 impl IFileChangesStore for StagedChangesStore
 {
-    fn getFileChange(&self, row: usize) -> &FileChange
+    fn getFileChanges(&self) -> &[FileChange]
+    {
+        self.store.getFileChanges()
+    }
+
+    fn getFileChange(&self, rowPath: &gtk::TreePath) -> Option<&FileChange>
+    {
+        self.store.getFileChange(rowPath)
+    }
+
+    fn getFilePath(&self, rowPath: &gtk::TreePath) -> Option<FilePathString>
     {
-        self.store.getFileChange(row)
+        self.store.getFilePath(rowPath)
     }
 
-    fn getFilePath(&self, row: usize) -> &FilePathStr
+    fn collectFileChanges(&self, rowPath: &gtk::TreePath) -> Vec<FileChange>
     {
-        self.store.getFilePath(row)
+        self.store.collectFileChanges(rowPath)
     }
 
     fn findFilePath(&self, path: &FilePathStr) -> Option<usize>
@@ -99,3 +132,11 @@ impl IFileChangesStore for StagedChangesStore
         self.store.findFilePath(path)
     }
 }
+
+fn makeWidgetData() -> FileChangesStoreWidgetData
+{
+    FileChangesStoreWidgetData{
+        store: WidgetData{name: "Staged changes store", source: Source::StagedChangesStore},
+        summaryLabelName: "Staged changes summary label"
+    }
+}
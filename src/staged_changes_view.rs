@@ -1,6 +1,8 @@
 use crate::event::{Event, Source, Sender};
-use crate::file_changes_view::{FileChangesView, OnRowActivatedAction};
+use crate::file_changes_drag_and_drop::DroppedItems;
+use crate::file_changes_view::{FileChangesView, MenuAction, MenuActionScope, OnRowActivatedAction};
 use crate::gui_element_provider::GuiElementProvider;
+use crate::repository::Repository;
 use crate::staged_changes_store::StagedChangesStore;
 
 use std::cell::RefCell;
@@ -12,19 +14,31 @@ pub(crate) type StagedChangesView = FileChangesView<StagedChangesStore>;
 pub(crate) fn makeStagedChangesView(
     guiElementProvider: &GuiElementProvider,
     sender: Sender,
-    store: Rc<RefCell<StagedChangesStore>>)
+    store: Rc<RefCell<StagedChangesStore>>,
+    repository: Rc<RefCell<Repository>>)
     -> StagedChangesView
 {
     let sender2 = sender.clone();
-    let onRowActivatedAction : OnRowActivatedAction = Box::new(move |fileChange|
-        sender.send((Source::StagedChangesView, Event::UnstageRequested(fileChange.clone()))).unwrap());
+    let onRowActivatedAction : OnRowActivatedAction = Box::new(move |fileChanges| {
+        let event = match fileChanges {
+            [fileChange] => Event::UnstageRequested(fileChange.clone()),
+            _ => Event::UnstageManyRequested(fileChanges.to_vec())
+        };
+        sender.send((Source::StagedChangesView, event)).unwrap();
+    });
 
-    FileChangesView::new(
+    let view = FileChangesView::new(
         guiElementProvider,
         "Staged changes view",
         store,
+        repository,
         onRowActivatedAction,
+        vec![
+            MenuAction{label: "Unstage selected", scope: MenuActionScope::Selected},
+            MenuAction{label: "Unstage all", scope: MenuActionScope::All}],
         sender2,
         Source::StagedChangesView
-    )
+    );
+    view.setupDragAndDrop(DroppedItems::FileChangesAndFiles, Event::StagePathsRequested);
+    view
 }
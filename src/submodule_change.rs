@@ -0,0 +1,130 @@
+use crate::commit_id::shortenCommitId;
+use crate::diff_formatter::{FormattedDiff, LineFormat};
+
+use itertools::Itertools;
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SubmoduleChangeKind
+{
+    Unstaged,
+    Staged
+}
+
+pub(crate) struct SubmoduleChange
+{
+    pub path: String,
+    pub states: Vec<&'static str>,
+    pub oldId: Option<git2::Oid>,
+    pub newId: Option<git2::Oid>,
+    // None when the submodule is not checked out or lacks some of the commits.
+    pub commits: Option<SubmoduleCommits>
+}
+
+#[derive(Default)]
+pub(crate) struct SubmoduleCommits
+{
+    pub added: Vec<String>,
+    pub removed: Vec<String>
+}
+
+pub(crate) fn findSubmoduleChange(gitRepo: &git2::Repository, path: &str, kind: SubmoduleChangeKind)
+    -> Option<SubmoduleChange>
+{
+    let submodule = gitRepo.find_submodule(path).ok()?;
+    let status = gitRepo.submodule_status(path, git2::SubmoduleIgnore::None).ok()?;
+    let (oldId, newId) = match kind {
+        SubmoduleChangeKind::Unstaged => (submodule.index_id(), submodule.workdir_id()),
+        SubmoduleChangeKind::Staged => (submodule.head_id(), submodule.index_id())
+    };
+    let commits = match (oldId, newId) {
+        (Some(oldId), Some(newId)) => submodule.open().ok()
+            .and_then(|submoduleRepo| collectSubmoduleCommits(&submoduleRepo, oldId, newId).ok()),
+        _ => Some(SubmoduleCommits::default())
+    };
+    Some(SubmoduleChange{path: path.into(), states: describeSubmoduleStatus(status, kind), oldId, newId, commits})
+}
+
+pub(crate) fn formatSubmoduleChange(change: &SubmoduleChange) -> FormattedDiff
+{
+    let states = match change.states.is_empty() {
+        true => "unchanged".into(),
+        false => change.states.join(", ")
+    };
+    let mut diff = FormattedDiff{
+        text: format!("submodule {}\nstatus: {}\ncommits {}..{}\n",
+                      change.path, states, formatSubmoduleCommitId(change.oldId), formatSubmoduleCommitId(change.newId)),
+        lineFormats: vec![LineFormat::TopHeader, LineFormat::TopHeader, LineFormat::HunkHeader],
+        characterFormats: vec![]
+    };
+    match &change.commits {
+        Some(commits) => {
+            for summary in &commits.removed {
+                diff.text.push_str(&format!("-{}\n", summary));
+                diff.lineFormats.push(LineFormat::RemovedLine);
+            }
+            for summary in &commits.added {
+                diff.text.push_str(&format!("+{}\n", summary));
+                diff.lineFormats.push(LineFormat::AddedLine);
+            }
+        },
+        None => {
+            diff.text.push_str(" commits are not available, the submodule is not initialized or not fetched\n");
+            diff.lineFormats.push(LineFormat::ContextLine);
+        }
+    }
+    diff
+}
+
+
+// private
+
+fn describeSubmoduleStatus(status: git2::SubmoduleStatus, kind: SubmoduleChangeKind) -> Vec<&'static str>
+{
+    use git2::SubmoduleStatus as S;
+    let flags: &[(S, &str)] = match kind {
+        SubmoduleChangeKind::Unstaged => &[
+            (S::WD_UNINITIALIZED, "not initialized"),
+            (S::WD_ADDED, "added"),
+            (S::WD_DELETED, "deleted"),
+            (S::WD_MODIFIED, "new commits"),
+            (S::WD_INDEX_MODIFIED | S::WD_WD_MODIFIED, "modified content"),
+            (S::WD_UNTRACKED, "untracked content")],
+        SubmoduleChangeKind::Staged => &[
+            (S::INDEX_ADDED, "added"),
+            (S::INDEX_DELETED, "deleted"),
+            (S::INDEX_MODIFIED, "new commits")]
+    };
+    flags.iter()
+        .filter(|(flag, _description)| status.intersects(*flag))
+        .map(|(_flag, description)| *description)
+        .collect()
+}
+
+fn collectSubmoduleCommits(submoduleRepo: &git2::Repository, oldId: git2::Oid, newId: git2::Oid)
+    -> Result<SubmoduleCommits, git2::Error>
+{
+    Ok(SubmoduleCommits{
+        added: collectCommitSummariesBetween(submoduleRepo, oldId, newId)?,
+        removed: collectCommitSummariesBetween(submoduleRepo, newId, oldId)?
+    })
+}
+
+fn collectCommitSummariesBetween(submoduleRepo: &git2::Repository, hiddenId: git2::Oid, shownId: git2::Oid)
+    -> Result<Vec<String>, git2::Error>
+{
+    let mut revwalk = submoduleRepo.revwalk()?;
+    revwalk.push(shownId)?;
+    revwalk.hide(hiddenId)?;
+    revwalk
+        .map(|id| {
+            let commit = submoduleRepo.find_commit(id?)?;
+            Ok(format!("{} {}", shortenCommitId(commit.id()), commit.summary().unwrap_or_default()))
+        })
+        .try_collect()
+}
+
+fn formatSubmoduleCommitId(id: Option<git2::Oid>) -> String
+{
+    id.map_or_else(|| "none".into(), shortenCommitId)
+}
@@ -0,0 +1,53 @@
+use crate::commit_id::shortenCommitId;
+use crate::gui_element_provider::GuiElementProvider;
+use crate::new_tag::NewTag;
+use crate::text_view::EXCLUDE_HIDDEN_CHARACTERS;
+
+use gtk::EditableSignals as _;
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::ToggleButtonExt as _;
+use gtk::prelude::WidgetExt as _;
+
+
+pub(crate) fn askForNewTag(commitId: git2::Oid) -> Option<NewTag>
+{
+    let guiElementProvider = GuiElementProvider::new(include_str!("tag_dialog.glade"));
+    let dialog = guiElementProvider.get::<gtk::Dialog>("dialog");
+    let nameEntry = guiElementProvider.get::<gtk::Entry>("Tag name entry");
+    let annotatedCheckbox = guiElementProvider.get::<gtk::CheckButton>("Tag annotated checkbox");
+    let messageView = guiElementProvider.get::<gtk::TextView>("Tag message view");
+    let createButton = guiElementProvider.get::<gtk::Button>("Create button");
+
+    dialog.set_title(&format!("Create tag at {}", shortenCommitId(commitId)));
+    createButton.set_sensitive(false);
+    nameEntry.connect_changed(move |entry| createButton.set_sensitive(isValidTagName(&entry.text())));
+    let messageViewClone = messageView.clone();
+    annotatedCheckbox.connect_toggled(move |checkbox| messageViewClone.set_sensitive(checkbox.is_active()));
+
+    let response = dialog.run();
+    let newTag = NewTag{
+        name: nameEntry.text().into(),
+        message: annotatedCheckbox.is_active().then(|| getText(&messageView))
+    };
+    dialog.close();
+
+    match response {
+        gtk::ResponseType::Ok => Some(newTag),
+        _ => None
+    }
+}
+
+fn isValidTagName(name: &str) -> bool
+{
+    !name.is_empty() && git2::Reference::is_valid_name(&format!("refs/tags/{}", name))
+}
+
+fn getText(textView: &gtk::TextView) -> String
+{
+    let buffer = textView.buffer().unwrap();
+    buffer.text(&buffer.start_iter(), &buffer.end_iter(), EXCLUDE_HIDDEN_CHARACTERS).unwrap().into()
+}
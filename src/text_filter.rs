@@ -1,5 +1,6 @@
 use anyhow::Result;
 use regex::{Regex, RegexBuilder};
+use std::ops::Range;
 
 
 pub(crate) struct TextFilter
@@ -121,4 +122,27 @@ impl TextFilter
             RegexState::Valid{regex} => regex.is_match(input)
         }
     }
+
+    // Byte ranges of non-overlapping matches. Empty matches of regexes are skipped, as there is nothing to show.
+    pub(crate) fn findMatches(&self, input: &str) -> Vec<Range<usize>>
+    {
+        if self.isEmpty() {
+            return vec![];
+        }
+        match &self.regexState {
+            RegexState::Disabled{text} => {
+                match RegexBuilder::new(&regex::escape(text)).case_insensitive(!self.caseSensitive).build() {
+                    Ok(regex) => findRegexMatches(&regex, input),
+                    Err(_) => vec![]
+                }
+            },
+            RegexState::Invalid{..} => vec![],
+            RegexState::Valid{regex} => findRegexMatches(regex, input)
+        }
+    }
+}
+
+fn findRegexMatches(regex: &Regex, input: &str) -> Vec<Range<usize>>
+{
+    regex.find_iter(input).filter(|found| !found.is_empty()).map(|found| found.range()).collect()
 }
@@ -13,13 +13,13 @@ const INVALID_INPUT_CSS: &[u8] = "entry {color: red;}".as_bytes();
 const VALID_INPUT_CSS: &[u8] = "".as_bytes();
 
 
-pub(crate) struct CommitLogTextFilterWidgets
+pub(crate) struct TextFilterWidgets
 {
     entryWidget: gtk::Entry,
     cssProvider: gtk::CssProvider
 }
 
-impl IEventHandler for CommitLogTextFilterWidgets
+impl IEventHandler for TextFilterWidgets
 {
     fn handle(&mut self, source: Source, event: &Event)
     {
@@ -31,7 +31,7 @@ impl IEventHandler for CommitLogTextFilterWidgets
     }
 }
 
-impl CommitLogTextFilterWidgets
+impl TextFilterWidgets
 {
     pub(crate) fn new(
         entryData: &WidgetData,
@@ -49,13 +49,13 @@ impl CommitLogTextFilterWidgets
         Self{entryWidget, cssProvider}
     }
 
-    fn onInvalidRegexInputted(&self, error: &regex::Error)
+    pub(crate) fn onInvalidRegexInputted(&self, error: &regex::Error)
     {
         self.cssProvider.load_from_data(INVALID_INPUT_CSS).unwrap();
         self.entryWidget.set_tooltip_text(Some(&error.to_string()));
     }
 
-    fn onValidRegexInputted(&self)
+    pub(crate) fn onValidRegexInputted(&self)
     {
         self.cssProvider.load_from_data(VALID_INPUT_CSS).unwrap();
         self.entryWidget.set_tooltip_text(None);
@@ -0,0 +1,235 @@
+use crate::event::{Event, Sender};
+use crate::gui_element_provider::GuiElementProvider;
+use crate::number_casts::ToI32 as _;
+use crate::text_filter::TextFilter;
+use crate::text_filter_widgets::{TextFilterWidgets, WidgetData};
+use crate::text_view::TextView;
+
+use gtk::prelude::ButtonExt as _;
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::LabelExt as _;
+use gtk::prelude::SearchBarExt as _;
+use gtk::prelude::SearchEntryExt as _;
+use gtk::prelude::TextTagExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::ops::Range;
+
+const MATCH_COLOR: &str = "#fff59d";
+const CURRENT_MATCH_COLOR: &str = "#ffb74d";
+
+
+// Searches the text of a text view like DiffColorizer colors it, so the owning view passes its text view in.
+pub(crate) struct TextSearch
+{
+    searchBar: gtk::SearchBar,
+    entry: gtk::SearchEntry,
+    matchCountLabel: gtk::Label,
+    filterWidgets: TextFilterWidgets,
+    filter: TextFilter,
+    matchTag: gtk::TextTag,
+    currentMatchTag: gtk::TextTag,
+    matches: Vec<Range<i32>>,
+    currentMatch: Option<usize>
+}
+
+pub(crate) struct TextSearchWidgetData
+{
+    pub searchBarName: &'static str,
+    pub entry: WidgetData,
+    pub caseButton: WidgetData,
+    pub regexButton: WidgetData,
+    pub previousButton: WidgetData,
+    pub nextButton: WidgetData,
+    pub matchCountLabelName: &'static str
+}
+
+impl TextSearch
+{
+    pub fn new(
+        guiElementProvider: &GuiElementProvider,
+        textView: &TextView,
+        widgetData: &TextSearchWidgetData,
+        sender: Sender)
+        -> Self
+    {
+        let filterWidgets = TextFilterWidgets::new(
+            &widgetData.entry, &widgetData.caseButton, &widgetData.regexButton, guiElementProvider, sender.clone());
+        let searchBar = guiElementProvider.get::<gtk::SearchBar>(widgetData.searchBarName);
+        let entry = guiElementProvider.get::<gtk::SearchEntry>(widgetData.entry.name);
+        searchBar.connect_entry(&entry);
+        connectEntry(&entry, widgetData, &sender);
+        setupButton(&widgetData.previousButton, guiElementProvider, sender.clone());
+        setupButton(&widgetData.nextButton, guiElementProvider, sender);
+
+        let matchTag = makeTag(MATCH_COLOR);
+        let currentMatchTag = makeTag(CURRENT_MATCH_COLOR);
+        textView.registerTags(&[&matchTag, &currentMatchTag]);
+
+        Self{
+            searchBar,
+            entry,
+            matchCountLabel: guiElementProvider.get::<gtk::Label>(widgetData.matchCountLabelName),
+            filterWidgets,
+            filter: TextFilter::new(),
+            matchTag,
+            currentMatchTag,
+            matches: vec![],
+            currentMatch: None
+        }
+    }
+
+    // The search shortcut is shared by all searches, so only the one shown on the current page is opened.
+    pub fn open(&self)
+    {
+        if !self.searchBar.is_mapped() {
+            return;
+        }
+        self.searchBar.set_search_mode(true);
+        self.entry.grab_focus();
+    }
+
+    pub fn searchFor(&mut self, textView: &TextView, text: &str)
+    {
+        let result = self.filter.setText(text);
+        self.showInputValidity(result);
+        self.searchAndSelectFirstMatch(textView);
+    }
+
+    pub fn setCaseSensitivityEnabled(&mut self, textView: &TextView, shouldEnable: bool)
+    {
+        let result = self.filter.setCaseSensitivityEnabled(shouldEnable);
+        self.showInputValidity(result);
+        self.searchAndSelectFirstMatch(textView);
+    }
+
+    pub fn setRegexEnabled(&mut self, textView: &TextView, shouldEnable: bool)
+    {
+        let result = self.filter.setRegexEnabled(shouldEnable);
+        self.showInputValidity(result);
+        self.searchAndSelectFirstMatch(textView);
+    }
+
+    pub fn selectNextMatch(&mut self, textView: &TextView)
+    {
+        if self.matches.is_empty() {
+            return;
+        }
+        let index = match self.currentMatch {
+            Some(index) => (index + 1) % self.matches.len(),
+            None => 0
+        };
+        self.selectMatch(textView, index);
+    }
+
+    pub fn selectPreviousMatch(&mut self, textView: &TextView)
+    {
+        if self.matches.is_empty() {
+            return;
+        }
+        let index = match self.currentMatch {
+            Some(index) => (index + self.matches.len() - 1) % self.matches.len(),
+            None => self.matches.len() - 1
+        };
+        self.selectMatch(textView, index);
+    }
+
+    // Called after the text changes. Matches are highlighted again, but the view is not scrolled.
+    pub fn refresh(&mut self, textView: &TextView)
+    {
+        self.search(textView);
+    }
+
+
+    // private
+
+    fn showInputValidity(&self, result: Result<(), regex::Error>)
+    {
+        match result {
+            Ok(()) => self.filterWidgets.onValidRegexInputted(),
+            Err(e) => self.filterWidgets.onInvalidRegexInputted(&e)
+        }
+    }
+
+    fn searchAndSelectFirstMatch(&mut self, textView: &TextView)
+    {
+        self.search(textView);
+        self.selectNextMatch(textView);
+    }
+
+    fn search(&mut self, textView: &TextView)
+    {
+        textView.removeTag(&self.matchTag);
+        textView.removeTag(&self.currentMatchTag);
+        let text = textView.getText();
+        self.matches = toCharacterRanges(&text, &self.filter.findMatches(&text));
+        self.currentMatch = None;
+        for range in &self.matches {
+            textView.applyTagToCharacters(&self.matchTag, range.start, range.end);
+        }
+        self.updateMatchCount();
+    }
+
+    fn selectMatch(&mut self, textView: &TextView, index: usize)
+    {
+        textView.removeTag(&self.currentMatchTag);
+        let range = &self.matches[index];
+        textView.applyTagToCharacters(&self.currentMatchTag, range.start, range.end);
+        textView.scrollToCharacter(range.start);
+        self.currentMatch = Some(index);
+        self.updateMatchCount();
+    }
+
+    fn updateMatchCount(&self)
+    {
+        let label = match (self.matches.len(), self.currentMatch) {
+            _ if self.filter.isEmpty() => String::new(),
+            (0, _) => "No matches".into(),
+            (count, Some(index)) => format!("{} of {}", index + 1, count),
+            (1, None) => "1 match".into(),
+            (count, None) => format!("{} matches", count)
+        };
+        self.matchCountLabel.set_text(&label);
+    }
+}
+
+fn connectEntry(entry: &gtk::SearchEntry, widgetData: &TextSearchWidgetData, sender: &Sender)
+{
+    let nextButtonSource = widgetData.nextButton.source;
+    let previousButtonSource = widgetData.previousButton.source;
+    let eventSender = sender.clone();
+    entry.connect_activate(move |_entry| eventSender.send((nextButtonSource, Event::Clicked)).unwrap());
+    let eventSender = sender.clone();
+    entry.connect_next_match(move |_entry| eventSender.send((nextButtonSource, Event::Clicked)).unwrap());
+    let eventSender = sender.clone();
+    entry.connect_previous_match(move |_entry| eventSender.send((previousButtonSource, Event::Clicked)).unwrap());
+}
+
+fn setupButton(buttonData: &WidgetData, guiElementProvider: &GuiElementProvider, sender: Sender)
+{
+    let button = guiElementProvider.get::<gtk::Button>(buttonData.name);
+    let source = buttonData.source;
+    button.connect_clicked(move |_button| sender.send((source, Event::Clicked)).unwrap());
+}
+
+fn makeTag(color: &str) -> gtk::TextTag
+{
+    let tag = gtk::TextTag::new(None);
+    tag.set_background(Some(color));
+    tag
+}
+
+// Text buffers are indexed by characters, while matches are found by bytes.
+fn toCharacterRanges(text: &str, byteRanges: &[Range<usize>]) -> Vec<Range<i32>>
+{
+    let mut characterRanges = Vec::with_capacity(byteRanges.len());
+    let mut lastByteOffset = 0;
+    let mut lastCharacterOffset = 0;
+    for byteRange in byteRanges {
+        let start = lastCharacterOffset + text[lastByteOffset..byteRange.start].chars().count();
+        let end = start + text[byteRange.clone()].chars().count();
+        characterRanges.push(start.toI32()..end.toI32());
+        lastByteOffset = byteRange.end;
+        lastCharacterOffset = end;
+    }
+    characterRanges
+}
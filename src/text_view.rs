@@ -12,15 +12,23 @@ use gtk::prelude::TextTagTableExt as _;
 use gtk::prelude::TextViewExt as _;
 use gtk::prelude::WidgetExt as _;
 use std::cmp::{min, max};
+use std::ops::Range;
 
 pub(crate) const EXCLUDE_HIDDEN_CHARACTERS : bool = false;
 const NO_SEARCH_LIMIT: Option<&gtk::TextIter> = None;
+const NO_MARK_NAME: Option<&str> = None;
+const LEFT_GRAVITY: bool = true;
+const NO_WITHIN_MARGIN: f64 = 0.0;
+const USE_ALIGN: bool = true;
+const LEFT_ALIGN: f64 = 0.0;
+const CENTER_ALIGN: f64 = 0.5;
 const SEARCH_VISIBLE_TEXT: gtk::TextSearchFlags = gtk::TextSearchFlags::from_bits_truncate(
     gtk::TextSearchFlags::VISIBLE_ONLY.bits() | gtk::TextSearchFlags::TEXT_ONLY.bits());
 
 
 pub(crate) struct TextView
 {
+    widget: gtk::TextView,
     buffer: gtk::TextBuffer,
     sender: Sender,
     source: Source,
@@ -58,6 +66,7 @@ impl TextView
     {
         let widget = guiElementProvider.get::<gtk::TextView>(name);
         let newSelf = Self{
+            widget: widget.clone(),
             buffer: widget.buffer().unwrap(),
             sender,
             source,
@@ -68,7 +77,7 @@ impl TextView
         if notifications == Notifications::Enabled {
             newSelf.connectBuffer();
         }
-        newSelf.connectWidget(&widget);
+        newSelf.connectWidget();
         newSelf
     }
 
@@ -137,11 +146,39 @@ impl TextView
         self.applyTagUntilMatchEnd(tag, line, "\n");
     }
 
+    pub fn applyTagToCharacters(&self, tag: &gtk::TextTag, startOffset: i32, endOffset: i32)
+    {
+        self.buffer.apply_tag(tag, &self.buffer.iter_at_offset(startOffset), &self.buffer.iter_at_offset(endOffset));
+    }
+
+    pub fn applyTagToLineCharacters(&self, tag: &gtk::TextTag, line: LineNumber, columns: Range<usize>)
+    {
+        let line = line.into();
+        let toOffset = |column: usize| i32::try_from(column).unwrap_or(i32::MAX);
+        self.buffer.apply_tag(
+            tag,
+            &self.buffer.iter_at_line_offset(line, toOffset(columns.start)),
+            &self.buffer.iter_at_line_offset(line, toOffset(columns.end)));
+    }
+
+    pub fn removeTag(&self, tag: &gtk::TextTag)
+    {
+        self.buffer.remove_tag(tag, &self.buffer.start_iter(), &self.buffer.end_iter());
+    }
+
     pub fn removeTags(&self)
     {
         self.buffer.remove_all_tags(&self.buffer.start_iter(), &self.buffer.end_iter());
     }
 
+    pub fn scrollToCharacter(&self, offset: i32)
+    {
+        // A mark is used, because scrolling to an iterator fails when line heights are not yet calculated.
+        let mark = self.buffer.create_mark(NO_MARK_NAME, &self.buffer.iter_at_offset(offset), LEFT_GRAVITY).unwrap();
+        self.widget.scroll_to_mark(&mark, NO_WITHIN_MARGIN, USE_ALIGN, LEFT_ALIGN, CENTER_ALIGN);
+        self.buffer.delete_mark(&mark);
+    }
+
 
     // private
 
@@ -154,11 +191,11 @@ impl TextView
         });
     }
 
-    fn connectWidget(&self, widget: &gtk::TextView)
+    fn connectWidget(&self)
     {
         let sender = self.sender.clone();
         let source = self.source;
-        widget.connect_scroll_event(move |_widget, event| {
+        self.widget.connect_scroll_event(move |_widget, event| {
             onScrolled(event, &sender, source)
         });
     }
@@ -0,0 +1,67 @@
+use crate::commit_message_trailers::{isValidTrailerKey, Trailer};
+use crate::gui_element_provider::GuiElementProvider;
+
+use gtk::glib;
+use gtk::EditableSignals as _;
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::EntryCompletionExt as _;
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::GtkListStoreExt as _;
+use gtk::prelude::GtkListStoreExtManual as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::rc::Rc;
+
+const TEXT_COLUMN: i32 = 0;
+
+
+// A given key is fixed, otherwise the user chooses it. Value suggestions are offered as completions.
+pub(crate) fn askForTrailer(key: Option<&str>, valueSuggestions: &[String]) -> Option<Trailer>
+{
+    let guiElementProvider = GuiElementProvider::new(include_str!("trailer_dialog.glade"));
+    let dialog = guiElementProvider.get::<gtk::Dialog>("dialog");
+    let keyEntry = guiElementProvider.get::<gtk::Entry>("Trailer key entry");
+    let valueEntry = guiElementProvider.get::<gtk::Entry>("Trailer value entry");
+    let addButton = guiElementProvider.get::<gtk::Button>("Add button");
+
+    if let Some(key) = key {
+        keyEntry.set_text(key);
+        keyEntry.set_sensitive(false);
+        valueEntry.grab_focus();
+    }
+    valueEntry.set_completion(Some(&makeCompletion(valueSuggestions)));
+    dialog.set_default_response(gtk::ResponseType::Ok);
+
+    let updateAddButton = Rc::new({
+        let keyEntry = keyEntry.clone();
+        let valueEntry = valueEntry.clone();
+        move || addButton.set_sensitive(
+            isValidTrailerKey(keyEntry.text().trim()) && !valueEntry.text().trim().is_empty())
+    });
+    updateAddButton();
+    let onChanged = Rc::clone(&updateAddButton);
+    keyEntry.connect_changed(move |_entry| onChanged());
+    valueEntry.connect_changed(move |_entry| updateAddButton());
+
+    let response = dialog.run();
+    let trailer = Trailer{key: keyEntry.text().trim().into(), value: valueEntry.text().trim().into()};
+    dialog.close();
+
+    match response {
+        gtk::ResponseType::Ok if isValidTrailerKey(&trailer.key) && !trailer.value.is_empty() => Some(trailer),
+        _ => None
+    }
+}
+
+fn makeCompletion(suggestions: &[String]) -> gtk::EntryCompletion
+{
+    let store = gtk::ListStore::new(&[glib::Type::STRING]);
+    for suggestion in suggestions {
+        store.set(&store.append(), &[(0, suggestion)]);
+    }
+    let completion = gtk::EntryCompletion::new();
+    completion.set_model(Some(&store));
+    completion.set_text_column(TEXT_COLUMN);
+    completion.set_inline_completion(true);
+    completion
+}
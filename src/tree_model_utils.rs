@@ -1,3 +1,6 @@
+use gtk::prelude::TreeModelFilterExt as _;
+
+
 pub(crate) type Row = usize;
 
 #[must_use]
@@ -5,3 +8,10 @@ pub(crate) fn toRow(rowPath: &gtk::TreePath) -> Row
 {
     rowPath.indices()[0].try_into().unwrap()
 }
+
+// Views of filtered models report row paths of the filter, while stores expect paths of their own rows.
+#[must_use]
+pub(crate) fn toChildRowPaths(modelFilter: &gtk::TreeModelFilter, rowPaths: &[gtk::TreePath]) -> Vec<gtk::TreePath>
+{
+    rowPaths.iter().filter_map(|rowPath| modelFilter.convert_path_to_child_path(rowPath)).collect()
+}
@@ -1,6 +1,5 @@
 use crate::event::{Event, Sender, Source};
 use crate::selections_comparer::SelectionsComparer;
-use crate::tree_model_utils::toRow;
 
 use glib::ObjectExt;
 use gtk::glib;
@@ -29,10 +28,21 @@ impl TreeSelection
         Self{selection, signalHandlerId, selectionsComparer, sender, eventSource}
     }
 
-    pub fn getSelectedRow(&self) -> Option<usize>
+    pub fn getSelectedRowPath(&self) -> Option<gtk::TreePath>
     {
         let (rowPaths, _model) = self.selection.selected_rows();
-        rowPaths.get(0).map(toRow)
+        rowPaths.into_iter().next()
+    }
+
+    pub fn getSelectedRowPaths(&self) -> Vec<gtk::TreePath>
+    {
+        let (rowPaths, _model) = self.selection.selected_rows();
+        rowPaths
+    }
+
+    pub fn isRowSelected(&self, rowPath: &gtk::TreePath) -> bool
+    {
+        self.selection.path_is_selected(rowPath)
     }
 
     pub fn selectByIterator(&self, iterator: &gtk::TreeIter)
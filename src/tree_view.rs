@@ -1,13 +1,16 @@
 use crate::error_handling::exit;
 use crate::event::{Event, Sender, Source};
-use crate::event_constants::FORWARD_EVENT;
+use crate::event_constants::{CONSUME_EVENT, FORWARD_EVENT};
 use crate::gui_element_provider::GuiElementProvider;
 use crate::number_casts::ToI32 as _;
 use crate::selections_comparer::SelectionsComparer;
 use crate::tree_model_utils::toRow;
 use crate::tree_selection::TreeSelection;
 
+use gtk::glib::prelude::ObjectExt as _;
+use gtk::prelude::CellRendererTextExt as _;
 use gtk::prelude::TreeModelExt as _;
+use gtk::prelude::TreeSelectionExt as _;
 use gtk::prelude::TreeViewColumnExt as _;
 use gtk::prelude::TreeViewExt as _;
 use gtk::prelude::WidgetExt as _;
@@ -15,7 +18,12 @@ use gtk::prelude::WidgetExt as _;
 const EXPAND_IN_LAYOUT : bool = true;
 const NO_COLUMN_FOCUS: Option<&gtk::TreeViewColumn> = None;
 const NO_EDITING: bool = false;
+const NO_EXPAND_IN_LAYOUT: bool = false;
+const NO_EXPAND_DESCENDANTS: bool = false;
+const FIRST_CELL_POSITION: i32 = 0;
 const MOUSE_RIGHT_BUTTON: u32 = 3;
+const MENU_SHOWN: bool = true;
+const MONOSPACE_FONT_FAMILY: &str = "monospace";
 
 
 pub(crate) struct TreeView
@@ -43,6 +51,11 @@ impl TreeView
         newSelf
     }
 
+    pub(crate) const fn getWidget(&self) -> &gtk::TreeView
+    {
+        &self.widget
+    }
+
     pub fn getModel(&self) -> gtk::TreeModel
     {
         self.widget.model().unwrap()
@@ -59,18 +72,21 @@ impl TreeView
     }
 
     pub fn getRowAtPosition(&self, x: f64, y: f64) -> Option<usize>
+    {
+        self.getRowPathAtPosition(x, y).map(|rowPath| toRow(&rowPath))
+    }
+
+    pub fn getRowPathAtPosition(&self, x: f64, y: f64) -> Option<gtk::TreePath>
     {
         match self.widget.path_at_pos(x.toI32(), y.toI32()) {
-            Some(result) => Some(toRow(&result.0.unwrap())),
+            Some(result) => result.0,
             None => None
         }
     }
 
-    pub fn focusFirstRow(&self)
+    pub fn focusRow(&self, iter: &gtk::TreeIter)
     {
-        let model = self.getModel();
-        let iter = model.iter_first().unwrap();
-        let rowPath = model.path(&iter).unwrap();
+        let rowPath = self.getModel().path(iter).unwrap();
         self.widget.set_cursor(&rowPath, NO_COLUMN_FOCUS, NO_EDITING);
         self.focus();
     }
@@ -80,6 +96,65 @@ impl TreeView
         self.widget.grab_focus();
     }
 
+    // Rows that get their first child are expanded, so that new directories show their content.
+    pub fn expandRowsOnFirstChild(&self)
+    {
+        self.widget.expand_all();
+        let widget = self.widget.clone();
+        self.getModel().connect_row_has_child_toggled(move |model, rowPath, iter| {
+            if model.iter_has_child(iter) {
+                widget.expand_row(rowPath, NO_EXPAND_DESCENDANTS);
+            }
+        });
+    }
+
+    pub fn prependMarkupCell(&self, viewColumnIndex: i32, modelColumnIndex: i32)
+    {
+        let renderer = gtk::CellRendererText::new();
+        let column = self.getColumn(viewColumnIndex);
+        column.pack_start(&renderer, NO_EXPAND_IN_LAYOUT);
+        gtk::prelude::CellLayoutExt::reorder(&column, &renderer, FIRST_CELL_POSITION);
+        column.add_attribute(&renderer, "markup", modelColumnIndex);
+    }
+
+    pub fn prependMonospaceCell(&self, viewColumnIndex: i32, modelColumnIndex: i32)
+    {
+        let renderer = gtk::CellRendererText::new();
+        renderer.set_family(Some(MONOSPACE_FONT_FAMILY));
+        let column = self.getColumn(viewColumnIndex);
+        column.pack_start(&renderer, NO_EXPAND_IN_LAYOUT);
+        gtk::prelude::CellLayoutExt::reorder(&column, &renderer, FIRST_CELL_POSITION);
+        column.add_attribute(&renderer, "text", modelColumnIndex);
+    }
+
+    pub fn prependIconCell(&self, viewColumnIndex: i32, modelColumnIndex: i32)
+    {
+        let renderer = gtk::CellRendererPixbuf::new();
+        let column = self.getColumn(viewColumnIndex);
+        column.pack_start(&renderer, NO_EXPAND_IN_LAYOUT);
+        gtk::prelude::CellLayoutExt::reorder(&column, &renderer, FIRST_CELL_POSITION);
+        column.add_attribute(&renderer, "icon-name", modelColumnIndex);
+    }
+
+    // The menu key and Shift+F10 ask for the same menu as a right click, but for the selected row.
+    pub fn enableMenuKey(&self, sender: Sender, source: Source)
+    {
+        self.widget.connect_popup_menu(move |_view| {
+            sender.send((source, Event::MenuRequested)).unwrap();
+            MENU_SHOWN
+        });
+    }
+
+    pub fn colorTextCells(&self, viewColumnIndex: i32, modelColumnIndex: i32)
+    {
+        let column = self.getColumn(viewColumnIndex);
+        for renderer in gtk::prelude::CellLayoutExt::cells(&column) {
+            if renderer.is::<gtk::CellRendererText>() {
+                column.add_attribute(&renderer, "foreground", modelColumnIndex);
+            }
+        }
+    }
+
 
     // private
 
@@ -91,12 +166,17 @@ impl TreeView
     fn setupColumn(&self, columnIndex: i32)
     {
         let renderer = gtk::CellRendererText::new();
-        let column = self.widget.column(columnIndex)
-            .unwrap_or_else(|| exit(&format!("Failed to get column with index {}", columnIndex)));
+        let column = self.getColumn(columnIndex);
         column.pack_start(&renderer, EXPAND_IN_LAYOUT);
         column.add_attribute(&renderer, "text", columnIndex);
     }
 
+    fn getColumn(&self, columnIndex: i32) -> gtk::TreeViewColumn
+    {
+        self.widget.column(columnIndex)
+            .unwrap_or_else(|| exit(&format!("Failed to get column with index {}", columnIndex)))
+    }
+
     fn connectWidget(&self, sender: Sender, source: Source)
     {
         self.connectRowActivated(sender.clone(), source);
@@ -112,11 +192,24 @@ impl TreeView
 
     fn connectButtonPressEvent(&self, sender: Sender, source: Source)
     {
-        self.widget.connect_button_press_event(move |_view, event| {
-            if event.button() == MOUSE_RIGHT_BUTTON {
-                sender.send((source, Event::RightClicked(event.clone()))).unwrap();
+        self.widget.connect_button_press_event(move |view, event| {
+            if event.button() != MOUSE_RIGHT_BUTTON {
+                return FORWARD_EVENT;
+            }
+            sender.send((source, Event::RightClicked(event.clone()))).unwrap();
+            // Right clicking a selected row keeps the selection, so that a context menu can act on all of it.
+            match isSelectedRowAtPosition(view, event.position()) {
+                true => CONSUME_EVENT,
+                false => FORWARD_EVENT
             }
-            FORWARD_EVENT
         });
     }
 }
+
+fn isSelectedRowAtPosition(view: &gtk::TreeView, (x, y): (f64, f64)) -> bool
+{
+    match view.path_at_pos(x.toI32(), y.toI32()) {
+        Some((Some(rowPath), ..)) => view.selection().path_is_selected(&rowPath),
+        _ => false
+    }
+}
@@ -1,10 +1,13 @@
+use crate::config::Config;
 use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
 use crate::file_change::{FileChange, FileChangeUpdate};
-use crate::file_changes_store::FileChangesStore;
-use crate::file_path::FilePathStr;
+use crate::file_changes_sort_order::FileChangesSortOrder;
+use crate::file_changes_store::{FileChangesStore, FileChangesStoreWidgetData};
+use crate::file_path::{FilePathStr, FilePathString};
 use crate::gui_element_provider::GuiElementProvider;
 use crate::ifile_changes_store::IFileChangesStore;
 use crate::repository::Repository;
+use crate::text_filter_widgets::WidgetData;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -22,8 +25,12 @@ impl IEventHandler for UnstagedChangesStore
     {
         match event {
             Event::AddedToUnstaged(fileChange)         => self.onAddedToUnstaged(fileChange),
+            Event::DirectoryTreeDisabled               => self.onDirectoryTreeToggled(false),
+            Event::DirectoryTreeEnabled                => self.onDirectoryTreeToggled(true),
+            Event::HeadChanged                         => self.onRefreshed(),
             Event::RemovedFromUnstaged(fileChange)     => self.onRemovedFromUnstaged(fileChange),
             Event::Refreshed                           => self.onRefreshed(),
+            Event::SortOrderChosen(sortOrder)          => self.onSortOrderChosen(*sortOrder),
             Event::UpdatedInUnstaged(fileChangeUpdate) => self.onUpdatedInUnstaged(fileChangeUpdate),
             _ => handleUnknown(source, event)
         }
@@ -32,16 +39,21 @@ impl IEventHandler for UnstagedChangesStore
 
 impl UnstagedChangesStore
 {
-    pub fn new(guiElementProvider: &GuiElementProvider, sender: Sender, repository: &Rc<RefCell<Repository>>)
+    pub fn new(
+        guiElementProvider: &GuiElementProvider,
+        sender: Sender,
+        repository: &Rc<RefCell<Repository>>,
+        config: &Config)
         -> Self
     {
         Self{
             store: FileChangesStore::new(
                 guiElementProvider,
-                "Unstaged changes store",
-                Source::UnstagedChangesStore,
+                &makeWidgetData(),
                 sender,
-                repository.borrow().getUnstagedChanges()),
+                repository.borrow().getUnstagedChanges(),
+                config.fileChangesPane.showsDirectoryTree,
+                config.fileChangesPane.sortOrder),
             repository: Rc::clone(repository)
         }
     }
@@ -64,6 +76,16 @@ impl UnstagedChangesStore
         self.store.remove(&fileChange.path);
     }
 
+    fn onDirectoryTreeToggled(&mut self, showsDirectoryTree: bool)
+    {
+        self.store.setShowsDirectoryTree(showsDirectoryTree);
+    }
+
+    fn onSortOrderChosen(&mut self, sortOrder: FileChangesSortOrder)
+    {
+        self.store.setSortOrder(sortOrder);
+    }
+
     fn onRefreshed(&mut self)
     {
         self.store.refresh(self.repository.borrow().getUnstagedChanges());
@@ -72,14 +94,24 @@ impl UnstagedChangesStore
 
 impl IFileChangesStore for UnstagedChangesStore
 {
-    fn getFileChange(&self, row: usize) -> &FileChange
+    fn getFileChanges(&self) -> &[FileChange]
+    {
+        self.store.getFileChanges()
+    }
+
+    fn getFileChange(&self, rowPath: &gtk::TreePath) -> Option<&FileChange>
+    {
+        self.store.getFileChange(rowPath)
+    }
+
+    fn getFilePath(&self, rowPath: &gtk::TreePath) -> Option<FilePathString>
     {
-        self.store.getFileChange(row)
+        self.store.getFilePath(rowPath)
     }
 
-    fn getFilePath(&self, row: usize) -> &FilePathStr
+    fn collectFileChanges(&self, rowPath: &gtk::TreePath) -> Vec<FileChange>
     {
-        self.store.getFilePath(row)
+        self.store.collectFileChanges(rowPath)
     }
 
     fn findFilePath(&self, path: &FilePathStr) -> Option<usize>
@@ -87,3 +119,11 @@ impl IFileChangesStore for UnstagedChangesStore
         self.store.findFilePath(path)
     }
 }
+
+fn makeWidgetData() -> FileChangesStoreWidgetData
+{
+    FileChangesStoreWidgetData{
+        store: WidgetData{name: "Unstaged changes store", source: Source::UnstagedChangesStore},
+        summaryLabelName: "Unstaged changes summary label"
+    }
+}
@@ -1,6 +1,8 @@
 use crate::event::{Event, Sender, Source};
-use crate::file_changes_view::{FileChangesView, OnRowActivatedAction};
+use crate::file_changes_drag_and_drop::DroppedItems;
+use crate::file_changes_view::{FileChangesView, MenuAction, MenuActionScope, OnRowActivatedAction};
 use crate::gui_element_provider::GuiElementProvider;
+use crate::repository::Repository;
 use crate::unstaged_changes_store::UnstagedChangesStore;
 
 use std::cell::RefCell;
@@ -12,19 +14,32 @@ pub(crate) type UnstagedChangesView = FileChangesView<UnstagedChangesStore>;
 pub(crate) fn makeUnstagedChangesView(
     guiElementProvider: &GuiElementProvider,
     sender: Sender,
-    store: Rc<RefCell<UnstagedChangesStore>>)
+    store: Rc<RefCell<UnstagedChangesStore>>,
+    repository: Rc<RefCell<Repository>>)
     -> UnstagedChangesView
 {
     let sender2 = sender.clone();
-    let onRowActivatedAction : OnRowActivatedAction = Box::new(move |fileChange|
-        sender.send((Source::UnstagedChangesView, Event::StageRequested(fileChange.clone()))).unwrap());
+    let onRowActivatedAction : OnRowActivatedAction = Box::new(move |fileChanges| {
+        let event = match fileChanges {
+            [fileChange] => Event::StageRequested(fileChange.clone()),
+            _ => Event::StageManyRequested(fileChanges.to_vec())
+        };
+        sender.send((Source::UnstagedChangesView, event)).unwrap();
+    });
 
-    FileChangesView::new(
+    let view = FileChangesView::new(
         guiElementProvider,
         "Unstaged changes view",
         store,
+        repository,
         onRowActivatedAction,
+        vec![
+            MenuAction{label: "Stage selected", scope: MenuActionScope::Selected},
+            MenuAction{label: "Stage all", scope: MenuActionScope::All},
+            MenuAction{label: "Stage all tracked", scope: MenuActionScope::AllTracked}],
         sender2,
         Source::UnstagedChangesView
-    )
+    );
+    view.setupDragAndDrop(DroppedItems::FileChanges, Event::UnstagePathsRequested);
+    view
 }
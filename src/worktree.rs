@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Worktree
+{
+    pub path: PathBuf,
+    pub branch: Option<String>,
+    pub isCurrent: bool,
+    pub isBare: bool,
+    pub isLocked: bool,
+    pub isPrunable: bool
+}
+
+#[derive(Debug)]
+pub(crate) struct NewWorktree
+{
+    pub branch: String,
+    pub path: PathBuf
+}
+
+// The main worktree is listed first, followed by the linked ones. Stale worktrees, whose directories are missing,
+// are prunable and their branches are unknown.
+pub(crate) fn collectWorktrees(gitRepo: &git2::Repository) -> Result<Vec<Worktree>, git2::Error>
+{
+    let currentWorkingDir = gitRepo.workdir().map(canonicalize);
+    let commonRepo = git2::Repository::open(findCommonGitDir(gitRepo))?;
+    let mainPath = commonRepo.workdir().unwrap_or_else(|| commonRepo.path()).to_owned();
+    let mut worktrees = vec![Worktree{
+        isCurrent: Some(canonicalize(&mainPath)) == currentWorkingDir,
+        path: mainPath,
+        branch: findBranch(&commonRepo),
+        isBare: commonRepo.is_bare(),
+        ..Worktree::default()
+    }];
+
+    for name in commonRepo.worktrees()?.iter().flatten() {
+        let linked = commonRepo.find_worktree(name)?;
+        let isPrunable = linked.validate().is_err();
+        let path = linked.path().to_owned();
+        worktrees.push(Worktree{
+            isCurrent: Some(canonicalize(&path)) == currentWorkingDir,
+            path,
+            branch: match isPrunable {
+                true => None,
+                false => git2::Repository::open_from_worktree(&linked).ok().and_then(|repo| findBranch(&repo))
+            },
+            isBare: false,
+            isLocked: matches!(linked.is_locked(), Ok(git2::WorktreeLockStatus::Locked(_))),
+            isPrunable
+        });
+    }
+    Ok(worktrees)
+}
+
+// The git directory of a linked worktree is <common dir>/worktrees/<name>, it names the common one in a file.
+pub(crate) fn findCommonGitDir(gitRepo: &git2::Repository) -> PathBuf
+{
+    let gitDir = gitRepo.path();
+    if !gitRepo.is_worktree() {
+        return gitDir.to_owned();
+    }
+    match std::fs::read_to_string(gitDir.join("commondir")) {
+        Ok(commonDir) => gitDir.join(commonDir.trim()),
+        Err(e) => {
+            eprintln!("Failed to read the common git directory of worktree {}: {}", gitDir.display(), e);
+            gitDir.to_owned()
+        }
+    }
+}
+
+fn findBranch(gitRepo: &git2::Repository) -> Option<String>
+{
+    let head = gitRepo.find_reference("HEAD").ok()?;
+    let target = head.symbolic_target()?;
+    target.strip_prefix("refs/heads/").map(str::to_owned)
+}
+
+fn canonicalize(path: &Path) -> PathBuf
+{
+    path.canonicalize().unwrap_or_else(|_| path.to_owned())
+}
@@ -0,0 +1,79 @@
+use crate::gui_element_provider::GuiElementProvider;
+use crate::worktree::NewWorktree;
+
+use gtk::EditableSignals as _;
+use gtk::glib::object::Cast as _;
+use gtk::prelude::BinExt as _;
+use gtk::prelude::ComboBoxTextExt as _;
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::GtkWindowExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+
+pub(crate) fn askForNewWorktree(branches: &[String], workingDir: &Path) -> Option<NewWorktree>
+{
+    let guiElementProvider = GuiElementProvider::new(include_str!("worktree_dialog.glade"));
+    let dialog = guiElementProvider.get::<gtk::Dialog>("dialog");
+    let branchComboBox = guiElementProvider.get::<gtk::ComboBoxText>("Worktree branch combo box");
+    let branchEntry = branchComboBox.child().unwrap().downcast::<gtk::Entry>().unwrap();
+    let pathEntry = guiElementProvider.get::<gtk::Entry>("Worktree path entry");
+    let createButton = guiElementProvider.get::<gtk::Button>("Create button");
+
+    for branch in branches {
+        branchComboBox.append_text(branch);
+    }
+    createButton.set_sensitive(false);
+
+    let workingDir = workingDir.to_owned();
+    let lastSuggestedPath = Rc::new(RefCell::new(String::new()));
+    let pathEntryClone = pathEntry.clone();
+    branchEntry.connect_changed(move |entry| {
+        // The suggested path follows the branch until the user types a different one.
+        let mut lastSuggestedPath = lastSuggestedPath.borrow_mut();
+        if pathEntryClone.text() == lastSuggestedPath.as_str() {
+            *lastSuggestedPath = suggestWorktreePath(&workingDir, &entry.text()).to_string_lossy().into();
+            pathEntryClone.set_text(&lastSuggestedPath);
+        }
+    });
+
+    let updateCreateButton = {
+        let branchEntry = branchEntry.clone();
+        let pathEntry = pathEntry.clone();
+        move || createButton.set_sensitive(isValidNewWorktree(&branchEntry.text(), &pathEntry.text()))
+    };
+    let updateCreateButton = Rc::new(updateCreateButton);
+    let updateCreateButtonClone = Rc::clone(&updateCreateButton);
+    branchEntry.connect_changed(move |_entry| updateCreateButtonClone());
+    pathEntry.connect_changed(move |_entry| updateCreateButton());
+
+    let response = dialog.run();
+    let newWorktree = NewWorktree{branch: branchEntry.text().into(), path: PathBuf::from(pathEntry.text().as_str())};
+    dialog.close();
+
+    match response {
+        gtk::ResponseType::Ok => Some(newWorktree),
+        _ => None
+    }
+}
+
+fn suggestWorktreePath(workingDir: &Path, branch: &str) -> PathBuf
+{
+    if branch.is_empty() {
+        return PathBuf::new();
+    }
+    let repositoryName = workingDir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let directoryName = format!("{}-{}", repositoryName, branch.replace('/', "-"));
+    workingDir.parent().unwrap_or(workingDir).join(directoryName)
+}
+
+fn isValidNewWorktree(branch: &str, path: &str) -> bool
+{
+    !branch.is_empty()
+        && git2::Reference::is_valid_name(&format!("refs/heads/{}", branch))
+        && !path.is_empty()
+        && !Path::new(path).exists()
+}
@@ -0,0 +1,131 @@
+use crate::app_launcher::launchApp;
+use crate::error_handling::showErrorDialog;
+use crate::event::{Event, handleUnknown, IEventHandler, Sender, Source};
+use crate::gui_element_provider::GuiElementProvider;
+use crate::repository::Repository;
+use crate::worktree::Worktree;
+use crate::worktree_dialog::askForNewWorktree;
+
+use gtk::gdk;
+use gtk::prelude::ButtonExt as _;
+use gtk::prelude::GtkMenuExt as _;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MenuShellExt as _;
+use gtk::prelude::WidgetExt as _;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+
+pub(crate) struct WorktreesButton
+{
+    widget: gtk::Button,
+    repository: Rc<RefCell<Repository>>,
+    sender: Sender
+}
+
+impl IEventHandler for WorktreesButton
+{
+    fn handle(&mut self, source: Source, event: &Event)
+    {
+        match event {
+            Event::Clicked => self.showWorktreesMenu(),
+            _ => handleUnknown(source, event)
+        }
+    }
+}
+
+impl WorktreesButton
+{
+    pub fn new(guiElementProvider: &GuiElementProvider, repository: Rc<RefCell<Repository>>, sender: Sender) -> Self
+    {
+        let widget = guiElementProvider.get::<gtk::Button>("Worktrees button");
+        let newSelf = Self{widget, repository, sender};
+        newSelf.connectWidget();
+        newSelf
+    }
+
+
+    // private
+
+    fn connectWidget(&self)
+    {
+        let sender = self.sender.clone();
+        self.widget.connect_clicked(move |_button| {
+            sender.send((Source::WorktreesButton, Event::Clicked)).unwrap();
+        });
+    }
+
+    fn showWorktreesMenu(&self)
+    {
+        let worktrees = self.repository.borrow().collectWorktrees();
+        let menu = gtk::Menu::new();
+        for worktree in &worktrees {
+            menu.append(&self.makeSwitchMenuItem(worktree));
+        }
+
+        menu.append(&gtk::SeparatorMenuItem::new());
+        menu.append(&self.makeCreateMenuItem());
+        let pruneMenuItem = gtk::MenuItem::with_label("Prune stale worktrees");
+        pruneMenuItem.set_sensitive(worktrees.iter().any(|worktree| worktree.isPrunable));
+        let sender = self.sender.clone();
+        pruneMenuItem.connect_activate(move |_item| {
+            sender.send((Source::WorktreesButton, Event::PruneWorktreesRequested)).unwrap();
+        });
+        menu.append(&pruneMenuItem);
+
+        menu.show_all();
+        menu.popup_at_widget(&self.widget, gdk::Gravity::SouthWest, gdk::Gravity::NorthWest, None);
+    }
+
+    fn makeSwitchMenuItem(&self, worktree: &Worktree) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label(&formatWorktree(worktree));
+        menuItem.set_sensitive(!worktree.isCurrent && !worktree.isBare && !worktree.isPrunable);
+        let path = worktree.path.clone();
+        let sender = self.sender.clone();
+        menuItem.connect_activate(move |_item| {
+            match launchApp(&path) {
+                Ok(()) => sender.send((Source::WorktreesButton, Event::QuitRequested)).unwrap(),
+                Err(e) => showErrorDialog(&format!("Failed to switch to worktree {}: {}", path.display(), e))
+            }
+        });
+        menuItem
+    }
+
+    fn makeCreateMenuItem(&self) -> gtk::MenuItem
+    {
+        let menuItem = gtk::MenuItem::with_label("Create worktree...");
+        let repository = Rc::clone(&self.repository);
+        let sender = self.sender.clone();
+        menuItem.connect_activate(move |_item| {
+            let (branches, workingDir) = {
+                let repository = repository.borrow();
+                (repository.collectLocalBranchNames(), repository.getWorkingDir().to_owned())
+            };
+            if let Some(newWorktree) = askForNewWorktree(&branches, &workingDir) {
+                sender.send((Source::WorktreesButton, Event::CreateWorktreeRequested(newWorktree))).unwrap();
+            }
+        });
+        menuItem
+    }
+}
+
+fn formatWorktree(worktree: &Worktree) -> String
+{
+    let head = match &worktree.branch {
+        Some(branch) => branch.as_str(),
+        None if worktree.isBare => "bare",
+        None => "detached HEAD"
+    };
+    let mut text = format!("{} ({})", worktree.path.display(), head);
+    if worktree.isCurrent {
+        text.push_str(" - current");
+    }
+    if worktree.isLocked {
+        text.push_str(" - locked");
+    }
+    if worktree.isPrunable {
+        text.push_str(" - stale");
+    }
+    text
+}
@@ -0,0 +1,34 @@
+use crate::common::gui_assertions::assertCommitMessageViewTextIs;
+use crate::common::gui_interactions::{selectCommitAmendCheckbox, setCommitMessage, unselectCommitAmendCheckbox};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn carryOnlyRemainingTrailersBetweenAmendModes()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    makeCommit("Initial commit\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage(&format!("Second commit\n\n{}\n", SIGNED_OFF_BY), &gui);
+    selectCommitAmendCheckbox(&gui);
+    assertCommitMessageViewTextIs(&format!("Initial commit\n\n{}\n", SIGNED_OFF_BY), &gui);
+
+    setCommitMessage(&format!("Initial commit\n\n{}\n{}\n", SIGNED_OFF_BY, CO_AUTHORED_BY), &gui);
+    unselectCommitAmendCheckbox(&gui);
+    assertCommitMessageViewTextIs(&format!("Second commit\n\n{}\n{}\n", SIGNED_OFF_BY, CO_AUTHORED_BY), &gui);
+
+    setCommitMessage("Second commit\n", &gui);
+    selectCommitAmendCheckbox(&gui);
+    assertCommitMessageViewTextIs("Initial commit\n", &gui);
+}
+}
+
+const SIGNED_OFF_BY: &str = "Signed-off-by: John Smith <john.smith@example.com>";
+const CO_AUTHORED_BY: &str = "Co-authored-by: Jane Doe <jane.doe@example.com>";
@@ -2,11 +2,14 @@ mod allow_enabling_amend_mode_after_creating_first_commit;
 mod allow_enabling_amend_mode_when_commit_is_found;
 mod amend_commit_by_changing_file;
 mod amend_commit_by_changing_message;
+mod carry_only_remaining_trailers_between_amend_modes;
 mod disabling_commit_amend_should_restore_stashed_commit_message;
 mod enable_amend_mode_given_commit_with_modified_file;
 mod enable_amend_mode_given_commit_with_new_file;
 mod enable_amend_mode_given_selected_staged_modified_file;
 mod forbid_enabling_amend_mode_when_no_commit_is_found;
+mod pass_amended_commit_as_source_to_prepare_commit_msg_hook;
+mod reset_author_when_amending_commit;
 mod stage_new_file_for_amend_which_in_normal_mode_was_staged_for_removal;
 mod unstage_modified_file_in_amend_mode;
-mod unstage_new_file_in_amend_mode;
\ No newline at end of file
+mod unstage_new_file_in_amend_mode;
@@ -0,0 +1,31 @@
+use crate::common::gui_interactions::{
+    clickCommitButton,
+    selectCommitAmendCheckbox,
+    setCommitMessage,
+    waitForHeadCommitSummary};
+use crate::common::setup::{makeCommit, makeGui, makeHook, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn passAmendedCommitAsSourceToPrepareCommitMsgHook()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    makeHook("prepare-commit-msg", "#!/bin/sh\necho \"$2 $3\" > .git/prepare-commit-msg-arguments\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+    selectCommitAmendCheckbox(&gui);
+    setCommitMessage("Amended commit", &gui);
+
+    clickCommitButton(&gui);
+    waitForHeadCommitSummary("Amended commit", &repositoryDir);
+
+    let hookArguments = std::fs::read_to_string(repositoryDir.join(".git/prepare-commit-msg-arguments")).unwrap();
+    assert_eq!("commit HEAD\n", hookArguments);
+}
+}
@@ -0,0 +1,30 @@
+use crate::common::gui_interactions::{clickCommitButton, selectCommitAmendCheckbox, setCommitAuthorOptions};
+use crate::common::repository_assertions::{assertRepositoryCommitSummariesAre, assertRepositoryHeadAuthorIs};
+use crate::common::setup::{makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+
+rusty_fork_test! {
+#[test]
+fn resetAuthorWhenAmendingCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    let status = Command::new("git")
+        .args(["commit", "-m", "Commit of another author", "--author", "Jane Doe <jane.doe@example.com>"])
+        .current_dir(&repositoryDir).stdout(Stdio::null()).status().unwrap();
+    assert!(status.success(), "Failed to create a commit of another author, command finished with {}", status);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitAuthorOptions("", "", true, &gui);
+    selectCommitAmendCheckbox(&gui);
+    clickCommitButton(&gui);
+
+    assertRepositoryCommitSummariesAre(&["Commit of another author"], &repositoryDir);
+    assertRepositoryHeadAuthorIs("John Smith <john.smith@example.com>", &repositoryDir);
+}
+}
@@ -0,0 +1,49 @@
+use crate::common::gui_interactions::{acceptNextDialog, activateCommitLogMenuItem, waitForCommitLogRowCount};
+use crate::common::repository_assertions::{assertRepositoryCommitSummariesAre, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{
+    getShortHeadCommitId,
+    makeCommit,
+    makeGui,
+    makeNewStagedFile,
+    modifyFile,
+    setupTest,
+    stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn autosquashFixupCommitsMatchingSummaryPrefixAndCommitId()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let baseFilePath = PathBuf::from("baseFile");
+    let firstFilePath = PathBuf::from("firstFile");
+    let secondFilePath = PathBuf::from("secondFile");
+    makeNewStagedFile(&baseFilePath, "base\n", &repositoryDir);
+    makeCommit("Base commit", &repositoryDir);
+    makeNewStagedFile(&firstFilePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit with a long summary", &repositoryDir);
+    makeNewStagedFile(&secondFilePath, "line 1\n", &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+    let secondCommitId = getShortHeadCommitId(&repositoryDir);
+    modifyFile(&firstFilePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&firstFilePath, &repositoryDir);
+    makeCommit("fixup! First commit", &repositoryDir);
+    modifyFile(&secondFilePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&secondFilePath, &repositoryDir);
+    makeCommit(&format!("fixup! {}", secondCommitId), &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    acceptNextDialog();
+    activateCommitLogMenuItem(0, &["Autosquash fixup and squash commits"], &gui);
+    waitForCommitLogRowCount(3, &gui);
+
+    assertRepositoryCommitSummariesAre(
+        &["Second commit", "First commit with a long summary", "Base commit"], &repositoryDir);
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+}
+}
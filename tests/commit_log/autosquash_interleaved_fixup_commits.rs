@@ -0,0 +1,40 @@
+use crate::common::gui_interactions::{acceptNextDialog, activateCommitLogMenuItem, waitForCommitLogRowCount};
+use crate::common::repository_assertions::{assertRepositoryCommitSummariesAre, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn autosquashInterleavedFixupCommits()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let baseFilePath = PathBuf::from("baseFile");
+    let firstFilePath = PathBuf::from("firstFile");
+    let secondFilePath = PathBuf::from("secondFile");
+    makeNewStagedFile(&baseFilePath, "base\n", &repositoryDir);
+    makeCommit("Base commit", &repositoryDir);
+    makeNewStagedFile(&firstFilePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    makeNewStagedFile(&secondFilePath, "line 1\n", &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+    modifyFile(&firstFilePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&firstFilePath, &repositoryDir);
+    makeCommit("fixup! First commit", &repositoryDir);
+    modifyFile(&secondFilePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&secondFilePath, &repositoryDir);
+    makeCommit("fixup! Second commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    acceptNextDialog();
+    activateCommitLogMenuItem(0, &["Autosquash fixup and squash commits"], &gui);
+    waitForCommitLogRowCount(3, &gui);
+
+    assertRepositoryCommitSummariesAre(&["Second commit", "First commit", "Base commit"], &repositoryDir);
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+}
+}
@@ -0,0 +1,39 @@
+use crate::common::gui_assertions::{assertHeadLabelIs, assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::activateCommitLogMenuItem;
+use crate::common::repository_assertions::{assertRepositoryCommitSummariesAre, assertRepositoryHeadIs};
+use crate::common::setup::{
+    getShortHeadCommitId,
+    makeCommit,
+    makeGui,
+    makeNewStagedFile,
+    modifyFile,
+    setupTest,
+    stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn checkoutPreviousCommitAsDetachedHead()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    activateCommitLogMenuItem(1, &["Checkout (detached HEAD)"], &gui);
+
+    assertRepositoryHeadIs("HEAD", &repositoryDir);
+    assertRepositoryCommitSummariesAre(&["First commit"], &repositoryDir);
+    assertHeadLabelIs(&format!("Detached HEAD at {}", getShortHeadCommitId(&repositoryDir)), &gui);
+    assertUnstagedChangesViewIsEmpty(&gui);
+}
+}
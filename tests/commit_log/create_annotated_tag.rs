@@ -0,0 +1,34 @@
+use crate::common::gui_interactions::{activateCommitLogMenuItem, fillAndAcceptNextDialog};
+use crate::common::repository_assertions::assertRepositoryTagsAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::ToggleButtonExt as _;
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn createAnnotatedTag()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("fileName"), "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    fillAndAcceptNextDialog(|dialog| {
+        gtk_test::find_child_by_name::<gtk::Entry, _>(dialog, "Tag name entry").unwrap().set_text("v1.0");
+        gtk_test::find_child_by_name::<gtk::CheckButton, _>(dialog, "Tag annotated checkbox").unwrap().set_active(true);
+        gtk_test::find_child_by_name::<gtk::TextView, _>(dialog, "Tag message view").unwrap()
+            .buffer().unwrap().set_text("Release 1.0");
+    });
+    activateCommitLogMenuItem(0, &["Create tag..."], &gui);
+
+    assertRepositoryTagsAre(&["v1.0 tag"], &repositoryDir);
+}
+}
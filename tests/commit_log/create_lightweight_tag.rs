@@ -0,0 +1,28 @@
+use crate::common::gui_interactions::{activateCommitLogMenuItem, fillAndAcceptNextDialog};
+use crate::common::repository_assertions::assertRepositoryTagsAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use gtk::prelude::EntryExt as _;
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn createLightweightTag()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("fileName"), "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    fillAndAcceptNextDialog(|dialog| {
+        gtk_test::find_child_by_name::<gtk::Entry, _>(dialog, "Tag name entry").unwrap().set_text("v1.0");
+    });
+    activateCommitLogMenuItem(0, &["Create tag..."], &gui);
+
+    assertRepositoryTagsAre(&["v1.0 commit"], &repositoryDir);
+}
+}
@@ -0,0 +1,27 @@
+use crate::common::gui_interactions::{acceptNextDialog, activateCommitLogMenuItem};
+use crate::common::repository_assertions::assertRepositoryTagsAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, makeTag, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn deleteTag()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("fileName"), "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    makeTag("v1.0", &repositoryDir);
+    makeTag("v1.1", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    acceptNextDialog();
+    activateCommitLogMenuItem(0, &["Delete tag v1.0"], &gui);
+
+    assertRepositoryTagsAre(&["v1.1 commit"], &repositoryDir);
+}
+}
@@ -0,0 +1,32 @@
+use crate::common::gui_interactions::{acceptNextDialog, activateCommitLogMenuItem};
+use crate::common::repository_assertions::{assertRepositoryCommitsChangeFiles, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn dropPreviousCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("firstFile"), "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    makeNewStagedFile(&PathBuf::from("secondFile"), "line 1\n", &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+    makeNewStagedFile(&PathBuf::from("thirdFile"), "line 1\n", &repositoryDir);
+    makeCommit("Third commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    acceptNextDialog();
+    activateCommitLogMenuItem(1, &["Drop"], &gui);
+
+    assertRepositoryCommitsChangeFiles(
+        &[("Third commit", &["thirdFile"]), ("First commit", &["firstFile"])], &repositoryDir);
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    assert!(!repositoryDir.join("secondFile").exists());
+}
+}
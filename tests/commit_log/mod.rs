@@ -0,0 +1,19 @@
+mod autosquash_fixup_commits_matching_summary_prefix_and_commit_id;
+mod autosquash_interleaved_fixup_commits;
+mod checkout_previous_commit_as_detached_head;
+mod create_annotated_tag;
+mod create_lightweight_tag;
+mod delete_tag;
+mod drop_previous_commit;
+mod open_diff_search_of_shown_page_with_shortcut;
+mod refuse_autosquashing_fixup_commit_without_target;
+mod reset_current_branch_hard_to_previous_commit;
+mod reset_current_branch_softly_to_previous_commit;
+mod reword_previous_commit;
+mod search_diff_of_selected_commit;
+mod search_diff_of_selected_commit_with_invalid_regex;
+mod show_hex_diff_of_selected_commit;
+mod show_image_change_of_selected_commit;
+mod show_new_and_amended_commits;
+mod show_verification_of_selected_commit_signature;
+mod split_commit_into_three_commits;
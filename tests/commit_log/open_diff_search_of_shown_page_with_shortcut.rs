@@ -0,0 +1,34 @@
+use crate::common::gui_assertions::{
+    assertCommitDiffSearchIsClosed,
+    assertCommitDiffSearchIsOpen,
+    assertDiffSearchIsClosed,
+    assertDiffSearchIsOpen};
+use crate::common::gui_interactions::{pressSearchShortcut, showCommitLog};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn openDiffSearchOfShownPageWithShortcut()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("fileName"), "line 1\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    assertDiffSearchIsClosed(&gui);
+    assertCommitDiffSearchIsClosed(&gui);
+
+    pressSearchShortcut(&gui);
+    assertDiffSearchIsOpen(&gui);
+    assertCommitDiffSearchIsClosed(&gui);
+
+    showCommitLog(&gui);
+    pressSearchShortcut(&gui);
+    assertCommitDiffSearchIsOpen(&gui);
+}
+}
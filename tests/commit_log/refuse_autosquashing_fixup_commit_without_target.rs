@@ -0,0 +1,32 @@
+use crate::common::gui_interactions::{acceptNextMessageDialog, activateCommitLogMenuItem};
+use crate::common::repository_assertions::assertRepositoryCommitSummariesAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn refuseAutosquashingFixupCommitWithoutTarget()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("fixup! Missing commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    let dialogText = acceptNextMessageDialog();
+    activateCommitLogMenuItem(0, &["Autosquash fixup and squash commits"], &gui);
+
+    assert_eq!(
+        r#"Failed to autosquash commits, no commit matching "Missing commit" was found on the current branch."#,
+        *dialogText.borrow());
+    assertRepositoryCommitSummariesAre(&["fixup! Missing commit", "First commit"], &repositoryDir);
+}
+}
@@ -0,0 +1,34 @@
+use crate::common::gui_assertions::{assertStagedChangesViewIsEmpty, assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::{acceptNextDialog, activateCommitLogMenuItem};
+use crate::common::repository_assertions::{assertRepositoryCommitSummariesAre, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn resetCurrentBranchHardToPreviousCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\nline 3\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    acceptNextDialog();
+    activateCommitLogMenuItem(1, &["Reset current branch here", "Hard"], &gui);
+
+    assertRepositoryCommitSummariesAre(&["First commit"], &repositoryDir);
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
@@ -0,0 +1,34 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewContains, assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::{acceptNextDialog, activateCommitLogMenuItem};
+use crate::common::repository_assertions::{assertRepositoryCommitSummariesAre, assertRepositoryHeadIs};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn resetCurrentBranchSoftlyToPreviousCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    acceptNextDialog();
+    activateCommitLogMenuItem(1, &["Reset current branch here", "Soft"], &gui);
+
+    assertRepositoryCommitSummariesAre(&["First commit"], &repositoryDir);
+    assertRepositoryHeadIs("main", &repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewContains(&[makeFileChange("Modified", &filePath)], &gui);
+}
+}
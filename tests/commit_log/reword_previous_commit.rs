@@ -0,0 +1,43 @@
+use crate::common::gui_interactions::{activateCommitLogMenuItem, fillAndAcceptNextDialog};
+use crate::common::repository_assertions::{
+    assertRepositoryCommitSummariesAre,
+    assertRepositoryHeadAuthorIs,
+    assertRepositoryHeadCommitterIs,
+    assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setConfigValue, setupTest, stageFile};
+
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextViewExt as _;
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn rewordPreviousCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Second commit", &repositoryDir);
+    setConfigValue("user.name", "Jane Doe", &repositoryDir);
+    setConfigValue("user.email", "jane.doe@example.com", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    fillAndAcceptNextDialog(|dialog| {
+        let messageView = gtk_test::find_child_by_name::<gtk::TextView, _>(dialog, "Commit message view").unwrap();
+        messageView.buffer().unwrap().set_text("Reworded first commit\n");
+    });
+    activateCommitLogMenuItem(1, &["Reword..."], &gui);
+
+    assertRepositoryCommitSummariesAre(&["Second commit", "Reworded first commit"], &repositoryDir);
+    assertRepositoryHeadAuthorIs("John Smith <john.smith@example.com>", &repositoryDir);
+    assertRepositoryHeadCommitterIs("Jane Doe <jane.doe@example.com>", &repositoryDir);
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+}
+}
@@ -0,0 +1,52 @@
+use crate::common::gui_assertions::assertCommitDiffSearchMatchCountIs;
+use crate::common::gui_interactions::{
+    searchInCommitDiff,
+    selectCommitInRow,
+    selectNextCommitDiffSearchMatch,
+    selectPreviousCommitDiffSearchMatch};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn searchDiffOfSelectedCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fruits");
+    makeNewStagedFile(&filePath, "apple 1\napple 2\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "apple 1\nnew apple 2\napple 3\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Change fruits", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    selectCommitInRow(0, &gui);
+    assertCommitDiffSearchMatchCountIs("", &gui);
+
+    searchInCommitDiff("APPLE", &gui);
+    assertCommitDiffSearchMatchCountIs("1 of 4", &gui);
+
+    selectPreviousCommitDiffSearchMatch(&gui);
+    assertCommitDiffSearchMatchCountIs("4 of 4", &gui);
+
+    selectPreviousCommitDiffSearchMatch(&gui);
+    assertCommitDiffSearchMatchCountIs("3 of 4", &gui);
+
+    selectNextCommitDiffSearchMatch(&gui);
+    assertCommitDiffSearchMatchCountIs("4 of 4", &gui);
+
+    selectNextCommitDiffSearchMatch(&gui);
+    assertCommitDiffSearchMatchCountIs("1 of 4", &gui);
+
+    selectCommitInRow(1, &gui);
+    assertCommitDiffSearchMatchCountIs("2 matches", &gui);
+
+    selectNextCommitDiffSearchMatch(&gui);
+    assertCommitDiffSearchMatchCountIs("1 of 2", &gui);
+}
+}
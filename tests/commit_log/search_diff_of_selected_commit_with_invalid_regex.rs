@@ -0,0 +1,40 @@
+use crate::common::gui_assertions::{
+    assertCommitDiffSearchInputIsInvalid,
+    assertCommitDiffSearchInputIsValid,
+    assertCommitDiffSearchMatchCountIs};
+use crate::common::gui_interactions::{searchInCommitDiff, selectCommitDiffSearchRegexButton, selectCommitInRow};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn searchDiffOfSelectedCommitWithInvalidRegex()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fruits");
+    makeNewStagedFile(&filePath, "apple 1\napple 2\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "apple 1\nnew apple 2\napple 3\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Change fruits", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    selectCommitInRow(0, &gui);
+
+    searchInCommitDiff("apple [12", &gui);
+    assertCommitDiffSearchInputIsValid(&gui);
+    assertCommitDiffSearchMatchCountIs("No matches", &gui);
+
+    selectCommitDiffSearchRegexButton(&gui);
+    assertCommitDiffSearchInputIsInvalid(&gui);
+    assertCommitDiffSearchMatchCountIs("", &gui);
+
+    searchInCommitDiff("apple [12]", &gui);
+    assertCommitDiffSearchInputIsValid(&gui);
+    assertCommitDiffSearchMatchCountIs("1 of 3", &gui);
+}
+}
@@ -0,0 +1,38 @@
+use crate::common::gui_assertions::{assertCommitDiffViewEndsWith, assertCommitDiffViewTaggedTextsAre};
+use crate::common::gui_interactions::selectCommitInRow;
+use crate::common::setup::{makeCommit, makeGui, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn showHexDiffOfSelectedCommit()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("data");
+    std::fs::write(repositoryDir.join(&filePath), b"\x00abcdefgh").unwrap();
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Add data", &repositoryDir);
+    std::fs::write(repositoryDir.join(&filePath), b"\x00abXYefgh").unwrap();
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Change data", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    selectCommitInRow(0, &gui);
+    assertCommitDiffViewEndsWith(
+"Binary files a/data and b/data differ
+binary file
+old: 9 bytes
+new: 9 bytes
+size delta: 0 bytes
+-00000000  00 61 62 63 64 65 66 67 68                       |.abcdefgh|
++00000000  00 61 62 58 59 65 66 67 68                       |.abXYefgh|
+", &gui);
+    assertCommitDiffViewTaggedTextsAre("red", &["63 64", "cd"], &gui);
+    assertCommitDiffViewTaggedTextsAre("green", &["58 59", "XY"], &gui);
+}
+}
@@ -0,0 +1,37 @@
+use crate::common::gui_assertions::assertCommitLogSummaryInRowIs;
+use crate::common::gui_interactions::{
+    clickCommitButton,
+    selectCommitAmendCheckbox,
+    setCommitMessage,
+    waitForCommitLogRowCount};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn showNewAndAmendedCommits()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file1"), "some file content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    makeNewStagedFile(&PathBuf::from("file2"), "some file content\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("Second commit", &gui);
+    clickCommitButton(&gui);
+    waitForCommitLogRowCount(2, &gui);
+    assertCommitLogSummaryInRowIs(0, "Second commit", &gui);
+    assertCommitLogSummaryInRowIs(1, "Initial commit", &gui);
+
+    selectCommitAmendCheckbox(&gui);
+    setCommitMessage("Amended second commit", &gui);
+    clickCommitButton(&gui);
+    waitForCommitLogRowCount(2, &gui);
+    assertCommitLogSummaryInRowIs(0, "Amended second commit", &gui);
+    assertCommitLogSummaryInRowIs(1, "Initial commit", &gui);
+}
+}
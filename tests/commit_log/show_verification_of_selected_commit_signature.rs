@@ -0,0 +1,52 @@
+use crate::common::gui_assertions::assertCommitLogSignatureInRowIs;
+use crate::common::gui_interactions::{
+    clickCommitButton,
+    selectCommitInRow,
+    setCommitMessage,
+    waitForCommitLogSignatureInRow};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setConfigValue, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+
+rusty_fork_test! {
+#[test]
+fn showVerificationOfSelectedCommitSignature()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file1"), "some file content\n", &repositoryDir);
+    makeCommit("Unsigned commit", &repositoryDir);
+
+    let keyPath = repositoryDir.join(".git").join("signing_key");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-f", keyPath.to_str().unwrap()])
+        .stdout(Stdio::null()).status().unwrap();
+    assert!(status.success(), "Failed to generate an SSH key, command finished with {}", status);
+    let publicKey = std::fs::read_to_string(keyPath.with_extension("pub")).unwrap();
+    let allowedSignersPath = repositoryDir.join(".git").join("allowed_signers");
+    std::fs::write(&allowedSignersPath, format!("john.smith@example.com {}", publicKey)).unwrap();
+    setConfigValue("commit.gpgsign", "true", &repositoryDir);
+    setConfigValue("gpg.format", "ssh", &repositoryDir);
+    setConfigValue("gpg.ssh.allowedSignersFile", allowedSignersPath.to_str().unwrap(), &repositoryDir);
+    setConfigValue("user.signingkey", keyPath.to_str().unwrap(), &repositoryDir);
+    makeNewStagedFile(&PathBuf::from("file2"), "some file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    setCommitMessage("Signed commit", &gui);
+    clickCommitButton(&gui);
+
+    assertCommitLogSignatureInRowIs(0, "SSH", &gui);
+    assertCommitLogSignatureInRowIs(1, "", &gui);
+
+    selectCommitInRow(0, &gui);
+    waitForCommitLogSignatureInRow(0, "SSH verified", &gui);
+    assertCommitLogSignatureInRowIs(1, "", &gui);
+
+    selectCommitInRow(1, &gui);
+    assertCommitLogSignatureInRowIs(0, "SSH verified", &gui);
+    assertCommitLogSignatureInRowIs(1, "", &gui);
+}
+}
@@ -0,0 +1,55 @@
+use crate::common::gui_interactions::{activateCommitLogMenuItem, fillAndAcceptNextDialog};
+use crate::common::repository_assertions::{assertRepositoryCommitsChangeFiles, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use gtk::glib::Cast as _;
+use gtk::prelude::GtkListStoreExtManual as _;
+use gtk::prelude::SpinButtonExt as _;
+use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextViewExt as _;
+use gtk::prelude::TreeModelExt as _;
+use gtk::prelude::TreeViewExt as _;
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn splitCommitIntoThreeCommits()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("baseFile"), "line 1\n", &repositoryDir);
+    makeCommit("Base commit", &repositoryDir);
+    for fileName in ["firstFile", "secondFile", "thirdFile"] {
+        makeNewStagedFile(&PathBuf::from(fileName), "line 1\n", &repositoryDir);
+    }
+    makeCommit("Add three files", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    fillAndAcceptNextDialog(|dialog| {
+        gtk_test::find_child_by_name::<gtk::SpinButton, _>(dialog, "Part count spin button").unwrap().set_value(3.0);
+        let hunksView = gtk_test::find_child_by_name::<gtk::TreeView, _>(dialog, "Hunks view").unwrap();
+        let store = hunksView.model().unwrap().downcast::<gtk::ListStore>().unwrap();
+        for (row, part) in [1_u32, 2, 3].into_iter().enumerate() {
+            let iter = store.iter_nth_child(None, row.try_into().unwrap()).unwrap();
+            store.set_value(&iter, 0, &part.into());
+        }
+        for (part, message) in [(1, "Add first file\n"), (2, "Add second file\n"), (3, "Add third file\n")] {
+            let viewName = format!("Commit {} message view", part);
+            let messageView = gtk_test::find_child_by_name::<gtk::TextView, _>(dialog, &viewName).unwrap();
+            messageView.buffer().unwrap().set_text(message);
+        }
+    });
+    activateCommitLogMenuItem(0, &["Split..."], &gui);
+
+    assertRepositoryCommitsChangeFiles(
+        &[("Add third file", &["thirdFile"]),
+          ("Add second file", &["secondFile"]),
+          ("Add first file", &["firstFile"]),
+          ("Base commit", &["baseFile"])],
+        &repositoryDir);
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+}
+}
@@ -0,0 +1,29 @@
+use crate::common::gui_assertions::assertMisspelledWordsAre;
+use crate::common::gui_interactions::{activateUnstagedChangeInRow, setCommitMessage};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupSpellChecking, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn acceptWordsFromStagedChangesWhenCheckingSpelling()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let _configDir = setupSpellChecking(DICTIONARY, AFFIXES);
+    makeNewUnstagedFile(&PathBuf::from("widgets.rs"), "fn frobnicate() {}\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("Frobnicate the widgets", &gui);
+    assertMisspelledWordsAre(&["Frobnicate", "widgets"], &gui);
+
+    activateUnstagedChangeInRow(0, &gui);
+
+    assertMisspelledWordsAre(&[], &gui);
+}
+}
+
+const DICTIONARY: &str = "1\nthe\n";
+const AFFIXES: &str = "SET UTF-8\n";
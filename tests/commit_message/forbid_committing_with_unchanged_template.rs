@@ -0,0 +1,32 @@
+use crate::common::gui_assertions::{
+    assertCommitButtonIsDisabled,
+    assertCommitButtonIsEnabled,
+    assertCommitButtonTooltipIs,
+    assertCommitButtonTooltipIsEmpty};
+use crate::common::gui_interactions::setCommitMessage;
+use crate::common::setup::{makeGui, makeNewStagedFile, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn forbidCommittingWithUnchangedTemplate()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewUnstagedFile(&PathBuf::from(".gitmessage"), "Summary\n\nDetails\n", &repositoryDir);
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertCommitButtonIsDisabled(&gui);
+    assertCommitButtonTooltipIs("The commit message is the unchanged template.", &gui);
+
+    setCommitMessage("Summary\n\nChanged details\n", &gui);
+
+    assertCommitButtonIsEnabled(&gui);
+    assertCommitButtonTooltipIsEmpty(&gui);
+}
+}
@@ -0,0 +1,26 @@
+use crate::common::gui_assertions::assertMisspelledWordsAre;
+use crate::common::gui_interactions::setCommitMessage;
+use crate::common::setup::{makeGui, makeNewStagedFile, setupSpellChecking, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn markMisspelledWordsInCommitMessage()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let _configDir = setupSpellChecking(DICTIONARY, AFFIXES);
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("Fixes the speling of words\n\nFix teh spelling, HTTP and v2", &gui);
+
+    assertMisspelledWordsAre(&["speling", "teh"], &gui);
+}
+}
+
+const DICTIONARY: &str = "5\nand\nfix/S\nof\nspelling\nthe\nword/S\n";
+const AFFIXES: &str = "SET UTF-8\nSFX S Y 2\nSFX S 0 es x\nSFX S 0 s [^x]\n";
@@ -0,0 +1,14 @@
+mod accept_words_from_staged_changes_when_checking_spelling;
+mod forbid_committing_with_unchanged_template;
+mod mark_misspelled_words_in_commit_message;
+mod offer_recent_commit_messages_of_user;
+mod prefill_commit_message_from_configured_template;
+mod prefill_commit_message_from_repository_template;
+mod restore_amended_commit_message_draft_on_startup;
+mod restore_commit_message_draft_on_startup;
+mod save_commit_message_draft_shortly_after_typing;
+mod save_commit_message_draft_when_amend_mode_is_toggled;
+mod save_commit_message_draft_when_focus_is_lost;
+mod show_commit_message_lint_warnings_in_commit_button_tooltip;
+mod show_invalid_required_commit_message_pattern_in_commit_button_tooltip;
+mod skip_lint_rules_on_comment_and_trailer_lines;
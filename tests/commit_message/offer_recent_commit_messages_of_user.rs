@@ -0,0 +1,32 @@
+use crate::common::gui_assertions::assertRecentCommitMessagesAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+
+rusty_fork_test! {
+#[test]
+fn offerRecentCommitMessagesOfUser()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("file");
+    makeNewStagedFile(&filePath, "line 1\n", &repositoryDir);
+    makeCommit("First commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    let status = Command::new("git")
+        .args(["commit", "-m", "Commit of another author", "--author", "Jane Doe <jane.doe@example.com>"])
+        .current_dir(&repositoryDir).stdout(Stdio::null()).status().unwrap();
+    assert!(status.success(), "Failed to create a commit of another author, command finished with {}", status);
+    modifyFile(&filePath, "line 1\nline 2\nline 3\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Third commit\n\nWith a body", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertRecentCommitMessagesAre(&["Third commit", "First commit"], &gui);
+}
+}
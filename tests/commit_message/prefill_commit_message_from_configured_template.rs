@@ -0,0 +1,23 @@
+use crate::common::gui_assertions::assertCommitMessageViewTextIs;
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setConfigValue, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn prefillCommitMessageFromConfiguredTemplate()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let templatePath = repositoryDir.join(".git").join("commit_template");
+    std::fs::write(&templatePath, "[TICKET-] \n\n# Describe why the change is needed.\nReviewed-by: \n").unwrap();
+    setConfigValue("commit.template", templatePath.to_str().unwrap(), &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertCommitMessageViewTextIs("[TICKET-] \n\nReviewed-by:\n", &gui);
+}
+}
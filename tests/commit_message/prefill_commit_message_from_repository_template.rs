@@ -0,0 +1,20 @@
+use crate::common::gui_assertions::assertCommitMessageViewTextIs;
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn prefillCommitMessageFromRepositoryTemplate()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewUnstagedFile(&PathBuf::from(".gitmessage"), "Summary\n\nDetails\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertCommitMessageViewTextIs("Summary\n\nDetails\n", &gui);
+}
+}
@@ -0,0 +1,30 @@
+use crate::common::gui_assertions::{assertCommitAmendCheckboxIsSelected, assertCommitMessageViewTextIs};
+use crate::common::gui_interactions::unselectCommitAmendCheckbox;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn restoreAmendedCommitMessageDraftOnStartup()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    std::fs::write(
+        repositoryDir.join(".git").join("rusty-git-gui-draft.toml"),
+        "message = \"Own message\"\namendedMessage = \"Reworded commit\\n\"\n").unwrap();
+
+    let gui = makeGui(&repositoryDir);
+
+    assertCommitAmendCheckboxIsSelected(&gui);
+    assertCommitMessageViewTextIs("Reworded commit\n", &gui);
+
+    unselectCommitAmendCheckbox(&gui);
+
+    assertCommitMessageViewTextIs("Own message", &gui);
+}
+}
@@ -0,0 +1,23 @@
+use crate::common::gui_assertions::assertCommitMessageViewTextIs;
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn restoreCommitMessageDraftOnStartup()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    std::fs::write(
+        repositoryDir.join(".git").join("rusty-git-gui-draft.toml"),
+        "message = \"Unfinished summary\\n\\nUnfinished body\"\n").unwrap();
+    makeNewUnstagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertCommitMessageViewTextIs("Unfinished summary\n\nUnfinished body", &gui);
+}
+}
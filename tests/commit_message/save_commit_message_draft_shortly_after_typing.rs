@@ -0,0 +1,22 @@
+use crate::common::gui_interactions::{setCommitMessage, waitForFileContent};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn saveCommitMessageDraftShortlyAfterTyping()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let draftPath = repositoryDir.join(".git").join("rusty-git-gui-draft.toml");
+    makeNewUnstagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("Unfinished summary", &gui);
+
+    waitForFileContent("message = \"Unfinished summary\"\namendedMessage = \"\"\n", &draftPath);
+}
+}
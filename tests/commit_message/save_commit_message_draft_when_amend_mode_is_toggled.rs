@@ -0,0 +1,30 @@
+use crate::common::gui_interactions::{selectCommitAmendCheckbox, setCommitMessage, unselectCommitAmendCheckbox};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn saveCommitMessageDraftWhenAmendModeIsToggled()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let draftPath = repositoryDir.join(".git").join("rusty-git-gui-draft.toml");
+    makeNewStagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+    setCommitMessage("Own message", &gui);
+
+    selectCommitAmendCheckbox(&gui);
+    assert_eq!("message = \"Own message\"\namendedMessage = \"Initial commit\\n\"\n",
+               std::fs::read_to_string(&draftPath).unwrap(),
+               "\nExpected saved draft differs from actual.");
+
+    unselectCommitAmendCheckbox(&gui);
+    assert_eq!("message = \"Own message\"\namendedMessage = \"\"\n",
+               std::fs::read_to_string(&draftPath).unwrap(),
+               "\nExpected saved draft differs from actual.");
+}
+}
@@ -0,0 +1,24 @@
+use crate::common::gui_interactions::{moveFocusOutOfCommitMessageView, setCommitMessage};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn saveCommitMessageDraftWhenFocusIsLost()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let draftPath = repositoryDir.join(".git").join("rusty-git-gui-draft.toml");
+    makeNewUnstagedFile(&PathBuf::from("file"), "some file content\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("Unfinished summary", &gui);
+
+    moveFocusOutOfCommitMessageView(&gui);
+    assert_eq!("message = \"Unfinished summary\"\namendedMessage = \"\"\n", std::fs::read_to_string(&draftPath).unwrap(),
+               "\nExpected saved draft differs from actual.");
+}
+}
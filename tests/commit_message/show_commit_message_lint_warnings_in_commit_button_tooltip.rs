@@ -0,0 +1,27 @@
+use crate::common::gui_assertions::{assertCommitButtonIsEnabled, assertCommitButtonTooltipIs};
+use crate::common::gui_interactions::setCommitMessage;
+use crate::common::setup::{makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn showCommitMessageLintWarningsInCommitButtonTooltip()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "staged file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    setCommitMessage("A summary which is much longer than fifty characters\nbody ", &gui);
+
+    assertCommitButtonIsEnabled(&gui);
+    assertCommitButtonTooltipIs(
+        "Warning in line 1: the summary has 52 characters, more than 50\n\
+         Warning in line 2: the summary should be followed by a blank line\n\
+         Warning in line 2: trailing whitespace",
+        &gui);
+}
+}
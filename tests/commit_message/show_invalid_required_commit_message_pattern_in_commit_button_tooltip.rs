@@ -0,0 +1,24 @@
+use crate::common::gui_assertions::assertCommitButtonTooltipIs;
+use crate::common::gui_interactions::setCommitMessage;
+use crate::common::setup::{makeGui, makeNewStagedFile, setupConfig, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn showInvalidRequiredCommitMessagePatternInCommitButtonTooltip()
+{
+    let _configDir = setupConfig("[commitMessageLinter]\nrequiredPattern = \"(unclosed\"\n");
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "staged file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    setCommitMessage("Summary", &gui);
+
+    assertCommitButtonTooltipIs(
+        "Error in line 1: the required pattern (unclosed is not a valid regular expression", &gui);
+}
+}
@@ -0,0 +1,30 @@
+use crate::common::gui_assertions::assertCommitButtonTooltipIs;
+use crate::common::gui_interactions::setCommitMessage;
+use crate::common::setup::{makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn skipLintRulesOnCommentAndTrailerLines()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file"), "staged file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    setCommitMessage(
+        "Summary\n\
+         \n\
+         Body line with trailing whitespace \n\
+         # A comment line which is much longer than seventy-two characters and ends with a space \n\
+         \n\
+         Signed-off-by: John Smith <john.smith@example.com> \n\
+         Co-authored-by: Somebody With A Really Long Name <somebody.with.a.really.long.name@example.com>\n",
+        &gui);
+
+    assertCommitButtonTooltipIs("Warning in line 3: trailing whitespace", &gui);
+}
+}
@@ -0,0 +1,45 @@
+use crate::common::gui_interactions::{clickCommitButton, setCommitMessage, waitForHeadCommitSummary};
+use crate::common::repository_assertions::{assertRepositoryLogIs, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeGui, makeHook, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn commitChangesStagedByPreCommitHook()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("file");
+    makeNewStagedFile(&filePath, "some file content\n", &repositoryDir);
+    makeHook("pre-commit", "#!/bin/sh\necho 'formatted file content' > file\ngit add file\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("initial commit", &gui);
+    clickCommitButton(&gui);
+    waitForHeadCommitSummary("initial commit", &repositoryDir);
+
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    assertRepositoryLogIs(COMMIT_LOG, &repositoryDir);
+}
+}
+
+const COMMIT_LOG: &str =
+r#"Author: John Smith
+Email: john.smith@example.com
+Subject: initial commit
+---
+ file | 1 +
+ 1 file changed, 1 insertion(+)
+
+diff --git a/file b/file
+new file mode 100644
+index 0000000..280f3ce
+--- /dev/null
++++ b/file
+@@ -0,0 +1 @@
++formatted file content
+"#;
@@ -0,0 +1,39 @@
+use crate::common::gui_interactions::{
+    clickCommitButton,
+    clickRefreshButton,
+    setCommitAuthorOptions,
+    setCommitMessage};
+use crate::common::repository_assertions::{assertRepositoryHeadAuthorDateIs, assertRepositoryHeadAuthorIs};
+use crate::common::setup::{makeGui, makeNewStagedFile, setupTest};
+
+use gtk::prelude::ButtonExt as _;
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn commitWithOverriddenAuthorOnlyOnce()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("file1"), "some file content\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    setCommitAuthorOptions("Jane Doe <jane.doe@example.com>", "2024-01-02 03:04:05 +0100", false, &gui);
+    assert_eq!("Author (custom)...", gui.findCommitAuthorButton().label().unwrap());
+    setCommitMessage("First commit", &gui);
+    clickCommitButton(&gui);
+
+    assertRepositoryHeadAuthorIs("Jane Doe <jane.doe@example.com>", &repositoryDir);
+    assertRepositoryHeadAuthorDateIs("2024-01-02 03:04:05 +0100", &repositoryDir);
+    assert_eq!("Author...", gui.findCommitAuthorButton().label().unwrap());
+
+    makeNewStagedFile(&PathBuf::from("file2"), "some file content\n", &repositoryDir);
+    clickRefreshButton(&gui);
+    setCommitMessage("Second commit", &gui);
+    clickCommitButton(&gui);
+
+    assertRepositoryHeadAuthorIs("John Smith <john.smith@example.com>", &repositoryDir);
+}
+}
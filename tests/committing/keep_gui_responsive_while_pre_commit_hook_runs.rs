@@ -0,0 +1,35 @@
+use crate::common::gui_assertions::assertCommitMessageViewTextIs;
+use crate::common::gui_interactions::{clickCommitButton, setCommitMessage, waitForHeadCommitSummary};
+use crate::common::setup::{makeCommit, makeGui, makeHook, makeNewStagedFile, modifyFile, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn keepGuiResponsiveWhilePreCommitHookRuns()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("file");
+    makeNewStagedFile(&filePath, "some file content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "modified file content\n", &repositoryDir);
+    stageFile(&filePath, &repositoryDir);
+    // The hook waits until the test lets it finish, but gives up after a few seconds.
+    makeHook(
+        "pre-commit",
+        "#!/bin/sh\nfor i in $(seq 50); do [ -f .git/finish-hook ] && exit 0; sleep 0.1; done\nexit 1\n",
+        &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+    setCommitMessage("Second commit", &gui);
+
+    clickCommitButton(&gui);
+    setCommitMessage("Typed while the hook runs", &gui);
+
+    assertCommitMessageViewTextIs("Typed while the hook runs", &gui);
+    std::fs::write(repositoryDir.join(".git/finish-hook"), "").unwrap();
+    waitForHeadCommitSummary("Second commit", &repositoryDir);
+}
+}
@@ -1,8 +1,14 @@
 mod allow_committing_when_change_is_staged_and_commit_message_is_filled;
 mod allow_committing_when_one_of_staged_changes_is_unstaged_and_message_is_filled;
+mod commit_changes_staged_by_pre_commit_hook;
 mod commit_staged_changes_given_no_previous_commits;
 mod commit_staged_changes_given_one_previous_commit;
+mod commit_with_overridden_author_only_once;
 mod forbid_committing_after_message_is_cleared;
 mod forbid_committing_when_change_is_staged_but_commit_message_is_empty;
 mod forbid_committing_when_last_staged_change_is_unstaged_and_message_is_filled;
-mod forbid_committing_when_no_changes_are_staged;
\ No newline at end of file
+mod forbid_committing_when_no_changes_are_staged;
+mod keep_gui_responsive_while_pre_commit_hook_runs;
+mod run_post_commit_hook_after_committing;
+mod sign_commit_with_ssh_key;
+mod skip_failing_pre_commit_hook;
@@ -0,0 +1,45 @@
+use crate::common::gui_interactions::{clickCommitButton, setCommitMessage, waitForFileContent};
+use crate::common::repository_assertions::{assertRepositoryLogIs, assertRepositoryStatusIsEmpty};
+use crate::common::setup::{makeGui, makeHook, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn runPostCommitHookAfterCommitting()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("file");
+    makeNewStagedFile(&filePath, "some file content\n", &repositoryDir);
+    makeHook("post-commit", "#!/bin/sh\ngit log -1 --format=%s > .git/post-commit-output\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("initial commit", &gui);
+    clickCommitButton(&gui);
+
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    assertRepositoryLogIs(COMMIT_LOG, &repositoryDir);
+    waitForFileContent("initial commit\n", &repositoryDir.join(".git/post-commit-output"));
+}
+}
+
+const COMMIT_LOG: &str =
+r#"Author: John Smith
+Email: john.smith@example.com
+Subject: initial commit
+---
+ file | 1 +
+ 1 file changed, 1 insertion(+)
+
+diff --git a/file b/file
+new file mode 100644
+index 0000000..c2e7a8d
+--- /dev/null
++++ b/file
+@@ -0,0 +1 @@
++some file content
+"#;
@@ -0,0 +1,39 @@
+use crate::common::gui_interactions::{clickCommitButton, setCommitMessage};
+use crate::common::repository_assertions::assertRepositoryStatusIsEmpty;
+use crate::common::setup::{makeGui, makeNewStagedFile, setConfigValue, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+
+rusty_fork_test! {
+#[test]
+fn signCommitWithSshKey()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let keyPath = repositoryDir.join(".git").join("signing_key");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-f", keyPath.to_str().unwrap()])
+        .stdout(Stdio::null()).status().unwrap();
+    assert!(status.success(), "Failed to generate an SSH key, command finished with {}", status);
+    setConfigValue("commit.gpgsign", "true", &repositoryDir);
+    setConfigValue("gpg.format", "ssh", &repositoryDir);
+    setConfigValue("user.signingkey", keyPath.to_str().unwrap(), &repositoryDir);
+    let filePath = PathBuf::from("file");
+    makeNewStagedFile(&filePath, "some file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    setCommitMessage("initial commit", &gui);
+    clickCommitButton(&gui);
+
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    let output = Command::new("git").args(["cat-file", "commit", "HEAD"])
+        .current_dir(&repositoryDir).output().unwrap();
+    let commitContent = String::from_utf8(output.stdout).unwrap();
+    assert!(commitContent.contains("gpgsig -----BEGIN SSH SIGNATURE-----"), "Commit is not signed:\n{}", commitContent);
+    assert!(commitContent.ends_with("\ninitial commit"), "Unexpected commit content:\n{}", commitContent);
+}
+}
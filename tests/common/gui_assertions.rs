@@ -3,13 +3,24 @@ use crate::common::file_changes_view_utils::FileChangesViewEntry;
 use crate::common::test_gui::TestGui;
 
 
+use gtk::glib::Cast as _;
+use gtk::prelude::ButtonExt as _;
+use gtk::prelude::ComboBoxTextExt as _;
+use gtk::prelude::ContainerExt as _;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::LabelExt as _;
+use gtk::prelude::MenuButtonExt as _;
+use gtk::prelude::SearchBarExt as _;
 use gtk::prelude::TextBufferExt as _;
+use gtk::prelude::TextTagTableExt as _;
 use gtk::prelude::TextViewExt as _;
 use gtk::prelude::ToggleButtonExt as _;
 use gtk::prelude::TreeModelExt as _;
 use gtk::prelude::TreeViewExt as _;
 use gtk::prelude::WidgetExt as _;
 
+pub(crate) const COMMIT_LOG_SIGNATURE_COLUMN: i32 = 4;
+const COMMIT_LOG_SUMMARY_COLUMN: i32 = 0;
 const CONTINUE_ITERATING_MODEL: bool = false;
 const EXCLUDE_HIDDEN_CHARACTERS : bool = false;
 const NO_FILE_CHANGES: Vec<FileChangesViewEntry> = vec![];
@@ -54,6 +65,21 @@ pub(crate) fn assertCommitMessageViewTextIs(text: &str, gui: &TestGui)
                "\nExpected content of commit message view differs from actual.");
 }
 
+pub(crate) fn assertRecentCommitMessagesAre(messages: &[&str], gui: &TestGui)
+{
+    let menu = gui.findRecentCommitMessagesButton().popup().unwrap();
+    let actualMessages = menu.children().iter()
+        .map(|item| item.clone().downcast::<gtk::MenuItem>().unwrap().label().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, actualMessages, "\nExpected recent commit messages differ from actual.");
+}
+
+pub(crate) fn assertMisspelledWordsAre(words: &[&str], gui: &TestGui)
+{
+    assert_eq!(words, getTaggedTexts(&gui.findCommitMessageView(), "misspelled"),
+               "\nExpected misspelled words differ from actual.");
+}
+
 pub(crate) fn assertCommitButtonIsEnabled(gui: &TestGui)
 {
     assert!(gui.findCommitButton().is_sensitive(),
@@ -78,6 +104,12 @@ pub(crate) fn assertCommitButtonTooltipIsEmpty(gui: &TestGui)
                "\nExpected empty commit button tooltip, but it is filled.");
 }
 
+pub(crate) fn assertIndexFlagsButtonLabelIs(label: &str, gui: &TestGui)
+{
+    assert_eq!(label, gui.findIndexFlagsButton().label().unwrap().as_str(),
+               "\nExpected index flags button label differs from actual.");
+}
+
 pub(crate) fn assertUnstagedChangesViewContains(changes: &[FileChangesViewEntry], gui: &TestGui)
 {
     assert_eq!(changes, &getFileChanges(&gui.findUnstagedChangesView())[..],
@@ -90,12 +122,130 @@ pub(crate) fn assertStagedChangesViewContains(changes: &[FileChangesViewEntry],
                "\nExpected content of staged changes view differs from actual.");
 }
 
+pub(crate) fn assertUnstagedChangesSummaryIs(summary: &str, gui: &TestGui)
+{
+    assert_eq!(summary, gui.findUnstagedChangesSummaryLabel().text(),
+               "\nExpected summary of unstaged changes differs from actual.");
+}
+
+pub(crate) fn assertStagedChangesSummaryIs(summary: &str, gui: &TestGui)
+{
+    assert_eq!(summary, gui.findStagedChangesSummaryLabel().text(),
+               "\nExpected summary of staged changes differs from actual.");
+}
+
 pub(crate) fn assertDiffViewContains(content: &str, gui: &TestGui)
 {
     assert_eq!(content, getText(&gui.findDiffView()),
                "\nExpected content of diff view differs from actual.");
 }
 
+// Tags are named after their colors, e.g. "green" for additions.
+pub(crate) fn assertDiffViewTaggedTextsAre(tagName: &str, texts: &[&str], gui: &TestGui)
+{
+    assert_eq!(texts, getTaggedTexts(&gui.findDiffView(), tagName),
+               "\nExpected texts tagged with {} in diff view differ from actual.", tagName);
+}
+
+pub(crate) fn assertCommitDiffViewEndsWith(content: &str, gui: &TestGui)
+{
+    let actualContent = getText(&gui.findCommitDiffView());
+    assert!(actualContent.ends_with(content),
+            "\nExpected end of commit diff view:\n{}\nActual content:\n{}", content, actualContent);
+}
+
+pub(crate) fn assertCommitDiffViewTaggedTextsAre(tagName: &str, texts: &[&str], gui: &TestGui)
+{
+    assert_eq!(texts, getTaggedTexts(&gui.findCommitDiffView(), tagName),
+               "\nExpected texts tagged with {} in commit diff view differ from actual.", tagName);
+}
+
+pub(crate) fn assertDiffSearchMatchCountIs(matchCount: &str, gui: &TestGui)
+{
+    assert_eq!(matchCount, gui.findDiffSearchMatchCountLabel().text(),
+               "\nExpected match count of diff search differs from actual.");
+}
+
+pub(crate) fn assertDiffSearchIsOpen(gui: &TestGui)
+{
+    assert!(gui.findDiffSearchBar().is_search_mode(), "\nExpected diff search to be open.");
+}
+
+pub(crate) fn assertDiffSearchIsClosed(gui: &TestGui)
+{
+    assert!(!gui.findDiffSearchBar().is_search_mode(), "\nExpected diff search to be closed.");
+}
+
+pub(crate) fn assertCommitDiffSearchMatchCountIs(matchCount: &str, gui: &TestGui)
+{
+    assert_eq!(matchCount, gui.findCommitDiffSearchMatchCountLabel().text(),
+               "\nExpected match count of commit diff search differs from actual.");
+}
+
+pub(crate) fn assertCommitDiffSearchInputIsInvalid(gui: &TestGui)
+{
+    assert!(gui.findCommitDiffSearchEntry().tooltip_text().is_some(),
+            "\nExpected commit diff search entry to show an error in its tooltip.");
+}
+
+pub(crate) fn assertCommitDiffSearchInputIsValid(gui: &TestGui)
+{
+    assert_eq!(None, gui.findCommitDiffSearchEntry().tooltip_text(),
+               "\nExpected commit diff search entry to have no error in its tooltip.");
+}
+
+pub(crate) fn assertCommitDiffSearchIsOpen(gui: &TestGui)
+{
+    assert!(gui.findCommitDiffSearchBar().is_search_mode(), "\nExpected commit diff search to be open.");
+}
+
+pub(crate) fn assertCommitDiffSearchIsClosed(gui: &TestGui)
+{
+    assert!(!gui.findCommitDiffSearchBar().is_search_mode(), "\nExpected commit diff search to be closed.");
+}
+
+pub(crate) fn assertImageDiffInfoIs(info: &str, gui: &TestGui)
+{
+    assert!(gui.findImageDiffBox().is_visible(), "\nExpected image diff to be visible.");
+    assert_eq!(info, gui.findImageDiffInfoLabel().text(), "\nExpected image diff info differs from actual.");
+}
+
+pub(crate) fn assertImageDiffIsHidden(gui: &TestGui)
+{
+    assert!(!gui.findImageDiffBox().is_visible(), "\nExpected image diff to be hidden.");
+}
+
+pub(crate) fn assertCommitImageDiffIs(path: &str, info: &str, gui: &TestGui)
+{
+    assert!(gui.findCommitImageDiffBox().is_visible(), "\nExpected commit image diff to be visible.");
+    assert_eq!(Some(path.into()), gui.findCommitImageDiffFileComboBox().active_text(),
+               "\nExpected image shown in commit image diff differs from actual.");
+    assert_eq!(info, gui.findCommitImageDiffInfoLabel().text(),
+               "\nExpected commit image diff info differs from actual.");
+}
+
+pub(crate) fn assertHeadLabelIs(text: &str, gui: &TestGui)
+{
+    assert_eq!(text, gui.findHeadLabel().text(), "\nExpected text of head label differs from actual.");
+}
+
+pub(crate) fn assertCommitLogSummaryInRowIs(row: usize, summary: &str, gui: &TestGui)
+{
+    let model = gui.findCommitLogView().model().unwrap();
+    let iter = model.iter_nth_child(None, i32::try_from(row).unwrap()).unwrap();
+    let actualSummary = model.value(&iter, COMMIT_LOG_SUMMARY_COLUMN).get::<String>().unwrap();
+    assert_eq!(summary, actualSummary, "\nExpected summary of commit in row {} differs from actual.", row);
+}
+
+pub(crate) fn assertCommitLogSignatureInRowIs(row: usize, signature: &str, gui: &TestGui)
+{
+    let model = gui.findCommitLogView().model().unwrap();
+    let iter = model.iter_nth_child(None, i32::try_from(row).unwrap()).unwrap();
+    let actualSignature = model.value(&iter, COMMIT_LOG_SIGNATURE_COLUMN).get::<Option<String>>().unwrap();
+    assert_eq!(signature, actualSignature.unwrap_or_default(),
+               "\nExpected signature of commit in row {} differs from actual.", row);
+}
+
 pub(crate) fn assertCommitAmendCheckboxIsEnabled(gui: &TestGui)
 {
     assert!(gui.findCommitAmendCheckbox().is_sensitive(),
@@ -166,3 +316,18 @@ fn getText(textView: &gtk::TextView) -> String
     let buffer = textView.buffer().unwrap();
     buffer.text(&buffer.start_iter(), &buffer.end_iter(), EXCLUDE_HIDDEN_CHARACTERS).unwrap().into()
 }
+
+fn getTaggedTexts(textView: &gtk::TextView, tagName: &str) -> Vec<String>
+{
+    let buffer = textView.buffer().unwrap();
+    let tag = buffer.tag_table().unwrap().lookup(tagName).unwrap();
+    let mut texts = vec![];
+    let mut start = buffer.start_iter();
+    while start.starts_tag(Some(&tag)) || start.forward_to_tag_toggle(Some(&tag)) {
+        let mut end = start;
+        end.forward_to_tag_toggle(Some(&tag));
+        texts.push(buffer.text(&start, &end, EXCLUDE_HIDDEN_CHARACTERS).unwrap().to_string());
+        start = end;
+    }
+    texts
+}
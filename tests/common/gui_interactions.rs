@@ -1,9 +1,23 @@
 use crate::common::file_changes_model_utils::{PATH_COLUMN, Row};
+use crate::common::gui_assertions::COMMIT_LOG_SIGNATURE_COLUMN;
 use crate::common::test_gui::TestGui;
 
 use rusty_git_gui::gui::Gui;
 
+use gtk::{gdk, glib};
+use gtk::glib::Cast as _;
+use gtk::glib::prelude::ObjectExt as _;
+use gtk::prelude::BinExt as _;
 use gtk::prelude::ButtonExt as _;
+use gtk::prelude::ComboBoxExt as _;
+use gtk::prelude::ContainerExt as _;
+use gtk::prelude::DialogExt as _;
+use gtk::prelude::DragContextExtManual as _;
+use gtk::prelude::EntryExt as _;
+use gtk::prelude::GtkMenuExt as _;
+use gtk::prelude::GtkMenuItemExt as _;
+use gtk::prelude::MessageDialogExt as _;
+use gtk::prelude::StackExt as _;
 use gtk::prelude::TextBufferExt as _;
 use gtk::prelude::TextViewExt as _;
 use gtk::prelude::ToggleButtonExt as _;
@@ -11,8 +25,22 @@ use gtk::prelude::TreeModelExt as _;
 use gtk::prelude::TreeSelectionExt as _;
 use gtk::prelude::TreeViewExt as _;
 use gtk::prelude::WidgetExt as _;
+use std::cell::RefCell;
+use std::path::Path;
+use std::process::Command;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 const NO_PARENT: Option<&gtk::TreeIter> = None;
+const NO_COLUMN: Option<&gtk::TreeViewColumn> = None;
+const NO_EDITING: bool = false;
+const DIALOG_POLL_INTERVAL: Duration = Duration::from_millis(10);
+const WORKER_THREAD_TIMEOUT: Duration = Duration::from_secs(10);
+const FILE_PATHS_TARGET: &str = "application/x-rusty-git-gui-file-paths";
+const FILE_PATHS_TARGET_INFO: u32 = 0;
+const TEST_SELECTION: &str = "RUSTY_GIT_GUI_TEST_SELECTION";
+const BYTE_FORMAT: i32 = 8;
+const PRIMARY_BUTTON: i32 = 1;
 
 
 pub(crate) fn show(gui: &Gui)
@@ -24,12 +52,12 @@ pub(crate) fn show(gui: &Gui)
 
 pub(crate) fn selectUnstagedChangeInRow(row: Row, gui: &TestGui)
 {
-    selectFileChange(row, &gui.findUnstagedChangesView());
+    selectRow(row, &gui.findUnstagedChangesView());
 }
 
 pub(crate) fn selectStagedChangeInRow(row: Row, gui: &TestGui)
 {
-    selectFileChange(row, &gui.findStagedChangesView());
+    selectRow(row, &gui.findStagedChangesView());
 }
 
 pub(crate) fn activateUnstagedChangeInRow(row: Row, gui: &TestGui)
@@ -42,6 +70,21 @@ pub(crate) fn activateStagedChangeInRow(row: Row, gui: &TestGui)
     activateFileChangeInRow(row, &gui.findStagedChangesView());
 }
 
+pub(crate) fn activateUnstagedChangesInRows(rows: &[Row], gui: &TestGui)
+{
+    activateFileChangesInRows(rows, &gui.findUnstagedChangesView());
+}
+
+pub(crate) fn dragUnstagedChangesToStaged(paths: &[&str], gui: &TestGui)
+{
+    dropFilePaths(paths, &gui.findUnstagedChangesView(), &gui.findStagedChangesView());
+}
+
+pub(crate) fn dragStagedChangesToUnstaged(paths: &[&str], gui: &TestGui)
+{
+    dropFilePaths(paths, &gui.findStagedChangesView(), &gui.findUnstagedChangesView());
+}
+
 pub(crate) fn setCommitMessage(message: &str, gui: &TestGui)
 {
     let view = gui.findCommitMessageView();
@@ -49,11 +92,31 @@ pub(crate) fn setCommitMessage(message: &str, gui: &TestGui)
     processEvents();
 }
 
+pub(crate) fn moveFocusOutOfCommitMessageView(gui: &TestGui)
+{
+    let event = gdk::Event::new(gdk::EventType::FocusChange);
+    gui.findCommitMessageView().emit_by_name::<bool>("focus-out-event", &[&event]);
+    processEvents();
+}
+
 pub(crate) fn clickCommitButton(gui: &TestGui)
 {
     clickButton(&gui.findCommitButton());
 }
 
+// Empty texts keep the defaults.
+pub(crate) fn setCommitAuthorOptions(author: &str, authorDate: &str, resetAuthorOnAmend: bool, gui: &TestGui)
+{
+    let (author, authorDate) = (author.to_owned(), authorDate.to_owned());
+    fillAndAcceptNextDialog(move |dialog| {
+        gtk_test::find_child_by_name::<gtk::Entry, _>(dialog, "Author entry").unwrap().set_text(&author);
+        gtk_test::find_child_by_name::<gtk::Entry, _>(dialog, "Author date entry").unwrap().set_text(&authorDate);
+        gtk_test::find_child_by_name::<gtk::CheckButton, _>(dialog, "Reset author checkbox").unwrap()
+            .set_active(resetAuthorOnAmend);
+    });
+    clickButton(&gui.findCommitAuthorButton());
+}
+
 pub(crate) fn clickRefreshButton(gui: &TestGui)
 {
     clickButton(&gui.findRefreshButton());
@@ -77,9 +140,239 @@ pub(crate) fn unselectCommitAmendCheckbox(gui: &TestGui)
     processEvents();
 }
 
+pub(crate) fn selectCommitSkipHooksCheckbox(gui: &TestGui)
+{
+    let checkbox = gui.findCommitSkipHooksCheckbox();
+    assert!(!checkbox.is_active());
+    checkbox.clicked();
+    processEvents();
+}
+
+pub(crate) fn selectDirectoryTreeCheckbox(gui: &TestGui)
+{
+    let checkbox = gui.findDirectoryTreeCheckbox();
+    assert!(!checkbox.is_active());
+    checkbox.clicked();
+    processEvents();
+}
+
+pub(crate) fn selectIgnoredFilesCheckbox(gui: &TestGui)
+{
+    let checkbox = gui.findIgnoredFilesCheckbox();
+    assert!(!checkbox.is_active());
+    checkbox.clicked();
+    processEvents();
+}
+
+pub(crate) fn filterFileChanges(text: &str, gui: &TestGui)
+{
+    gui.findFileChangesFilterEntry().set_text(text);
+    processEvents();
+}
+
+pub(crate) fn sortFileChangesBy(sortOrderId: &str, gui: &TestGui)
+{
+    assert!(gui.findFileChangesSortComboBox().set_active_id(Some(sortOrderId)));
+    processEvents();
+}
+
+pub(crate) fn searchInDiff(text: &str, gui: &TestGui)
+{
+    gui.findDiffSearchEntry().set_text(text);
+    processEvents();
+}
+
+pub(crate) fn selectNextDiffSearchMatch(gui: &TestGui)
+{
+    gui.findDiffSearchNextButton().clicked();
+    processEvents();
+}
+
+pub(crate) fn searchInCommitDiff(text: &str, gui: &TestGui)
+{
+    gui.findCommitDiffSearchEntry().set_text(text);
+    processEvents();
+}
+
+pub(crate) fn selectNextCommitDiffSearchMatch(gui: &TestGui)
+{
+    gui.findCommitDiffSearchNextButton().clicked();
+    processEvents();
+}
+
+pub(crate) fn selectPreviousCommitDiffSearchMatch(gui: &TestGui)
+{
+    gui.findCommitDiffSearchPreviousButton().clicked();
+    processEvents();
+}
+
+pub(crate) fn selectCommitDiffSearchRegexButton(gui: &TestGui)
+{
+    let button = gui.findCommitDiffSearchRegexButton();
+    assert!(!button.is_active());
+    button.clicked();
+    processEvents();
+}
+
+pub(crate) fn pressSearchShortcut(gui: &TestGui)
+{
+    let (key, modifiers) = gtk::accelerator_parse("<Control>f");
+    assert!(gtk::accel_groups_activate(gui.getWindow(), key, modifiers));
+    processEvents();
+}
+
+pub(crate) fn showCommitLog(gui: &TestGui)
+{
+    gui.findMainStack().set_visible_child_name("Commit log");
+    processEvents();
+}
+
+pub(crate) fn selectCommitInRow(row: Row, gui: &TestGui)
+{
+    showCommitLog(gui);
+    selectRow(row, &gui.findCommitLogView());
+}
+
+// The menu is opened with the menu key for the commit in the row. Labels before the last one lead through submenus.
+pub(crate) fn activateCommitLogMenuItem(row: Row, labels: &[&str], gui: &TestGui)
+{
+    showCommitLog(gui);
+    let view = gui.findCommitLogView();
+    selectRow(row, &view);
+    view.emit_by_name::<bool>("popup-menu", &[]);
+    processEvents();
+    activateShownMenuItem(labels);
+}
+
+// The menu key opens the menu of the row with the cursor.
+pub(crate) fn activateUnstagedChangeMenuItem(row: Row, label: &str, gui: &TestGui)
+{
+    let view = gui.findUnstagedChangesView();
+    let rowPath = gtk::TreePath::from_indicesv(&[i32::try_from(row).unwrap()]);
+    view.set_cursor(&rowPath, NO_COLUMN, NO_EDITING);
+    view.emit_by_name::<bool>("popup-menu", &[]);
+    processEvents();
+    activateShownMenuItem(&[label]);
+}
+
+// Dialogs run their own main loop, so they are answered from a timeout set up before they are opened.
+pub(crate) fn acceptNextDialog()
+{
+    fillAndAcceptNextDialog(|_dialog| ());
+}
+
+// The text is available once the dialog is answered.
+pub(crate) fn acceptNextMessageDialog() -> Rc<RefCell<String>>
+{
+    let text = Rc::new(RefCell::new(String::new()));
+    let dialogText = Rc::clone(&text);
+    fillAndAcceptNextDialog(move |dialog| {
+        *dialogText.borrow_mut() = dialog.clone().downcast::<gtk::MessageDialog>().unwrap().text().unwrap().into();
+    });
+    text
+}
+
+// Results of worker threads reach the main loop whenever the threads finish, so events are processed until then.
+pub(crate) fn waitForCommitLogRowCount(rowCount: i32, gui: &TestGui)
+{
+    let deadline = Instant::now() + WORKER_THREAD_TIMEOUT;
+    let model = gui.findCommitLogView().model().unwrap();
+    while model.iter_n_children(NO_PARENT) != rowCount {
+        assert!(Instant::now() < deadline, "\nTimed out waiting for the commit log to have {} rows.", rowCount);
+        gtk::main_iteration_do(false);
+        std::thread::sleep(DIALOG_POLL_INTERVAL);
+    }
+}
+
+// Signatures are verified in a worker thread, so events are processed until the result is shown.
+pub(crate) fn waitForCommitLogSignatureInRow(row: usize, signature: &str, gui: &TestGui)
+{
+    let deadline = Instant::now() + WORKER_THREAD_TIMEOUT;
+    let model = gui.findCommitLogView().model().unwrap();
+    let iter = model.iter_nth_child(NO_PARENT, i32::try_from(row).unwrap()).unwrap();
+    let getSignature = || model.value(&iter, COMMIT_LOG_SIGNATURE_COLUMN).get::<Option<String>>().unwrap();
+    while getSignature().unwrap_or_default() != signature {
+        assert!(Instant::now() < deadline,
+                "\nTimed out waiting for the signature of commit in row {} to be: {}", row, signature);
+        gtk::main_iteration_do(false);
+        std::thread::sleep(DIALOG_POLL_INTERVAL);
+    }
+}
+
+// Commit hooks run in a worker thread, so events are processed until the commit is made.
+pub(crate) fn waitForHeadCommitSummary(summary: &str, repositoryDir: &Path)
+{
+    let deadline = Instant::now() + WORKER_THREAD_TIMEOUT;
+    while getHeadCommitSummary(repositoryDir) != summary {
+        assert!(Instant::now() < deadline, "\nTimed out waiting for a commit with summary: {}", summary);
+        gtk::main_iteration_do(false);
+        std::thread::sleep(DIALOG_POLL_INTERVAL);
+    }
+}
+
+// Timers fire only while the main loop runs, so events are processed until the file is written.
+pub(crate) fn waitForFileContent(content: &str, filePath: &Path)
+{
+    let deadline = Instant::now() + WORKER_THREAD_TIMEOUT;
+    while std::fs::read_to_string(filePath).ok().as_deref() != Some(content) {
+        assert!(Instant::now() < deadline, "\nTimed out waiting for {} to contain:\n{}", filePath.display(), content);
+        gtk::main_iteration_do(false);
+        std::thread::sleep(DIALOG_POLL_INTERVAL);
+    }
+}
+
+pub(crate) fn fillAndAcceptNextDialog(fill: impl Fn(&gtk::Dialog) + 'static)
+{
+    glib::timeout_add_local(DIALOG_POLL_INTERVAL, move || match findShownDialog() {
+        Some(dialog) => {
+            fill(&dialog);
+            dialog.response(gtk::ResponseType::Ok);
+            glib::ControlFlow::Break
+        },
+        None => glib::ControlFlow::Continue
+    });
+}
+
 
 // private
 
+// gtk-test cannot drag with a mouse, so a drag is begun on the source view and its file paths are given to the drop
+// handler of the target view directly.
+fn dropFilePaths(paths: &[&str], sourceView: &gtk::TreeView, targetView: &gtk::TreeView)
+{
+    let targets = gtk::TargetList::new(&[makeFilePathsTarget()]);
+    let context = sourceView.drag_begin_with_coordinates(&targets, gdk::DragAction::MOVE, PRIMARY_BUTTON, None, -1, -1)
+        .unwrap_or_else(|| panic!("Failed to begin dragging from {}.", sourceView.widget_name()));
+    let selectionData = makeSelectionData(&paths.join("\n"));
+    targetView.emit_by_name::<()>(
+        "drag-data-received", &[&context, &0, &0, &selectionData, &FILE_PATHS_TARGET_INFO, &0_u32]);
+    context.drag_cancel();
+    processEvents();
+}
+
+// Selection data cannot be created on its own, so it is requested from a selection owned by the application.
+fn makeSelectionData(data: &str) -> gtk::SelectionData
+{
+    let clipboard = gtk::Clipboard::get(&gdk::Atom::intern(TEST_SELECTION));
+    let data = data.to_owned();
+    clipboard.set_with_data(&[makeFilePathsTarget()], move |_clipboard, selectionData, _info| {
+        selectionData.set(&selectionData.target(), BYTE_FORMAT, data.as_bytes());
+    });
+    clipboard.wait_for_contents(&gdk::Atom::intern(FILE_PATHS_TARGET))
+        .unwrap_or_else(|| panic!("Failed to get selection data of {}.", FILE_PATHS_TARGET))
+}
+
+fn makeFilePathsTarget() -> gtk::TargetEntry
+{
+    gtk::TargetEntry::new(FILE_PATHS_TARGET, gtk::TargetFlags::SAME_APP, FILE_PATHS_TARGET_INFO)
+}
+
+fn getHeadCommitSummary(repositoryDir: &Path) -> String
+{
+    let output = Command::new("git").args(["log", "-1", "--format=%s"]).current_dir(repositoryDir).output().unwrap();
+    String::from_utf8(output.stdout).unwrap().trim_end().into()
+}
+
 fn processEvents()
 {
     while gtk::events_pending() {
@@ -87,7 +380,7 @@ fn processEvents()
     }
 }
 
-fn selectFileChange(row: Row, view: &gtk::TreeView)
+fn selectRow(row: Row, view: &gtk::TreeView)
 {
     let model = view.model().unwrap();
     let row = i32::try_from(row).unwrap();
@@ -108,6 +401,59 @@ fn activateFileChangeInRow(row: Row, view: &gtk::TreeView)
     processEvents();
 }
 
+fn activateFileChangesInRows(rows: &[Row], view: &gtk::TreeView)
+{
+    let model = view.model().unwrap();
+    view.selection().unselect_all();
+    for row in rows {
+        let iter = model.iter_nth_child(NO_PARENT, i32::try_from(*row).unwrap()).unwrap();
+        view.selection().select_iter(&iter);
+    }
+    let iter = model.iter_nth_child(NO_PARENT, i32::try_from(rows[0]).unwrap()).unwrap();
+    let rowPath = model.path(&iter).unwrap();
+    let column = view.column(PATH_COLUMN).unwrap();
+    view.row_activated(&rowPath, &column);
+    processEvents();
+}
+
+fn activateShownMenuItem(labels: &[&str])
+{
+    let rootMenu = findShownMenu();
+    let (itemLabel, submenuLabels) = labels.split_last().unwrap();
+    let mut menu = rootMenu.clone();
+    for label in submenuLabels {
+        menu = findMenuItem(&menu, label).submenu().unwrap().downcast::<gtk::Menu>().unwrap();
+    }
+    let menuItem = findMenuItem(&menu, itemLabel);
+    rootMenu.popdown();
+    menuItem.emit_activate();
+    processEvents();
+}
+
+fn findShownMenu() -> gtk::Menu
+{
+    gtk::Window::list_toplevels().into_iter()
+        .filter(|window| window.is_visible())
+        .filter_map(|window| window.downcast::<gtk::Bin>().ok()?.child()?.downcast::<gtk::Menu>().ok())
+        .next()
+        .unwrap_or_else(|| panic!("No shown menu was found."))
+}
+
+fn findMenuItem(menu: &gtk::Menu, label: &str) -> gtk::MenuItem
+{
+    menu.children().into_iter()
+        .filter_map(|child| child.downcast::<gtk::MenuItem>().ok())
+        .find(|menuItem| menuItem.label().as_deref() == Some(label))
+        .unwrap_or_else(|| panic!("Menu item {} was not found.", label))
+}
+
+fn findShownDialog() -> Option<gtk::Dialog>
+{
+    gtk::Window::list_toplevels().into_iter()
+        .filter(|window| window.is_visible())
+        .find_map(|window| window.downcast::<gtk::Dialog>().ok())
+}
+
 fn clickButton(button: &gtk::Button)
 {
     assert!(button.is_sensitive());
@@ -28,6 +28,55 @@ pub(crate) fn assertRepositoryLogIs(expectedOutput: &str, repositoryDir: &Path)
         repositoryDir);
 }
 
+pub(crate) fn assertRepositoryCommitSummariesAre(expectedSummaries: &[&str], repositoryDir: &Path)
+{
+    let expectedOutput = expectedSummaries.iter().map(|summary| format!("{}\n", summary)).collect::<String>();
+    assertCommandOutput(&["git", "log", "--format=%s"], &expectedOutput, repositoryDir);
+}
+
+// Commits are given from the newest one, each as its summary and the paths it changes.
+pub(crate) fn assertRepositoryCommitsChangeFiles(expectedCommits: &[(&str, &[&str])], repositoryDir: &Path)
+{
+    let expectedOutput = expectedCommits.iter()
+        .map(|(summary, paths)| {
+            let paths = paths.iter().map(|path| format!("{}\n", path)).collect::<String>();
+            format!("{}\n\n{}", summary, paths)
+        })
+        .collect::<String>();
+    assertCommandOutput(&["git", "log", "--format=%s", "--name-only"], &expectedOutput, repositoryDir);
+}
+
+// Tags are given as "<name> <type of tagged object>", so annotated tags end with "tag" and lightweight ones with "commit".
+pub(crate) fn assertRepositoryTagsAre(expectedTags: &[&str], repositoryDir: &Path)
+{
+    let expectedOutput = expectedTags.iter().map(|tag| format!("{}\n", tag)).collect::<String>();
+    assertCommandOutput(
+        &["git", "for-each-ref", "--format=%(refname:short) %(objecttype)", "refs/tags"], &expectedOutput, repositoryDir);
+}
+
+// A detached HEAD is named "HEAD".
+pub(crate) fn assertRepositoryHeadIs(expectedName: &str, repositoryDir: &Path)
+{
+    assertCommandOutput(
+        &["git", "rev-parse", "--abbrev-ref", "HEAD"], &format!("{}\n", expectedName), repositoryDir);
+}
+
+pub(crate) fn assertRepositoryHeadAuthorIs(expectedAuthor: &str, repositoryDir: &Path)
+{
+    assertCommandOutput(&["git", "log", "-1", "--format=%an <%ae>"], &format!("{}\n", expectedAuthor), repositoryDir);
+}
+
+pub(crate) fn assertRepositoryHeadAuthorDateIs(expectedDate: &str, repositoryDir: &Path)
+{
+    assertCommandOutput(
+        &["git", "log", "-1", "--date=iso", "--format=%ad"], &format!("{}\n", expectedDate), repositoryDir);
+}
+
+pub(crate) fn assertRepositoryHeadCommitterIs(expectedCommitter: &str, repositoryDir: &Path)
+{
+    assertCommandOutput(&["git", "log", "-1", "--format=%cn <%ce>"], &format!("{}\n", expectedCommitter), repositoryDir);
+}
+
 pub(crate) fn assertRepositoryStatusIs(expectedStatusEntries: &[RepositoryStatusEntry], repositoryDir: &Path)
 {
     let output = String::from_utf8(getCommandOutput(
@@ -52,6 +101,12 @@ pub(crate) fn assertGitDiffStagedIs(expectedOutput: &str, repositoryDir: &Path)
     assertCommandOutput(&["git", "diff", "--staged"], expectedOutput, repositoryDir);
 }
 
+// Tags of "git ls-files -v": "H" for tracked files, "h" for assume-unchanged and "S" for skip-worktree ones.
+pub(crate) fn assertGitIndexEntriesAre(expectedOutput: &str, repositoryDir: &Path)
+{
+    assertCommandOutput(&["git", "ls-files", "-v"], expectedOutput, repositoryDir);
+}
+
 
 fn getCommandOutput(commandParts: &[&str], repositoryDir: &Path) -> std::process::Output
 {
@@ -70,6 +70,7 @@ pub(crate) enum FileChangeStatus
     Deleted,
     Renamed,
     Copied,
+    TypeChanged,
     Unmerged
 }
 
@@ -85,6 +86,7 @@ impl FileChangeStatus
             'D' => Self::Deleted,
             'R' => Self::Renamed,
             'C' => Self::Copied,
+            'T' => Self::TypeChanged,
             'U' => Self::Unmerged,
              _  => panic!("Unknown file change status: {}", symbol)
         }
@@ -7,7 +7,7 @@ use rusty_git_gui::gui::Gui;
 use gtk::glib::object::Cast as _;
 use std::fs::{File, OpenOptions};
 use std::io::Write as _;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use tempfile::{tempdir, TempDir};
 
@@ -61,6 +61,12 @@ pub(crate) fn makeCommit(message: &str, repositoryDir: &Path)
                r#"Failed to create a commit with message "{}", command finished with {}"#, message, status);
 }
 
+pub(crate) fn makeTag(name: &str, repositoryDir: &Path)
+{
+    let status = Command::new("git").args(["tag", name]).current_dir(repositoryDir).status().unwrap();
+    assert!(status.success(), r#"Failed to create tag "{}", command finished with {}"#, name, status);
+}
+
 pub(crate) fn modifyFile(filePath: &Path, newContent: &str, repositoryDir: &Path)
 {
     let mut file = openExistingFileForWriting(&repositoryDir.join(filePath));
@@ -82,6 +88,101 @@ pub(crate) fn renameFile(oldFilePath: &Path, newFilePath: &Path, repositoryDir:
     std::fs::rename(repositoryDir.join(oldFilePath), repositoryDir.join(newFilePath)).unwrap();
 }
 
+pub(crate) fn replaceFileWithSymlink(filePath: &Path, targetPath: &Path, repositoryDir: &Path)
+{
+    removeFile(filePath, repositoryDir);
+    std::os::unix::fs::symlink(targetPath, repositoryDir.join(filePath)).unwrap();
+}
+
+pub(crate) fn setConfigValue(key: &str, value: &str, repositoryDir: &Path)
+{
+    let status = Command::new("git").args(["config", key, value])
+        .current_dir(repositoryDir).status().unwrap();
+    assert!(status.success(), r#"Failed to set config value "{}" to "{}", command finished with {}"#, key, value, status);
+}
+
+pub(crate) fn setSkipWorktree(filePath: &Path, repositoryDir: &Path)
+{
+    let status = Command::new("git").args(["update-index", "--skip-worktree", filePath.to_str().unwrap()])
+        .current_dir(repositoryDir).status().unwrap();
+    assert!(status.success(), r#"Failed to set skip-worktree for file "{}", command finished with {}"#,
+            filePath.to_string_lossy(), status);
+}
+
+pub(crate) fn addSubmodule(submodulePath: &Path, repositoryDir: &Path)
+{
+    let originDir = makeTemporaryDirectory();
+    initializeGitRepository(originDir.path());
+    makeNewStagedFile(Path::new("file"), "submodule file content\n", originDir.path());
+    makeCommit("Initial submodule commit", originDir.path());
+    let status = Command::new("git")
+        .args(["-c", "protocol.file.allow=always", "submodule", "add", "--quiet",
+               originDir.path().to_str().unwrap(), submodulePath.to_str().unwrap()])
+        .current_dir(repositoryDir).status().unwrap();
+    assert!(status.success(), r#"Failed to add submodule "{}", command finished with {}"#,
+            submodulePath.to_string_lossy(), status);
+    initializeGitRepositoryWith(&["git", "config", "user.name", "John Smith"], &repositoryDir.join(submodulePath));
+    initializeGitRepositoryWith(
+        &["git", "config", "user.email", "john.smith@example.com"], &repositoryDir.join(submodulePath));
+}
+
+// The worktree is created in its own temporary directory, which is returned together with the worktree path.
+pub(crate) fn addWorktree(branch: &str, repositoryDir: &Path) -> (TempDir, PathBuf)
+{
+    let parentDir = makeTemporaryDirectory();
+    let worktreeDir = parentDir.path().join(branch);
+    let status = Command::new("git")
+        .args(["worktree", "add", "--quiet", "-b", branch, worktreeDir.to_str().unwrap()])
+        .current_dir(repositoryDir).status().unwrap();
+    assert!(status.success(), r#"Failed to add worktree for branch "{}", command finished with {}"#, branch, status);
+    (parentDir, worktreeDir)
+}
+
+pub(crate) fn getShortHeadCommitId(repositoryDir: &Path) -> String
+{
+    let output = Command::new("git").args(["rev-parse", "--short=7", "HEAD"])
+        .current_dir(repositoryDir).output().unwrap();
+    assert!(output.status.success(), "Failed to get HEAD commit id, command finished with {}", output.status);
+    String::from_utf8(output.stdout).unwrap().trim().into()
+}
+
+#[cfg(unix)]
+pub(crate) fn makeHook(hookName: &str, script: &str, repositoryDir: &Path)
+{
+    use std::os::unix::fs::PermissionsExt as _;
+    let hookPath = repositoryDir.join(".git").join("hooks").join(hookName);
+    std::fs::create_dir_all(hookPath.parent().unwrap()).unwrap();
+    std::fs::write(&hookPath, script).unwrap();
+    std::fs::set_permissions(&hookPath, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+// The configuration is read from a new directory set in XDG_CONFIG_HOME, which has to be kept until the test ends.
+pub(crate) fn setupConfig(config: &str) -> TempDir
+{
+    let configDir = makeTemporaryDirectory();
+    let appConfigDir = configDir.path().join("rusty-git-gui");
+    std::fs::create_dir(&appConfigDir).unwrap();
+    std::fs::write(appConfigDir.join("config.toml"), config).unwrap();
+    std::env::set_var("XDG_CONFIG_HOME", configDir.path());
+    configDir
+}
+
+// The configuration is read from a new directory set in XDG_CONFIG_HOME, which has to be kept until the test ends.
+pub(crate) fn setupSpellChecking(dictionary: &str, affixes: &str) -> TempDir
+{
+    let configDir = makeTemporaryDirectory();
+    let appConfigDir = configDir.path().join("rusty-git-gui");
+    std::fs::create_dir(&appConfigDir).unwrap();
+    std::fs::write(appConfigDir.join("test.dic"), dictionary).unwrap();
+    std::fs::write(appConfigDir.join("test.aff"), affixes).unwrap();
+    std::fs::write(
+        appConfigDir.join("config.toml"),
+        format!("[spellChecking]\nenabled = true\nlanguage = \"test\"\ndictionaryDirs = [{:?}]\n", appConfigDir))
+        .unwrap();
+    std::env::set_var("XDG_CONFIG_HOME", configDir.path());
+    configDir
+}
+
 
 // private
 
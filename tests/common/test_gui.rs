@@ -30,11 +30,146 @@ impl TestGui
         self.findWidget::<gtk::TextView>("Diff view")
     }
 
+    pub fn findCommitDiffView(&self) -> gtk::TextView
+    {
+        self.findWidget::<gtk::TextView>("Commit diff view")
+    }
+
+    pub fn findImageDiffBox(&self) -> gtk::Box
+    {
+        self.findWidget::<gtk::Box>("Image diff box")
+    }
+
+    pub fn findImageDiffInfoLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Image diff info label")
+    }
+
+    pub fn findCommitImageDiffBox(&self) -> gtk::Box
+    {
+        self.findWidget::<gtk::Box>("Commit image diff box")
+    }
+
+    pub fn findCommitImageDiffFileComboBox(&self) -> gtk::ComboBoxText
+    {
+        self.findWidget::<gtk::ComboBoxText>("Commit image diff file combo box")
+    }
+
+    pub fn findCommitImageDiffInfoLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Commit image diff info label")
+    }
+
+    pub fn findUnstagedChangesSummaryLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Unstaged changes summary label")
+    }
+
+    pub fn findStagedChangesSummaryLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Staged changes summary label")
+    }
+
+    pub fn findDirectoryTreeCheckbox(&self) -> gtk::CheckButton
+    {
+        self.findWidget::<gtk::CheckButton>("Directory tree checkbox")
+    }
+
+    pub fn findIgnoredFilesCheckbox(&self) -> gtk::CheckButton
+    {
+        self.findWidget::<gtk::CheckButton>("Ignored files checkbox")
+    }
+
+    pub fn findIndexFlagsButton(&self) -> gtk::Button
+    {
+        self.findWidget::<gtk::Button>("Index flags button")
+    }
+
+    pub fn findFileChangesFilterEntry(&self) -> gtk::SearchEntry
+    {
+        self.findWidget::<gtk::SearchEntry>("File changes filter entry")
+    }
+
+    pub fn findFileChangesSortComboBox(&self) -> gtk::ComboBoxText
+    {
+        self.findWidget::<gtk::ComboBoxText>("File changes sort combo box")
+    }
+
+    pub fn findDiffSearchEntry(&self) -> gtk::SearchEntry
+    {
+        self.findWidget::<gtk::SearchEntry>("Diff search entry")
+    }
+
+    pub fn findDiffSearchNextButton(&self) -> gtk::Button
+    {
+        self.findWidget::<gtk::Button>("Diff search next button")
+    }
+
+    pub fn findDiffSearchMatchCountLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Diff search match count label")
+    }
+
+    pub fn findDiffSearchBar(&self) -> gtk::SearchBar
+    {
+        self.findWidget::<gtk::SearchBar>("Diff search bar")
+    }
+
+    pub fn findCommitDiffSearchEntry(&self) -> gtk::SearchEntry
+    {
+        self.findWidget::<gtk::SearchEntry>("Commit diff search entry")
+    }
+
+    pub fn findCommitDiffSearchRegexButton(&self) -> gtk::ToggleButton
+    {
+        self.findWidget::<gtk::ToggleButton>("Commit diff search regex button")
+    }
+
+    pub fn findCommitDiffSearchPreviousButton(&self) -> gtk::Button
+    {
+        self.findWidget::<gtk::Button>("Commit diff search previous button")
+    }
+
+    pub fn findCommitDiffSearchNextButton(&self) -> gtk::Button
+    {
+        self.findWidget::<gtk::Button>("Commit diff search next button")
+    }
+
+    pub fn findCommitDiffSearchMatchCountLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Commit diff search match count label")
+    }
+
+    pub fn findCommitDiffSearchBar(&self) -> gtk::SearchBar
+    {
+        self.findWidget::<gtk::SearchBar>("Commit diff search bar")
+    }
+
+    pub fn findMainStack(&self) -> gtk::Stack
+    {
+        self.findWidget::<gtk::Stack>("Main stack")
+    }
+
+    pub fn findHeadLabel(&self) -> gtk::Label
+    {
+        self.findWidget::<gtk::Label>("Head label")
+    }
+
+    pub fn findCommitLogView(&self) -> gtk::TreeView
+    {
+        self.findWidget::<gtk::TreeView>("Commit log view")
+    }
+
     pub fn findCommitMessageView(&self) -> gtk::TextView
     {
         self.findWidget::<gtk::TextView>("Commit message view")
     }
 
+    pub fn findRecentCommitMessagesButton(&self) -> gtk::MenuButton
+    {
+        self.findWidget::<gtk::MenuButton>("Recent commit messages button")
+    }
+
     pub fn findRefreshButton(&self) -> gtk::Button
     {
         self.findWidget::<gtk::Button>("Refresh button")
@@ -45,11 +180,26 @@ impl TestGui
         self.findWidget::<gtk::Button>("Commit button")
     }
 
+    pub fn findCommitAuthorButton(&self) -> gtk::Button
+    {
+        self.findWidget::<gtk::Button>("Commit author button")
+    }
+
     pub fn findCommitAmendCheckbox(&self) -> gtk::CheckButton
     {
         self.findWidget::<gtk::CheckButton>("Commit amend checkbox")
     }
 
+    pub fn findCommitSkipHooksCheckbox(&self) -> gtk::CheckButton
+    {
+        self.findWidget::<gtk::CheckButton>("Commit skip hooks checkbox")
+    }
+
+    pub fn getWindow(&self) -> &gtk::ApplicationWindow
+    {
+        &self.window
+    }
+
 
     // private
 
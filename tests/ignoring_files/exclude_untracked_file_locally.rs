@@ -0,0 +1,31 @@
+use crate::common::gui_assertions::{assertStagedChangesViewIsEmpty, assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::activateUnstagedChangeMenuItem;
+use crate::common::repository_assertions::assertRepositoryStatusIsEmpty;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, makeNewUnstagedFile, makeSubdirectory, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn excludeUntrackedFileLocally()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("committed_file"), "some content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    makeSubdirectory(&PathBuf::from("some_dir"), &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("some_dir/[draft].txt"), "some draft\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    activateUnstagedChangeMenuItem(0, "Add to .git/info/exclude", &gui);
+
+    let excludeContent = std::fs::read_to_string(repositoryDir.join(".git/info/exclude")).unwrap();
+    assert_eq!(Some("/some_dir/\\[draft].txt"), excludeContent.lines().last());
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
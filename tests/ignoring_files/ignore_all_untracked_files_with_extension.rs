@@ -0,0 +1,35 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewIsEmpty, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::activateUnstagedChangeMenuItem;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn ignoreAllUntrackedFilesWithExtension()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let gitignorePath = PathBuf::from(".gitignore");
+    makeNewStagedFile(&gitignorePath, "*.tmp", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("first.log"), "first log\n", &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("second.log"), "second log\n", &repositoryDir);
+    let notesPath = PathBuf::from("third.txt");
+    makeNewUnstagedFile(&notesPath, "some notes\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    activateUnstagedChangeMenuItem(0, "Ignore all *.log", &gui);
+
+    assert_eq!("*.tmp\n*.log\n", std::fs::read_to_string(repositoryDir.join(&gitignorePath)).unwrap());
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("Modified", &gitignorePath),
+          makeFileChange("New", &notesPath)],
+        &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
@@ -0,0 +1,33 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewIsEmpty, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::activateUnstagedChangeMenuItem;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn ignoreUntrackedFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("committed_file"), "some content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    let otherFilePath = PathBuf::from("other_file");
+    makeNewUnstagedFile(&otherFilePath, "other content\n", &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("some_file"), "some content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    activateUnstagedChangeMenuItem(1, "Ignore this file", &gui);
+
+    assert_eq!("/some_file\n", std::fs::read_to_string(repositoryDir.join(".gitignore")).unwrap());
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("New", &PathBuf::from(".gitignore")),
+          makeFileChange("New", &otherFilePath)],
+        &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
@@ -0,0 +1,5 @@
+mod exclude_untracked_file_locally;
+mod ignore_all_untracked_files_with_extension;
+mod ignore_directory_of_untracked_file;
+mod ignore_untracked_file;
+mod stage_all_without_ignored_files;
@@ -0,0 +1,40 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewContains, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::{activateUnstagedChangeMenuItem, selectIgnoredFilesCheckbox};
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stageAllWithoutIgnoredFiles()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from(".gitignore"), "*.log\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    let ignoredFilePath = PathBuf::from("debug.log");
+    let newFilePath = PathBuf::from("notes.txt");
+    makeNewUnstagedFile(&ignoredFilePath, "some log\n", &repositoryDir);
+    makeNewUnstagedFile(&newFilePath, "some notes\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    selectIgnoredFilesCheckbox(&gui);
+
+    activateUnstagedChangeMenuItem(0, "Stage all", &gui);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&newFilePath, WorkTreeStatus(Unmodified), IndexStatus(Added))],
+        &repositoryDir);
+    assertUnstagedChangesViewContains(&[makeFileChange("Ignored", &ignoredFilePath)], &gui);
+    assertStagedChangesViewContains(&[makeFileChange("New", &newFilePath)], &gui);
+}
+}
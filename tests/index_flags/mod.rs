@@ -0,0 +1,3 @@
+mod assume_modified_file_unchanged;
+mod skip_worktree_of_modified_file;
+mod stop_tracking_modified_file;
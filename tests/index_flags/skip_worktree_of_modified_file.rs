@@ -0,0 +1,35 @@
+use crate::common::gui_assertions::{
+    assertIndexFlagsButtonLabelIs,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::activateUnstagedChangeMenuItem;
+use crate::common::repository_assertions::assertGitIndexEntriesAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn skipWorktreeOfModifiedFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("local_settings");
+    makeNewStagedFile(&filePath, "some settings\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "some local settings\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertIndexFlagsButtonLabelIs("Index flags", &gui);
+
+    activateUnstagedChangeMenuItem(0, "Skip worktree", &gui);
+
+    assertGitIndexEntriesAre("S local_settings\n", &repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewIsEmpty(&gui);
+    assertIndexFlagsButtonLabelIs("Index flags (1)", &gui);
+}
+}
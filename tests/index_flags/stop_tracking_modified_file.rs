@@ -0,0 +1,32 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewContains, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::activateUnstagedChangeMenuItem;
+use crate::common::repository_assertions::assertGitIndexEntriesAre;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stopTrackingModifiedFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from("other_file"), "other content\n", &repositoryDir);
+    let filePath = PathBuf::from("some_file");
+    makeNewStagedFile(&filePath, "some content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "some modified content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    activateUnstagedChangeMenuItem(0, "Stop tracking (keep file)", &gui);
+
+    assertGitIndexEntriesAre("H other_file\n", &repositoryDir);
+    assert_eq!("some modified content\n", std::fs::read_to_string(repositoryDir.join(&filePath)).unwrap());
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &filePath)], &gui);
+    assertStagedChangesViewContains(&[makeFileChange("Deleted", &filePath)], &gui);
+}
+}
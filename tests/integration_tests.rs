@@ -1,8 +1,12 @@
 #![allow(non_snake_case)]
 
 mod amending_commit;
+mod commit_log;
+mod commit_message;
 mod committing;
 mod common;
+mod ignoring_files;
+mod index_flags;
 mod loading_repository;
 mod refreshing_repository;
 mod staging;
@@ -0,0 +1,30 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertDiffViewContains,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewContains};
+use crate::common::setup::{addWorktree, makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryFromLinkedWorktree()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "some file content\nsecond line\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    let (_worktreeParentDir, worktreeDir) = addWorktree("feature", &repositoryDir);
+    modifyFile(&filePath, "some file content\nmodified second line\n", &worktreeDir);
+
+    let gui = makeGui(&worktreeDir);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("Modified", &filePath)], &gui);
+    assertDiffViewContains("@@ -1,2 +1,2 @@\n some file content\n-second line\n+modified second line\n", &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
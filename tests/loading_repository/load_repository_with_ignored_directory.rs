@@ -0,0 +1,43 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesSummaryIs,
+    assertUnstagedChangesViewContains,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::selectIgnoredFilesCheckbox;
+use crate::common::setup::{
+    makeCommit,
+    makeGui,
+    makeNewStagedFile,
+    makeNewUnstagedFile,
+    makeSubdirectory,
+    setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithIgnoredDirectory()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from(".gitignore"), "build/\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    makeSubdirectory(&PathBuf::from("build"), &repositoryDir);
+    makeSubdirectory(&PathBuf::from("build/objects"), &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("build/app"), "some binary\n", &repositoryDir);
+    makeNewUnstagedFile(&PathBuf::from("build/objects/main.o"), "some object\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewIsEmpty(&gui);
+
+    selectIgnoredFilesCheckbox(&gui);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("Ignored", &PathBuf::from("build"))], &gui);
+    assertUnstagedChangesSummaryIs("", &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
@@ -0,0 +1,36 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewContains,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::selectIgnoredFilesCheckbox;
+use crate::common::repository_assertions::assertRepositoryStatusIsEmpty;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithIgnoredFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    makeNewStagedFile(&PathBuf::from(".gitignore"), "*.log\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    let ignoredFilePath = PathBuf::from("debug.log");
+    makeNewUnstagedFile(&ignoredFilePath, "some log\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertRepositoryStatusIsEmpty(&repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewIsEmpty(&gui);
+
+    selectIgnoredFilesCheckbox(&gui);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("Ignored", &ignoredFilePath)], &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
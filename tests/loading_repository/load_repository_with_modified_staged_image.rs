@@ -0,0 +1,49 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertDiffViewIsEmpty,
+    assertImageDiffInfoIs,
+    assertStagedChangesViewContains,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::selectStagedChangeInRow;
+use crate::common::setup::{makeCommit, makeGui, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithModifiedStagedImage()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let imagePath = PathBuf::from("image.png");
+    std::fs::write(repositoryDir.join(&imagePath), PNG_IMAGE_2_BY_1).unwrap();
+    stageFile(&imagePath, &repositoryDir);
+    makeCommit("Add image", &repositoryDir);
+    std::fs::write(repositoryDir.join(&imagePath), PNG_IMAGE_1_BY_1).unwrap();
+    stageFile(&imagePath, &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewContains(&[makeFileChange("Modified", &imagePath)], &gui);
+    selectStagedChangeInRow(0, &gui);
+    assertImageDiffInfoIs("Old: 2×1 px, 70 B    New: 1×1 px, 69 B", &gui);
+    assertDiffViewIsEmpty(&gui);
+}
+}
+
+const PNG_IMAGE_2_BY_1: [u8; 70] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x7b, 0x40, 0xe8,
+    0xdd, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0x00, 0x04,
+    0xff, 0x01, 0x07, 0x00, 0x01, 0xff, 0xe2, 0x23, 0x9e, 0x59, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45,
+    0x4e, 0x44, 0xae, 0x42, 0x60, 0x82];
+
+const PNG_IMAGE_1_BY_1: [u8; 69] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90, 0x77, 0x53,
+    0xde, 0x00, 0x00, 0x00, 0x0c, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0x60, 0xf8, 0xcf, 0x00,
+    0x00, 0x02, 0x02, 0x01, 0x00, 0x45, 0xf4, 0x52, 0xd4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e,
+    0x44, 0xae, 0x42, 0x60, 0x82];
@@ -0,0 +1,38 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertDiffViewContains,
+    assertDiffViewTaggedTextsAre,
+    assertUnstagedChangesViewContains};
+use crate::common::setup::{makeCommit, makeGui, setupTest, stageFile};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithModifiedUnstagedBinaryFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("data");
+    std::fs::write(repositoryDir.join(&filePath), b"\x00abcdefgh").unwrap();
+    stageFile(&filePath, &repositoryDir);
+    makeCommit("Add data", &repositoryDir);
+    std::fs::write(repositoryDir.join(&filePath), b"\x00abXYefgh").unwrap();
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("Modified", &filePath)], &gui);
+    assertDiffViewContains(
+"binary file
+old: 9 bytes
+new: 9 bytes
+size delta: 0 bytes
+-00000000  00 61 62 63 64 65 66 67 68                       |.abcdefgh|
++00000000  00 61 62 58 59 65 66 67 68                       |.abXYefgh|
+", &gui);
+    assertDiffViewTaggedTextsAre("red", &["63 64", "cd"], &gui);
+    assertDiffViewTaggedTextsAre("green", &["58 59", "XY"], &gui);
+}
+}
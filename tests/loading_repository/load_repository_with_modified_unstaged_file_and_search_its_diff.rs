@@ -0,0 +1,37 @@
+use crate::common::gui_assertions::{assertDiffSearchMatchCountIs, assertDiffViewContains};
+use crate::common::gui_interactions::{searchInDiff, selectNextDiffSearchMatch};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithModifiedUnstagedFileAndSearchItsDiff()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "line 1\nline 2\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "line 1\nmodified line 2\nline 3\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertDiffViewContains("@@ -1,2 +1,3 @@\n line 1\n-line 2\n+modified line 2\n+line 3\n", &gui);
+    assertDiffSearchMatchCountIs("", &gui);
+
+    searchInDiff("LINE 2", &gui);
+    assertDiffSearchMatchCountIs("1 of 2", &gui);
+
+    selectNextDiffSearchMatch(&gui);
+    assertDiffSearchMatchCountIs("2 of 2", &gui);
+
+    selectNextDiffSearchMatch(&gui);
+    assertDiffSearchMatchCountIs("1 of 2", &gui);
+
+    searchInDiff("missing", &gui);
+    assertDiffSearchMatchCountIs("No matches", &gui);
+}
+}
@@ -0,0 +1,30 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertDiffViewContains, assertUnstagedChangesViewContains};
+use crate::common::setup::{makeGui, setupConfig, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithNewUnstagedBinaryFileTooLargeToCompare()
+{
+    let _configDir = setupConfig("[hexDiff]\nmaxSize = 4\n");
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("program");
+    std::fs::write(repositoryDir.join(&filePath), b"\x7fELF\x00\x01\x02").unwrap();
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &filePath)], &gui);
+    assertDiffViewContains(
+"binary file
+old: none
+new: 7 bytes
+size delta: +7 bytes
+ files larger than 4 bytes are not compared byte by byte
+", &gui);
+}
+}
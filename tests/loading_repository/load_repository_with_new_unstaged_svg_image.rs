@@ -0,0 +1,30 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertDiffViewContains,
+    assertImageDiffIsHidden,
+    assertUnstagedChangesViewContains};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithNewUnstagedSvgImage()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let imagePath = PathBuf::from("image.svg");
+    makeNewUnstagedFile(&imagePath, SVG_IMAGE, &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &imagePath)], &gui);
+    assertImageDiffIsHidden(&gui);
+    assertDiffViewContains(&format!("@@ -0,0 +1 @@\n+{}", SVG_IMAGE), &gui);
+}
+}
+
+const SVG_IMAGE: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="2" height="1"/>
+"#;
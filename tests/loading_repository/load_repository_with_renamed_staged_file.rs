@@ -40,7 +40,7 @@ fn loadRepositoryWithStagedRenamedFile()
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
     assertUnstagedChangesViewIsEmpty(&gui);
     assertDiffViewIsEmpty(&gui);
-    assertStagedChangesViewContains(&[makeFileChange("Renamed", &newFilePath)], &gui);
+    assertStagedChangesViewContains(&[makeFileChange("Renamed (100%)", &newFilePath)], &gui);
     assertCommitMessageViewIsEmpty(&gui);
     assertCommitButtonIsDisabled(&gui);
 
@@ -70,4 +70,5 @@ const RENAMED_FILE_DIFF: &str =
 r"renamed file
 old path: some_file
 new path: renamed_file
+similarity: 100%
 ";
@@ -38,8 +38,8 @@ fn loadRepositoryWithNewRenamedFile()
           Entry::new(&newFilePath, WorkTreeStatus(Untracked), IndexStatus(Untracked))],
         &repositoryDir);
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
-    assertUnstagedChangesViewContains(&[makeFileChange("Renamed", &newFilePath)], &gui);
-    assertDiffViewContains("renamed file\nold path: some_file\nnew path: renamed_file\n", &gui);
+    assertUnstagedChangesViewContains(&[makeFileChange("Renamed (100%)", &newFilePath)], &gui);
+    assertDiffViewContains("renamed file\nold path: some_file\nnew path: renamed_file\nsimilarity: 100%\n", &gui);
     assertStagedChangesViewIsEmpty(&gui);
     assertCommitMessageViewIsEmpty(&gui);
     assertCommitButtonIsDisabled(&gui);
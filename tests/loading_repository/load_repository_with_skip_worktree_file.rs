@@ -0,0 +1,29 @@
+use crate::common::gui_assertions::{
+    assertIndexFlagsButtonLabelIs,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setSkipWorktree, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithSkipWorktreeFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("local settings");
+    makeNewStagedFile(&filePath, "some settings\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    setSkipWorktree(&filePath, &repositoryDir);
+    modifyFile(&filePath, "some local settings\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewIsEmpty(&gui);
+    assertIndexFlagsButtonLabelIs("Index flags (1)", &gui);
+}
+}
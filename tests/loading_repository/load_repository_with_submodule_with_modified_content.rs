@@ -0,0 +1,28 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewIsEmpty, assertUnstagedChangesViewContains};
+use crate::common::setup::{addSubmodule, makeCommit, makeGui, makeNewUnstagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithSubmoduleWithModifiedContent()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let submodulePath = PathBuf::from("submodule");
+    addSubmodule(&submodulePath, &repositoryDir);
+    makeCommit("Add submodule", &repositoryDir);
+    let submoduleDir = repositoryDir.join(&submodulePath);
+    modifyFile(&PathBuf::from("file"), "modified submodule file content\n", &submoduleDir);
+    makeNewUnstagedFile(&PathBuf::from("untrackedFile"), "untracked file content\n", &submoduleDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("Submodule (modified content, untracked content)", &submodulePath)], &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
@@ -0,0 +1,35 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertDiffViewContains, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::selectUnstagedChangeInRow;
+use crate::common::setup::{addSubmodule, getShortHeadCommitId, makeCommit, makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithSubmoduleWithNewCommits()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let submodulePath = PathBuf::from("submodule");
+    addSubmodule(&submodulePath, &repositoryDir);
+    makeCommit("Add submodule", &repositoryDir);
+    let submoduleDir = repositoryDir.join(&submodulePath);
+    let oldCommitId = getShortHeadCommitId(&submoduleDir);
+    makeNewStagedFile(&PathBuf::from("newFile"), "new file content\n", &submoduleDir);
+    makeCommit("Add new file", &submoduleDir);
+    let newCommitId = getShortHeadCommitId(&submoduleDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("Submodule (new commits)", &submodulePath)], &gui);
+
+    selectUnstagedChangeInRow(0, &gui);
+    assertDiffViewContains(
+        &format!("submodule submodule\nstatus: new commits\ncommits {}..{}\n+{} Add new file\n",
+                 oldCommitId, newCommitId, newCommitId),
+        &gui);
+}
+}
@@ -0,0 +1,40 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertCommitButtonIsDisabled,
+    assertCommitMessageViewIsEmpty,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewContains};
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, replaceFileWithSymlink, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn loadRepositoryWithTypeChangedUnstagedFile()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("some_file");
+    makeNewStagedFile(&filePath, "some file content\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    replaceFileWithSymlink(&filePath, &PathBuf::from("other_file"), &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&filePath, WorkTreeStatus(TypeChanged), IndexStatus(Unmodified))],
+        &repositoryDir);
+    assertUnstagedChangesViewContains(&[makeFileChange("Type changed", &filePath)], &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+    assertCommitMessageViewIsEmpty(&gui);
+    assertCommitButtonIsDisabled(&gui);
+}
+}
@@ -1,13 +1,27 @@
 mod load_empty_repository;
+mod load_repository_from_linked_worktree;
 mod load_repository_with_commit_and_modified_unstaged_file;
 mod load_repository_with_deleted_staged_file;
 mod load_repository_with_deleted_unstaged_file;
+mod load_repository_with_ignored_directory;
+mod load_repository_with_ignored_file;
 mod load_repository_with_modified_staged_file;
+mod load_repository_with_modified_staged_image;
+mod load_repository_with_modified_unstaged_binary_file;
 mod load_repository_with_modified_unstaged_file_and_same_new_staged_file;
+mod load_repository_with_modified_unstaged_file_and_search_its_diff;
 mod load_repository_with_multiple_kinds_of_files;
 mod load_repository_with_new_staged_file;
+mod load_repository_with_new_unstaged_binary_file;
+mod load_repository_with_new_unstaged_binary_file_too_large_to_compare;
 mod load_repository_with_new_unstaged_empty_file;
 mod load_repository_with_new_unstaged_file;
 mod load_repository_with_new_unstaged_file_in_subdirectory;
+mod load_repository_with_new_unstaged_image;
+mod load_repository_with_new_unstaged_svg_image;
 mod load_repository_with_renamed_staged_file;
 mod load_repository_with_renamed_unstaged_file;
+mod load_repository_with_skip_worktree_file;
+mod load_repository_with_submodule_with_modified_content;
+mod load_repository_with_submodule_with_new_commits;
+mod load_repository_with_type_changed_unstaged_file;
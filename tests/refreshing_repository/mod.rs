@@ -1,5 +1,8 @@
 mod refresh_empty_repository;
 mod refresh_empty_repository_after_new_file_is_created;
+mod refresh_repository_keeps_diff_search_matches;
+mod refresh_repository_keeps_selected_file_when_it_moves_in_sort_order;
+mod refresh_repository_sorted_by_size_of_change_after_file_is_modified;
 mod refresh_repository_with_new_selected_staged_file_after_it_changes_to_modified;
 mod refresh_repository_with_new_staged_file_after_it_changes_to_modified;
 mod refresh_repository_with_three_untracked_files_after_first_is_removed;
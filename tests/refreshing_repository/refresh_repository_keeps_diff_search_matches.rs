@@ -0,0 +1,33 @@
+use crate::common::gui_assertions::{assertDiffSearchMatchCountIs, assertDiffViewContains};
+use crate::common::gui_interactions::{clickRefreshButton, searchInDiff, selectNextDiffSearchMatch};
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn refreshRepositoryKeepsDiffSearchMatches()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fruits");
+    makeNewStagedFile(&filePath, "apple 1\napple 2\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath, "apple 1\nnew apple 2\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    searchInDiff("apple", &gui);
+    assertDiffSearchMatchCountIs("1 of 3", &gui);
+
+    modifyFile(&filePath, "apple 1\nnew apple 2\napple 3\n", &repositoryDir);
+    clickRefreshButton(&gui);
+
+    assertDiffViewContains("@@ -1,2 +1,3 @@\n apple 1\n-apple 2\n+new apple 2\n+apple 3\n", &gui);
+    assertDiffSearchMatchCountIs("4 matches", &gui);
+
+    selectNextDiffSearchMatch(&gui);
+    assertDiffSearchMatchCountIs("1 of 4", &gui);
+}
+}
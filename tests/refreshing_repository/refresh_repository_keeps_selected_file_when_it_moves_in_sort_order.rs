@@ -0,0 +1,39 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertDiffViewContains, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::{clickRefreshButton, selectUnstagedChangeInRow, sortFileChangesBy};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn refreshRepositoryKeepsSelectedFileWhenItMovesInSortOrder()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath1 = PathBuf::from("fileName1");
+    let filePath2 = PathBuf::from("fileName2");
+    let filePath3 = PathBuf::from("fileName3");
+    makeNewUnstagedFile(&filePath1, "line 1\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath2, "line 1\nline 2\nline 3\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath3, "line 1\nline 2\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+    sortFileChangesBy("changeSize", &gui);
+
+    selectUnstagedChangeInRow(2, &gui);
+
+    assertDiffViewContains("@@ -0,0 +1 @@\n+line 1\n", &gui);
+
+    modifyFile(&filePath1, "line 1\nline 2\nline 3\nline 4\n", &repositoryDir);
+    clickRefreshButton(&gui);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("New", &filePath1),
+          makeFileChange("New", &filePath2),
+          makeFileChange("New", &filePath3)],
+        &gui);
+    assertDiffViewContains("@@ -0,0 +1,4 @@\n+line 1\n+line 2\n+line 3\n+line 4\n", &gui);
+}
+}
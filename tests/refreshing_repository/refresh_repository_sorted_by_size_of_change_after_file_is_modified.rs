@@ -0,0 +1,48 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewIsEmpty, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::{clickRefreshButton, sortFileChangesBy};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn refreshRepositorySortedBySizeOfChangeAfterFileIsModified()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath1 = PathBuf::from("fileName1");
+    let filePath2 = PathBuf::from("fileName2");
+    let filePath3 = PathBuf::from("fileName3");
+    makeNewUnstagedFile(&filePath1, "line 1\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath2, "line 1\nline 2\nline 3\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath3, "line 1\nline 2\n", &repositoryDir);
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("New", &filePath1),
+          makeFileChange("New", &filePath2),
+          makeFileChange("New", &filePath3)],
+        &gui);
+
+    sortFileChangesBy("changeSize", &gui);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("New", &filePath2),
+          makeFileChange("New", &filePath3),
+          makeFileChange("New", &filePath1)],
+        &gui);
+
+    modifyFile(&filePath1, "line 1\nline 2\nline 3\nline 4\n", &repositoryDir);
+    clickRefreshButton(&gui);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("New", &filePath1),
+          makeFileChange("New", &filePath2),
+          makeFileChange("New", &filePath3)],
+        &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
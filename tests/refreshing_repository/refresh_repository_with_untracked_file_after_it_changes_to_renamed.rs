@@ -52,8 +52,8 @@ fn refreshRepositoryWithUntrackedFileAfterItChangesToRenamed()
           Entry::new(&filePath,            WorkTreeStatus(Untracked), IndexStatus(Untracked))],
         &repositoryDir);
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
-    assertUnstagedChangesViewContains(&[makeFileChange("Renamed", &filePath)], &gui);
-    assertDiffViewContains("renamed file\nold path: temp_renamed_file\nnew path: some_file\n", &gui);
+    assertUnstagedChangesViewContains(&[makeFileChange("Renamed (100%)", &filePath)], &gui);
+    assertDiffViewContains("renamed file\nold path: temp_renamed_file\nnew path: some_file\nsimilarity: 100%\n", &gui);
     assertStagedChangesViewIsEmpty(&gui);
 }
 }
@@ -0,0 +1,29 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::assertUnstagedChangesViewContains;
+use crate::common::gui_interactions::filterFileChanges;
+use crate::common::setup::{makeGui, makeNewUnstagedFile, makeSubdirectory, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn filterFileChangesWithNegatedBracketGlob()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath1 = PathBuf::from("src-lib.rs");
+    let filePath2 = PathBuf::from("src/lib.rs");
+    let filePath3 = PathBuf::from("src_lib.rs");
+    makeSubdirectory(&PathBuf::from("src"), &repositoryDir);
+    makeNewUnstagedFile(&filePath1, "file content 1\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath2, "file content 2\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath3, "file content 3\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    filterFileChanges("src[!_]lib.rs", &gui);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &filePath1)], &gui);
+}
+}
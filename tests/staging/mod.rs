@@ -1,9 +1,16 @@
+mod filter_file_changes_with_glob_matching_any_directory;
+mod filter_file_changes_with_negated_bracket_glob;
 mod stage_deleted_file_given_it_was_committed_before;
 mod stage_deleted_file_given_no_commits;
+mod stage_directory_in_directory_tree;
+mod stage_file_in_filtered_unstaged_changes;
+mod stage_files_by_dragging_them_to_staged_changes;
 mod stage_modified_file_given_it_was_committed_before;
 mod stage_modified_file_given_it_was_deleted_before;
 mod stage_modified_file_given_no_commits;
+mod stage_modified_file_given_other_file_was_staged_before;
 mod stage_new_file;
 mod stage_new_file_given_it_was_deleted_before;
 mod stage_one_of_two_unstaged_files;
 mod stage_renamed_file;
+mod stage_selected_files;
@@ -0,0 +1,55 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewContains, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::{activateUnstagedChangeInRow, selectDirectoryTreeCheckbox};
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, makeSubdirectory, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stageDirectoryInDirectoryTree()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let subdir = PathBuf::from("subdir");
+    makeSubdirectory(&subdir, &repositoryDir);
+    let firstFilePath = subdir.join("firstFile");
+    let secondFilePath = subdir.join("secondFile");
+    let otherFilePath = PathBuf::from("otherFile");
+    makeNewUnstagedFile(&firstFilePath, "first file content\n", &repositoryDir);
+    makeNewUnstagedFile(&secondFilePath, "second file content\n", &repositoryDir);
+    makeNewUnstagedFile(&otherFilePath, "other file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    selectDirectoryTreeCheckbox(&gui);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("", &PathBuf::from("subdir (2)")),
+          makeFileChange("New", &PathBuf::from("firstFile")),
+          makeFileChange("New", &PathBuf::from("secondFile")),
+          makeFileChange("New", &otherFilePath)],
+        &gui);
+
+    activateUnstagedChangeInRow(0, &gui);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&otherFilePath, WorkTreeStatus(Untracked), IndexStatus(Untracked)),
+          Entry::new(&firstFilePath, WorkTreeStatus(Unmodified), IndexStatus(Added)),
+          Entry::new(&secondFilePath, WorkTreeStatus(Unmodified), IndexStatus(Added))],
+        &repositoryDir);
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &otherFilePath)], &gui);
+    assertStagedChangesViewContains(
+        &[makeFileChange("", &PathBuf::from("subdir (2)")),
+          makeFileChange("New", &PathBuf::from("firstFile")),
+          makeFileChange("New", &PathBuf::from("secondFile"))],
+        &gui);
+}
+}
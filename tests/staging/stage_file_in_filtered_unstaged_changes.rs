@@ -0,0 +1,62 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertStagedChangesSummaryIs,
+    assertStagedChangesViewContains,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesSummaryIs,
+    assertUnstagedChangesViewContains,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::{activateUnstagedChangeInRow, filterFileChanges};
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stageFileInFilteredUnstagedChanges()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath1 = PathBuf::from("fileName1.txt");
+    let filePath2 = PathBuf::from("fileName2.rs");
+    let filePath3 = PathBuf::from("fileName3.txt");
+    makeNewUnstagedFile(&filePath1, "file content 1\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath2, "file content 2\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath3, "file content 3\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    filterFileChanges("*.rs", &gui);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &filePath2)], &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+    assertUnstagedChangesSummaryIs("Total: 3 files  +3 −0", &gui);
+
+    activateUnstagedChangeInRow(0, &gui);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&filePath1, WorkTreeStatus(Untracked),  IndexStatus(Untracked)),
+          Entry::new(&filePath2, WorkTreeStatus(Unmodified), IndexStatus(Added)),
+          Entry::new(&filePath3, WorkTreeStatus(Untracked),  IndexStatus(Untracked))],
+        &repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewContains(&[makeFileChange("New", &filePath2)], &gui);
+    assertUnstagedChangesSummaryIs("Total: 2 files  +2 −0", &gui);
+    assertStagedChangesSummaryIs("Total: 1 file  +1 −0", &gui);
+
+    filterFileChanges("", &gui);
+
+    assertUnstagedChangesViewContains(
+        &[makeFileChange("New", &filePath1),
+          makeFileChange("New", &filePath3)],
+        &gui);
+    assertStagedChangesViewContains(&[makeFileChange("New", &filePath2)], &gui);
+}
+}
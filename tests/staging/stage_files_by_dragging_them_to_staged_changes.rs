@@ -0,0 +1,46 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertStagedChangesViewContains,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::dragUnstagedChangesToStaged;
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stageFilesByDraggingThemToStagedChanges()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath1 = PathBuf::from("file1");
+    let filePath2 = PathBuf::from("file2");
+    let filePath3 = PathBuf::from("file3");
+    makeNewUnstagedFile(&filePath1, "file1 content\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath2, "file2 content\n", &repositoryDir);
+    makeNewUnstagedFile(&filePath3, "file3 content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    assertStagedChangesViewIsEmpty(&gui);
+
+    dragUnstagedChangesToStaged(&["file1", "file3"], &gui);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&filePath1, WorkTreeStatus(Unmodified), IndexStatus(Added)),
+          Entry::new(&filePath2, WorkTreeStatus(Untracked), IndexStatus(Untracked)),
+          Entry::new(&filePath3, WorkTreeStatus(Unmodified), IndexStatus(Added))],
+        &repositoryDir);
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &filePath2)], &gui);
+    assertStagedChangesViewContains(
+        &[makeFileChange("New", &filePath1), makeFileChange("New", &filePath3)], &gui);
+}
+}
@@ -0,0 +1,45 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertStagedChangesSummaryIs,
+    assertStagedChangesViewContains,
+    assertUnstagedChangesSummaryIs,
+    assertUnstagedChangesViewContains,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::activateUnstagedChangeInRow;
+use crate::common::setup::{makeCommit, makeGui, makeNewStagedFile, modifyFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stageModifiedFileGivenOtherFileWasStagedBefore()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath1 = PathBuf::from("fileName1");
+    let filePath2 = PathBuf::from("fileName2");
+    makeNewStagedFile(&filePath1, "line 1\nline 2\n", &repositoryDir);
+    makeCommit("Initial commit", &repositoryDir);
+    modifyFile(&filePath1, "line 1\nmodified line 2\nline 3\n", &repositoryDir);
+    makeNewStagedFile(&filePath2, "line 1\nline 2\nline 3\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    assertUnstagedChangesViewContains(&[makeFileChange("Modified", &filePath1)], &gui);
+    assertStagedChangesViewContains(&[makeFileChange("New", &filePath2)], &gui);
+    assertUnstagedChangesSummaryIs("Total: 1 file  +2 −1", &gui);
+    assertStagedChangesSummaryIs("Total: 1 file  +3 −0", &gui);
+
+    activateUnstagedChangeInRow(0, &gui);
+
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewContains(
+        &[makeFileChange("Modified", &filePath1),
+          makeFileChange("New", &filePath2)],
+        &gui);
+    assertUnstagedChangesSummaryIs("", &gui);
+    assertStagedChangesSummaryIs("Total: 2 files  +5 −1", &gui);
+}
+}
@@ -38,7 +38,7 @@ fn stageRenamedFile()
           Entry::new(&newFilePath, WorkTreeStatus(Untracked), IndexStatus(Untracked))],
         &repositoryDir);
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
-    assertUnstagedChangesViewContains(&[makeFileChange("Renamed", &newFilePath)], &gui);
+    assertUnstagedChangesViewContains(&[makeFileChange("Renamed (100%)", &newFilePath)], &gui);
     assertStagedChangesViewIsEmpty(&gui);
     assertDiffViewContains(DIFF, &gui);
 
@@ -49,7 +49,7 @@ fn stageRenamedFile()
         &repositoryDir);
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
     assertUnstagedChangesViewIsEmpty(&gui);
-    assertStagedChangesViewContains(&[makeFileChange("Renamed", &newFilePath)], &gui);
+    assertStagedChangesViewContains(&[makeFileChange("Renamed (100%)", &newFilePath)], &gui);
     assertDiffViewIsEmpty(&gui);
 
     selectStagedChangeInRow(0, &gui);
@@ -78,4 +78,5 @@ const DIFF: &str =
 r"renamed file
 old path: some_file
 new path: renamed_file
+similarity: 100%
 ";
@@ -0,0 +1,43 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{assertStagedChangesViewContains, assertUnstagedChangesViewContains};
+use crate::common::gui_interactions::activateUnstagedChangesInRows;
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeGui, makeNewUnstagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn stageSelectedFiles()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let firstFilePath = PathBuf::from("firstFile");
+    let secondFilePath = PathBuf::from("secondFile");
+    let thirdFilePath = PathBuf::from("thirdFile");
+    makeNewUnstagedFile(&firstFilePath, "first file content\n", &repositoryDir);
+    makeNewUnstagedFile(&secondFilePath, "second file content\n", &repositoryDir);
+    makeNewUnstagedFile(&thirdFilePath, "third file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+
+    activateUnstagedChangesInRows(&[0, 2], &gui);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&firstFilePath, WorkTreeStatus(Unmodified), IndexStatus(Added)),
+          Entry::new(&secondFilePath, WorkTreeStatus(Untracked), IndexStatus(Untracked)),
+          Entry::new(&thirdFilePath, WorkTreeStatus(Unmodified), IndexStatus(Added))],
+        &repositoryDir);
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &secondFilePath)], &gui);
+    assertStagedChangesViewContains(
+        &[makeFileChange("New", &firstFilePath), makeFileChange("New", &thirdFilePath)],
+        &gui);
+}
+}
@@ -0,0 +1,41 @@
+use crate::common::file_changes_view_utils::makeFileChange;
+use crate::common::gui_assertions::{
+    assertStagedChangesViewContains,
+    assertStagedChangesViewIsEmpty,
+    assertUnstagedChangesViewContains,
+    assertUnstagedChangesViewIsEmpty};
+use crate::common::gui_interactions::dragStagedChangesToUnstaged;
+use crate::common::repository_assertions::assertRepositoryStatusIs;
+use crate::common::repository_status_utils::{
+    FileChangeStatus::*,
+    IndexStatus,
+    RepositoryStatusEntry as Entry,
+    WorkTreeStatus};
+use crate::common::setup::{makeGui, makeNewStagedFile, setupTest};
+
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+
+rusty_fork_test! {
+#[test]
+fn unstageFileByDraggingItToUnstagedChanges()
+{
+    let repositoryDir = setupTest();
+    let repositoryDir = repositoryDir.path().to_owned();
+    let filePath = PathBuf::from("fileName");
+    makeNewStagedFile(&filePath, "file content\n", &repositoryDir);
+
+    let gui = makeGui(&repositoryDir);
+    assertUnstagedChangesViewIsEmpty(&gui);
+    assertStagedChangesViewContains(&[makeFileChange("New", &filePath)], &gui);
+
+    dragStagedChangesToUnstaged(&["fileName"], &gui);
+
+    assertRepositoryStatusIs(
+        &[Entry::new(&filePath, WorkTreeStatus(Untracked), IndexStatus(Untracked))],
+        &repositoryDir);
+    assertUnstagedChangesViewContains(&[makeFileChange("New", &filePath)], &gui);
+    assertStagedChangesViewIsEmpty(&gui);
+}
+}
@@ -40,7 +40,7 @@ fn unstageRenamedFile()
         &repositoryDir);
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
     assertUnstagedChangesViewIsEmpty(&gui);
-    assertStagedChangesViewContains(&[makeFileChange("Renamed", &newFilePath)], &gui);
+    assertStagedChangesViewContains(&[makeFileChange("Renamed (100%)", &newFilePath)], &gui);
     assertDiffViewIsEmpty(&gui);
 
     activateStagedChangeInRow(0, &gui);
@@ -50,7 +50,7 @@ fn unstageRenamedFile()
           Entry::new(&newFilePath, WorkTreeStatus(Untracked), IndexStatus(Untracked))],
         &repositoryDir);
     assertRepositoryLogIs(REPOSITORY_LOG, &repositoryDir);
-    assertUnstagedChangesViewContains(&[makeFileChange("Renamed", &newFilePath)], &gui);
+    assertUnstagedChangesViewContains(&[makeFileChange("Renamed (100%)", &newFilePath)], &gui);
     assertStagedChangesViewIsEmpty(&gui);
     assertDiffViewIsEmpty(&gui);
 
@@ -80,4 +80,5 @@ const DIFF: &str =
 r"renamed file
 old path: some_file
 new path: renamed_file
+similarity: 100%
 ";